@@ -0,0 +1,57 @@
+//! 由小鬼运动模拟重新生成 `src/constants.rs` 中的小鬼x坐标对照表。
+//!
+//! 用法：`cargo run --release --example gen_imp_table`
+use pvz_interception_calculator::game;
+use std::fmt::Write;
+use std::fs;
+use std::process::Command;
+
+const CONSTANTS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/constants.rs");
+const BEGIN_MARKER: &str = "// 以下小鬼x坐标对照表";
+const END_MARKER: &str = "// 以上小鬼x坐标对照表";
+
+fn main() {
+    let constants = fs::read_to_string(CONSTANTS_PATH).expect("cannot read constants.rs");
+    let (Some(begin), Some(end)) = (constants.find(BEGIN_MARKER), constants.find(END_MARKER))
+    else {
+        panic!("cannot find imp x tables in constants.rs");
+    };
+    let mut output = constants[..begin].to_string();
+    output += BEGIN_MARKER;
+    output += "由 `cargo run --release --example gen_imp_table` 生成，请勿手动修改。\n";
+    output += "// 第一个值对应小鬼x坐标为 MIN_IMP_X 时投掷该小鬼的巨人x范围，第二个对应 MIN_IMP_X+1，依次类推；\n";
+    output += "// 没有巨人会投掷该坐标的小鬼时为 None。之所以这样储存，主要是因为rust不支持编译期定义HashMap\n";
+    for (prefix, scene) in [("", game::Scene::PE), ("ROOF_", game::Scene::RE)] {
+        let (min_imp_x, table) = game::min_max_garg_x_of_imp_x_table(&scene);
+        write_table(&mut output, prefix, min_imp_x, &table);
+    }
+    output += "\n";
+    output += &constants[end..];
+    fs::write(CONSTANTS_PATH, output).expect("cannot write constants.rs");
+    let formatted = Command::new("rustfmt")
+        .args(["--edition", "2021", CONSTANTS_PATH])
+        .status()
+        .is_ok_and(|status| status.success());
+    if !formatted {
+        eprintln!("rustfmt failed, please run `cargo fmt` manually");
+    }
+}
+
+fn write_table(output: &mut String, prefix: &str, min_imp_x: i32, table: &[Option<(f32, f32)>]) {
+    let max_imp_x = min_imp_x + table.len() as i32 - 1;
+    writeln!(output).unwrap();
+    writeln!(output, "pub const {prefix}MIN_IMP_X: i32 = {min_imp_x};").unwrap();
+    writeln!(output, "pub const {prefix}MAX_IMP_X: i32 = {max_imp_x};").unwrap();
+    writeln!(
+        output,
+        "const {prefix}MIN_MAX_GARG_X_OF_IMP_POS: [Option<(f32, f32)>; ({prefix}MAX_IMP_X - {prefix}MIN_IMP_X + 1) as usize] = ["
+    )
+    .unwrap();
+    for min_max_garg_x in table {
+        match min_max_garg_x {
+            Some((min, max)) => writeln!(output, "    Some(({min:.3}, {max:.3})),").unwrap(),
+            None => writeln!(output, "    None,").unwrap(),
+        }
+    }
+    writeln!(output, "];").unwrap();
+}
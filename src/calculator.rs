@@ -0,0 +1,1509 @@
+use crate::command::{
+    AshCommand, DelayCommand, EatCommand, ExplodeCommand, GargPos, HitCommand, HitMode,
+    IceSolveCommand, ImpCommand, KillerCommand, LandCommand, LawnCommand, MaxDelayCommand,
+    MultiCommand, ScanCommand, SolveCommand, SvgCommand, TimedCob, TraceCommand, WaveCommand,
+};
+use crate::constants;
+use crate::game;
+use crate::report::{
+    AshReport, DelayReport, EatReport, HitReport, IceSolveRange, IceSolveReport, ImpReport,
+    KillerReport, LandReport, MaxDelay, MaxDelayReport, MultiReport, RelativeRow, ScanReport,
+    ScanRow, SolveRange, SolveReport, SvgReport, TraceReport, Warning, WaveReport,
+};
+use dyn_fmt::AsStrFormatExt;
+use std::cmp;
+use std::collections::HashMap;
+
+use crate::lang::*;
+
+pub const DEFAULT_SCENE: game::Scene = game::Scene::PE;
+pub const DEFAULT_COB_TIME: i32 = 318;
+/// 未指定炮尾所在行时使用的默认值
+pub const DEFAULT_ROOF_COB_ROW: i32 = 3;
+const MAX_FULLY_HITTABLE_GARG_X: i32 = 817; // 巨人x坐标超过此值时，无法全伤巨人
+
+fn corrected_hit_col(hit_col: f32, warnings: &mut Vec<Warning>) -> f32 {
+    match game::hit_col_matching_int_pixel(hit_col) {
+        None => hit_col,
+        Some(corrected_hit_col) => {
+            warnings.push(Warning::HitColCorrected {
+                hit_col,
+                corrected_hit_col,
+            });
+            corrected_hit_col
+        }
+    }
+}
+
+fn corrected_cob(cob: &game::Cob, warnings: &mut Vec<Warning>) -> game::Cob {
+    let col = corrected_hit_col(cob.col(), warnings);
+    match cob {
+        game::Cob::Ground { row, col: _ } => game::Cob::ground(*row, col),
+        game::Cob::Roof {
+            row,
+            col: _,
+            cob_col,
+            cob_row,
+        } => game::Cob::roof(*row, col, *cob_col, *cob_row),
+    }
+}
+
+fn wave_warnings(
+    ice_and_cob_times: &game::IceAndCobTimes,
+    min_max_garg_x: (f32, f32),
+) -> Vec<Warning> {
+    let mut warnings = vec![];
+    if min_max_garg_x.1 as i32 > MAX_FULLY_HITTABLE_GARG_X {
+        warnings.push(Warning::CannotHitAllGarg);
+    }
+    warnings.extend(garg_walk_warning(ice_and_cob_times));
+    warnings
+}
+
+// 巨人行走时间超出行走数据表长时，巨人x范围按行走周期外推
+fn garg_walk_warning(ice_and_cob_times: &game::IceAndCobTimes) -> Option<Warning> {
+    game::extrapolated_garg_walk(ice_and_cob_times)
+        .map(|max_walk| Warning::GargWalkExtrapolated { max_walk })
+}
+
+// 保留外推时间最长的警告
+fn keep_longest_garg_walk_warning(longest: &mut Option<Warning>, warning: Option<Warning>) {
+    if let Some(Warning::GargWalkExtrapolated { max_walk }) = warning {
+        match longest {
+            Some(Warning::GargWalkExtrapolated { max_walk: prev }) if *prev >= max_walk => {}
+            _ => *longest = warning,
+        }
+    }
+}
+
+// 第 `index` 次用冰的时机与上一组相邻时并入上一组，否则新开一组
+fn extend_ice_solve_ranges(
+    ranges: &mut Vec<IceSolveRange>,
+    index: usize,
+    ice_times: &[i32],
+    max_delay: i32,
+) {
+    match ranges.last_mut() {
+        Some(range) if range.min_max_ice_times[index].1 == ice_times[index] - 1 => {
+            range.min_max_ice_times[index].1 = ice_times[index];
+            if max_delay > range.max_delay {
+                range.max_delay = max_delay;
+                range.max_delay_ice_times = ice_times.to_vec();
+            }
+        }
+        _ => ranges.push(IceSolveRange {
+            min_max_ice_times: ice_times.iter().map(|&time| (time, time)).collect(),
+            max_delay,
+            max_delay_ice_times: ice_times.to_vec(),
+        }),
+    }
+}
+
+/// 一次拦截计算的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct Judgement {
+    /// 实际用于计算的巨人x范围
+    pub garg_x_range: game::GargXRange,
+    pub eat: game::Eat,
+    pub intercept: game::Intercept,
+}
+
+impl Judgement {
+    pub fn safe_intercept_interval(&self) -> Option<(i32, i32)> {
+        game::safe_intercept_interval(&self.eat, &self.intercept)
+    }
+
+    pub fn unsafe_intercept_interval(&self) -> Option<(i32, i32)> {
+        game::unsafe_intercept_interval(&self.eat, &self.intercept)
+    }
+}
+
+/// 拦截计算器：保存场合、用冰及激活时机，计算结果不做任何打印
+#[derive(Debug, Clone)]
+pub struct Calculator {
+    scene: game::Scene,
+    ice_and_cob_times: game::IceAndCobTimes,
+    min_max_garg_x: (f32, f32),
+    lawn: game::Lawn,
+}
+
+impl Default for Calculator {
+    fn default() -> Self {
+        Calculator::new(
+            DEFAULT_SCENE,
+            game::IceAndCobTimes::of_ice_times_and_cob_time(&[], DEFAULT_COB_TIME).unwrap(),
+        )
+    }
+}
+
+impl Calculator {
+    pub fn new(scene: game::Scene, ice_and_cob_times: game::IceAndCobTimes) -> Calculator {
+        let min_max_garg_x = game::min_max_garg_x(&ice_and_cob_times);
+        Calculator {
+            scene,
+            ice_and_cob_times,
+            min_max_garg_x,
+            lawn: game::Lawn::default(),
+        }
+    }
+
+    pub fn scene(&self) -> &game::Scene {
+        &self.scene
+    }
+
+    pub fn set_scene(&mut self, scene: game::Scene) {
+        self.scene = scene;
+    }
+
+    pub fn ice_and_cob_times(&self) -> &game::IceAndCobTimes {
+        &self.ice_and_cob_times
+    }
+
+    /// 种有植物的格子，用于计算啃食目标
+    pub fn lawn(&self) -> &game::Lawn {
+        &self.lawn
+    }
+
+    pub fn set_lawn(&mut self, lawn: game::Lawn) {
+        self.lawn = lawn;
+    }
+
+    /// 激活时巨人x坐标的最小、最大值
+    pub fn min_max_garg_x(&self) -> (f32, f32) {
+        self.min_max_garg_x
+    }
+
+    pub fn is_iced(&self) -> bool {
+        self.ice_and_cob_times.is_iced()
+    }
+
+    /// 设置用冰、减速及激活时机；出错时保持原设置不变
+    pub fn set_wave(
+        &mut self,
+        ice_times: &[i32],
+        slow_times: &[i32],
+        cob_time: i32,
+        activation: game::Activation,
+    ) -> Result<(), String> {
+        let ice_and_cob_times =
+            game::IceAndCobTimes::of_times(ice_times, slow_times, cob_time, activation)?;
+        self.min_max_garg_x = game::min_max_garg_x(&ice_and_cob_times);
+        self.ice_and_cob_times = ice_and_cob_times;
+        Ok(())
+    }
+
+    /// 在当前设置基础上延迟 `delay_time` 激活，返回新的设置及巨人x范围
+    pub fn delayed(&self, delay_time: i32) -> Result<(game::IceAndCobTimes, (f32, f32)), String> {
+        let ice_and_cob_times = game::IceAndCobTimes::of_times(
+            &self.ice_and_cob_times.ice_times,
+            &self.ice_and_cob_times.slow_times,
+            self.ice_and_cob_times.cob_time.saturating_add(delay_time),
+            self.ice_and_cob_times.activation,
+        )?;
+        let min_max_garg_x = game::min_max_garg_x(&ice_and_cob_times);
+        Ok((ice_and_cob_times, min_max_garg_x))
+    }
+
+    /// 计算炮拦截；`min_max_garg_x`、`iced` 为 `None` 时使用当前设置
+    pub fn judge_cobs(
+        &self,
+        cob_and_garg_rows: &[(game::Cob, Vec<i32>)],
+        min_max_garg_x: Option<(f32, f32)>,
+        iced: Option<bool>,
+    ) -> Judgement {
+        let explode_and_garg_rows = cob_and_garg_rows
+            .iter()
+            .map(|(cob, garg_rows)| (game::Explode::of_cob(cob, &self.scene), garg_rows))
+            .collect::<Vec<_>>();
+        self.judge_explodes(&explode_and_garg_rows, min_max_garg_x, iced)
+    }
+
+    /// 计算灰烬拦截（植物拦截）；`min_max_garg_x`、`iced` 为 `None` 时使用当前设置
+    pub fn judge_ash(
+        &self,
+        ash: &game::Ash,
+        garg_rows: &Vec<i32>,
+        min_max_garg_x: Option<(f32, f32)>,
+        iced: Option<bool>,
+    ) -> Judgement {
+        let explode = game::Explode::of_ash(ash, &self.scene);
+        let mut judgement = self.judge_explodes(&[(explode, garg_rows)], min_max_garg_x, iced);
+        judgement.eat.shift_to_plant_intercept();
+        judgement.intercept.shift_to_plant_intercept();
+        judgement
+    }
+
+    /// 计算秒杀植物的秒杀时机；`min_max_garg_x`、`iced` 为 `None` 时使用当前设置
+    pub fn judge_killer(
+        &self,
+        plant: &game::KillerPlant,
+        garg_rows: &[i32],
+        min_max_garg_x: Option<(f32, f32)>,
+        iced: Option<bool>,
+    ) -> (game::GargXRange, game::Eat, game::Kill) {
+        let garg_x_range =
+            game::GargXRange::of_min_max_garg_pos(min_max_garg_x.unwrap_or(self.min_max_garg_x));
+        let (eat, kill) = game::judge_killer(
+            &garg_x_range,
+            garg_rows,
+            iced.unwrap_or(self.is_iced()),
+            &self.scene,
+            &game::Killer::of_plant(plant),
+        );
+        (garg_x_range, eat, kill)
+    }
+
+    /// 计算在不同时机生效的多门炮拦截各小鬼的情况；`min_max_garg_x`、`iced` 为 `None` 时使用当前设置
+    pub fn judge_timed_cobs(
+        &self,
+        timed_cobs: &[TimedCob],
+        min_max_garg_x: Option<(f32, f32)>,
+        iced: Option<bool>,
+    ) -> (game::GargXRange, Vec<game::Trajectory>) {
+        let garg_x_range =
+            game::GargXRange::of_min_max_garg_pos(min_max_garg_x.unwrap_or(self.min_max_garg_x));
+        let explode_time_and_garg_rows = timed_cobs
+            .iter()
+            .map(|timed_cob| {
+                (
+                    game::Explode::of_cob(&timed_cob.cob, &self.scene),
+                    timed_cob.time,
+                    &timed_cob.garg_rows,
+                )
+            })
+            .collect::<Vec<_>>();
+        let trajectories = game::judge_timed(
+            &garg_x_range,
+            &explode_time_and_garg_rows,
+            iced.unwrap_or(self.is_iced()),
+            &self.scene,
+        );
+        (garg_x_range, trajectories)
+    }
+
+    fn cob(
+        &self,
+        hit_row: i32,
+        hit_col: f32,
+        cob_col: Option<i32>,
+        cob_row: Option<i32>,
+    ) -> game::Cob {
+        if self.scene.is_roof() {
+            game::Cob::roof(
+                hit_row,
+                hit_col,
+                cob_col.expect(&NEED_COB_COL),
+                cob_row.unwrap_or(DEFAULT_ROOF_COB_ROW),
+            )
+        } else {
+            game::Cob::ground(hit_row, hit_col)
+        }
+    }
+
+    // 落点列范围内每个像素列各一门炮
+    fn cob_list(
+        &self,
+        hit_row: i32,
+        (min_hit_col, max_hit_col): (f32, f32),
+        cob_col: Option<i32>,
+        cob_row: Option<i32>,
+        warnings: &mut Vec<Warning>,
+    ) -> Vec<game::Cob> {
+        let min_hit_col = corrected_hit_col(min_hit_col, warnings);
+        let max_hit_col = corrected_hit_col(max_hit_col, warnings);
+        ((min_hit_col * 80.).round() as i32..=(max_hit_col * 80.).round() as i32)
+            .map(|v| self.cob(hit_row, v as f32 / 80., cob_col, cob_row))
+            .collect()
+    }
+
+    /// 计算结果所用的巨人x范围；若与当前设置相同则返回 `None`
+    fn judged_garg_x_range(
+        &self,
+        garg_x_range: &game::GargXRange,
+        min_max_garg_x: Option<(f32, f32)>,
+        warnings: &mut Vec<Warning>,
+    ) -> Option<(f32, f32)> {
+        if min_max_garg_x.is_none() {
+            warnings.extend(garg_walk_warning(&self.ice_and_cob_times));
+        }
+        let min_max_garg_x = match *garg_x_range {
+            game::GargXRange::Cancelled => {
+                warnings.push(Warning::GargXRangeCancelled);
+                min_max_garg_x.unwrap_or(self.min_max_garg_x)
+            }
+            game::GargXRange::Modified { min, max } => {
+                warnings.push(Warning::GargXRangeModified { min, max });
+                (min, max)
+            }
+            game::GargXRange::Ok { min, max } => (min, max),
+        };
+        if min_max_garg_x != self.min_max_garg_x {
+            Some(min_max_garg_x)
+        } else {
+            None
+        }
+    }
+
+    pub fn run_wave(&mut self, command: &WaveCommand) -> Result<WaveReport, String> {
+        if let WaveCommand::Set {
+            ice_times,
+            slow_times,
+            cob_time,
+            activation,
+        } = command
+        {
+            self.set_wave(ice_times, slow_times, *cob_time, *activation)?;
+        }
+        Ok(WaveReport {
+            warnings: wave_warnings(&self.ice_and_cob_times, self.min_max_garg_x),
+            ice_and_cob_times: self.ice_and_cob_times.clone(),
+            min_max_garg_x: self.min_max_garg_x,
+        })
+    }
+
+    pub fn run_delay(&self, command: &DelayCommand) -> DelayReport {
+        let mut warnings = vec![];
+        let (cob_and_garg_rows, min_max_garg_x, ice_flag, explode_center) = match command {
+            DelayCommand::Default {
+                delay_mode,
+                hit_col,
+                cob_col,
+                cob_row,
+            } => {
+                let hit_col = corrected_hit_col(*hit_col, &mut warnings);
+                let delay_mode =
+                    delay_mode.unwrap_or(self.scene.default_delay_mode(hit_col, *cob_col));
+                (
+                    self.scene
+                        .hit_row_and_garg_rows_of_delay_mode(&delay_mode)
+                        .into_iter()
+                        .map(|(hit_row, garg_rows)| {
+                            (self.cob(hit_row, hit_col, *cob_col, *cob_row), garg_rows)
+                        })
+                        .collect::<Vec<_>>(),
+                    None,
+                    None,
+                    None,
+                )
+            }
+            DelayCommand::Specific {
+                cob,
+                garg_pos:
+                    GargPos {
+                        garg_rows,
+                        min_max_garg_x,
+                        ice_flag,
+                    },
+            } => {
+                let cob = corrected_cob(cob, &mut warnings);
+                let explode_center = game::Explode::of_cob(&cob, &self.scene).center();
+                (
+                    vec![(cob, garg_rows.clone())],
+                    *min_max_garg_x,
+                    *ice_flag,
+                    explode_center,
+                )
+            }
+        };
+        let judgement = self.judge_cobs(&cob_and_garg_rows, min_max_garg_x, ice_flag);
+        let garg_x_range =
+            self.judged_garg_x_range(&judgement.garg_x_range, min_max_garg_x, &mut warnings);
+        DelayReport {
+            warnings,
+            cob_and_garg_rows,
+            explode_center,
+            garg_x_range,
+            eat: judgement.eat,
+            intercept: judgement.intercept,
+        }
+    }
+
+    pub fn run_ash(&self, AshCommand { ash, garg_pos }: &AshCommand) -> AshReport {
+        let mut warnings = vec![];
+        let (garg_rows, min_max_garg_x, ice_flag, explode_center) = match garg_pos {
+            None => (self.scene.garg_rows_for_ash(ash), None, None, None),
+            Some(GargPos {
+                garg_rows,
+                min_max_garg_x,
+                ice_flag,
+            }) => (
+                garg_rows.clone(),
+                *min_max_garg_x,
+                *ice_flag,
+                game::Explode::of_ash(ash, &self.scene).center(),
+            ),
+        };
+        let judgement = self.judge_ash(ash, &garg_rows, min_max_garg_x, ice_flag);
+        let garg_x_range =
+            self.judged_garg_x_range(&judgement.garg_x_range, min_max_garg_x, &mut warnings);
+        AshReport {
+            warnings,
+            ash: *ash,
+            garg_rows,
+            explode_center,
+            garg_x_range,
+            eat: judgement.eat,
+            intercept: judgement.intercept,
+        }
+    }
+
+    pub fn run_killer(&self, KillerCommand { plant, garg_pos }: &KillerCommand) -> KillerReport {
+        let mut warnings = vec![];
+        let (garg_rows, min_max_garg_x, ice_flag) = match garg_pos {
+            None => (self.scene.garg_rows_for_killer(plant), None, None),
+            Some(GargPos {
+                garg_rows,
+                min_max_garg_x,
+                ice_flag,
+            }) => (garg_rows.clone(), *min_max_garg_x, *ice_flag),
+        };
+        let (garg_x_range, eat, kill) =
+            self.judge_killer(plant, &garg_rows, min_max_garg_x, ice_flag);
+        let garg_x_range = self.judged_garg_x_range(&garg_x_range, min_max_garg_x, &mut warnings);
+        KillerReport {
+            warnings,
+            plant: *plant,
+            garg_rows,
+            garg_x_range,
+            eat,
+            kill,
+        }
+    }
+
+    pub fn run_hit(
+        &self,
+        HitCommand {
+            mode,
+            cob_col,
+            cob_row,
+            delay_time,
+        }: &HitCommand,
+    ) -> Result<HitReport, String> {
+        let (warnings, delayed, (min_garg_x, max_garg_x)) = match delay_time {
+            None => (
+                garg_walk_warning(&self.ice_and_cob_times)
+                    .into_iter()
+                    .collect(),
+                None,
+                self.min_max_garg_x,
+            ),
+            Some(delay_time) => {
+                let (ice_and_cob_times, min_max_garg_x) = self.delayed(*delay_time)?;
+                (
+                    wave_warnings(&ice_and_cob_times, min_max_garg_x),
+                    Some((ice_and_cob_times, min_max_garg_x)),
+                    min_max_garg_x,
+                )
+            }
+        };
+        let cob_dist = self.scene.cob_dist(*cob_col);
+        let relative_rows = if self.scene.is_roof() {
+            vec![RelativeRow::Above, RelativeRow::Same, RelativeRow::Below]
+        } else {
+            vec![RelativeRow::Same, RelativeRow::Above]
+        };
+        let hit_pixels = relative_rows
+            .into_iter()
+            .map(|relative_row| {
+                let dist = match relative_row {
+                    RelativeRow::Above => cob_dist.hit_above,
+                    RelativeRow::Same => cob_dist.hit_same,
+                    RelativeRow::Below => cob_dist.hit_below,
+                };
+                let pixel = match mode {
+                    HitMode::Hit => max_garg_x as i32 - dist,
+                    HitMode::Nohit => min_garg_x as i32 - dist - 1,
+                };
+                (relative_row, pixel)
+            })
+            .collect();
+        Ok(HitReport {
+            warnings,
+            mode: *mode,
+            scene: self.scene,
+            cob_tail: cob_col.map(|cob_col| (cob_row.unwrap_or(DEFAULT_ROOF_COB_ROW), cob_col)),
+            delayed,
+            hit_pixels,
+        })
+    }
+
+    pub fn run_max_delay(
+        &self,
+        MaxDelayCommand {
+            hit_row,
+            min_max_hit_col: (min_hit_col, max_hit_col),
+            cob_col,
+            cob_row,
+            garg_pos:
+                GargPos {
+                    garg_rows,
+                    min_max_garg_x,
+                    ice_flag,
+                },
+        }: &MaxDelayCommand,
+    ) -> MaxDelayReport {
+        let mut warnings = vec![];
+        let cob_list = self.cob_list(
+            *hit_row,
+            (*min_hit_col, *max_hit_col),
+            *cob_col,
+            *cob_row,
+            &mut warnings,
+        );
+        let mut max_delay: Option<(i32, MaxDelay)> = None;
+        let mut garg_x_range = None;
+        for cob in &cob_list {
+            let judgement = self.judge_cobs(
+                &[(cob.clone(), garg_rows.clone())],
+                *min_max_garg_x,
+                *ice_flag,
+            );
+            if garg_x_range.is_none() {
+                garg_x_range = Some(self.judged_garg_x_range(
+                    &judgement.garg_x_range,
+                    *min_max_garg_x,
+                    &mut warnings,
+                ));
+            }
+            let Some((_, new_max)) = judgement.safe_intercept_interval() else {
+                continue;
+            };
+            match &mut max_delay {
+                Some((prev_max, max_delay)) if new_max <= *prev_max => {
+                    max_delay.hit_cols.push(cob.col());
+                }
+                _ => {
+                    max_delay = Some((
+                        new_max,
+                        MaxDelay {
+                            hit_cols: vec![cob.col()],
+                            eat: judgement.eat,
+                            intercept: judgement.intercept,
+                        },
+                    ))
+                }
+            }
+        }
+        MaxDelayReport {
+            warnings,
+            cob: cob_list[0].clone(),
+            garg_rows: garg_rows.clone(),
+            min_max_hit_col: (cob_list[0].col(), cob_list.last().unwrap().col()),
+            garg_x_range: garg_x_range.flatten(),
+            max_delay: max_delay.map(|(_, mut max_delay)| {
+                max_delay.hit_cols.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                max_delay
+            }),
+        }
+    }
+
+    pub fn run_scan(
+        &self,
+        ScanCommand {
+            hit_row,
+            min_max_hit_col,
+            cob_col,
+            cob_row,
+            garg_pos:
+                GargPos {
+                    garg_rows,
+                    min_max_garg_x,
+                    ice_flag,
+                },
+            min_max_delay,
+            csv,
+        }: &ScanCommand,
+    ) -> ScanReport {
+        let mut warnings = vec![];
+        let cob_list = self.cob_list(
+            *hit_row,
+            *min_max_hit_col,
+            *cob_col,
+            *cob_row,
+            &mut warnings,
+        );
+        let mut garg_x_range = None;
+        let rows = cob_list
+            .iter()
+            .map(|cob| {
+                let judgement = self.judge_cobs(
+                    &[(cob.clone(), garg_rows.clone())],
+                    *min_max_garg_x,
+                    *ice_flag,
+                );
+                if garg_x_range.is_none() {
+                    garg_x_range = Some(self.judged_garg_x_range(
+                        &judgement.garg_x_range,
+                        *min_max_garg_x,
+                        &mut warnings,
+                    ));
+                }
+                ScanRow {
+                    hit_col: cob.col(),
+                    eat: judgement.eat,
+                    intercept: judgement.intercept,
+                }
+            })
+            .collect::<Vec<ScanRow>>();
+        ScanReport {
+            warnings,
+            cob: cob_list[0].clone(),
+            garg_rows: garg_rows.clone(),
+            min_max_hit_col: (cob_list[0].col(), cob_list.last().unwrap().col()),
+            garg_x_range: garg_x_range.flatten(),
+            min_max_delay: *min_max_delay,
+            csv: *csv,
+            rows,
+        }
+    }
+
+    /// 沿用当前用冰及激活方式，逐个激活时机重新计算巨人x范围并判断能否无伤拦截
+    pub fn run_solve(
+        &self,
+        SolveCommand {
+            min_max_cob_time: (min_cob_time, max_cob_time),
+            cob,
+            garg_rows,
+        }: &SolveCommand,
+    ) -> Result<SolveReport, String> {
+        let mut warnings = vec![];
+        let cob = corrected_cob(cob, &mut warnings);
+        let cob_and_garg_rows = [(cob.clone(), garg_rows.clone())];
+        let mut ranges: Vec<SolveRange> = vec![];
+        let mut longest_walk_warning = None;
+        for cob_time in *min_cob_time..=*max_cob_time {
+            let ice_and_cob_times = game::IceAndCobTimes::of_times(
+                &self.ice_and_cob_times.ice_times,
+                &self.ice_and_cob_times.slow_times,
+                cob_time,
+                self.ice_and_cob_times.activation,
+            )?;
+            keep_longest_garg_walk_warning(
+                &mut longest_walk_warning,
+                garg_walk_warning(&ice_and_cob_times),
+            );
+            let judgement = self.judge_cobs(
+                &cob_and_garg_rows,
+                Some(game::min_max_garg_x(&ice_and_cob_times)),
+                Some(ice_and_cob_times.is_iced()),
+            );
+            let Some((_, max_delay)) = judgement.safe_intercept_interval() else {
+                continue;
+            };
+            match ranges.last_mut() {
+                Some(range) if range.min_max_cob_time.1 == cob_time - 1 => {
+                    range.min_max_cob_time.1 = cob_time;
+                    if max_delay > range.max_delay {
+                        range.max_delay = max_delay;
+                        range.max_delay_cob_time = cob_time;
+                    }
+                }
+                _ => ranges.push(SolveRange {
+                    min_max_cob_time: (cob_time, cob_time),
+                    max_delay,
+                    max_delay_cob_time: cob_time,
+                }),
+            }
+        }
+        warnings.extend(longest_walk_warning);
+        Ok(SolveReport {
+            warnings,
+            cob,
+            garg_rows: garg_rows.clone(),
+            min_max_cob_time: (*min_cob_time, *max_cob_time),
+            ranges,
+        })
+    }
+
+    /// 沿用当前激活时机、减速及激活方式，以扫描的时机代替原有用冰
+    pub fn run_ice_solve(
+        &self,
+        IceSolveCommand {
+            min_max_ice_time: (min_ice_time, max_ice_time),
+            min_max_second_ice_time,
+            cob,
+            garg_rows,
+        }: &IceSolveCommand,
+    ) -> Result<IceSolveReport, String> {
+        let mut warnings = vec![];
+        let cob = corrected_cob(cob, &mut warnings);
+        let cob_and_garg_rows = [(cob.clone(), garg_rows.clone())];
+        // 不同用冰时机常得到相同的巨人x范围，按巨人x范围及是否减速缓存结果
+        let mut max_delays = HashMap::new();
+        let mut longest_walk_warning = None;
+        let mut max_delay_of = |ice_times: &[i32]| -> Result<Option<i32>, String> {
+            let ice_and_cob_times = game::IceAndCobTimes::of_times(
+                ice_times,
+                &self.ice_and_cob_times.slow_times,
+                self.ice_and_cob_times.cob_time,
+                self.ice_and_cob_times.activation,
+            )?;
+            keep_longest_garg_walk_warning(
+                &mut longest_walk_warning,
+                garg_walk_warning(&ice_and_cob_times),
+            );
+            let (min_garg_x, max_garg_x) = game::min_max_garg_x(&ice_and_cob_times);
+            let iced = ice_and_cob_times.is_iced();
+            let max_delay = max_delays
+                .entry((min_garg_x.to_bits(), max_garg_x.to_bits(), iced))
+                .or_insert_with(|| {
+                    self.judge_cobs(
+                        &cob_and_garg_rows,
+                        Some((min_garg_x, max_garg_x)),
+                        Some(iced),
+                    )
+                    .safe_intercept_interval()
+                    .map(|(_, max_delay)| max_delay)
+                });
+            Ok(*max_delay)
+        };
+        let mut ranges: Vec<IceSolveRange> = vec![];
+        match min_max_second_ice_time {
+            None => {
+                for ice_time in *min_ice_time..=*max_ice_time {
+                    if let Some(max_delay) = max_delay_of(&[ice_time])? {
+                        extend_ice_solve_ranges(&mut ranges, 0, &[ice_time], max_delay);
+                    }
+                }
+            }
+            Some((min_second, max_second)) => {
+                // 先在第二次用冰方向合并，再合并第一次用冰相邻且第二次范围相同的结果
+                for first in *min_ice_time..=*max_ice_time {
+                    let mut row: Vec<IceSolveRange> = vec![];
+                    for second in cmp::max(*min_second, first + 1)..=*max_second {
+                        if let Some(max_delay) = max_delay_of(&[first, second])? {
+                            extend_ice_solve_ranges(&mut row, 1, &[first, second], max_delay);
+                        }
+                    }
+                    for range in row {
+                        match ranges.iter_mut().find(|prev| {
+                            prev.min_max_ice_times[0].1 == first - 1
+                                && prev.min_max_ice_times[1] == range.min_max_ice_times[1]
+                        }) {
+                            Some(prev) => {
+                                prev.min_max_ice_times[0].1 = first;
+                                if range.max_delay > prev.max_delay {
+                                    prev.max_delay = range.max_delay;
+                                    prev.max_delay_ice_times = range.max_delay_ice_times;
+                                }
+                            }
+                            None => ranges.push(range),
+                        }
+                    }
+                }
+            }
+        }
+        warnings.extend(longest_walk_warning);
+        Ok(IceSolveReport {
+            warnings,
+            cob,
+            garg_rows: garg_rows.clone(),
+            cob_time: self.ice_and_cob_times.cob_time,
+            min_max_ice_times: [(*min_ice_time, *max_ice_time)]
+                .into_iter()
+                .chain(*min_max_second_ice_time)
+                .collect(),
+            ranges,
+        })
+    }
+
+    pub fn run_multi(
+        &self,
+        MultiCommand {
+            cobs,
+            min_max_garg_x,
+            ice_flag,
+        }: &MultiCommand,
+    ) -> MultiReport {
+        let mut warnings = vec![];
+        let cobs = cobs
+            .iter()
+            .map(|timed_cob| TimedCob {
+                cob: corrected_cob(&timed_cob.cob, &mut warnings),
+                ..timed_cob.clone()
+            })
+            .collect::<Vec<TimedCob>>();
+        let (garg_x_range, trajectories) = self.judge_timed_cobs(&cobs, *min_max_garg_x, *ice_flag);
+        let garg_x_range = self.judged_garg_x_range(&garg_x_range, *min_max_garg_x, &mut warnings);
+        MultiReport {
+            warnings,
+            cobs,
+            garg_x_range,
+            trajectories,
+        }
+    }
+
+    pub fn run_trace(
+        &self,
+        TraceCommand {
+            cob,
+            garg_x,
+            garg_row,
+            rnd,
+            ice_flag,
+        }: &TraceCommand,
+    ) -> TraceReport {
+        let mut warnings = vec![];
+        let cob = cob.as_ref().map(|cob| corrected_cob(cob, &mut warnings));
+        let explode = cob
+            .as_ref()
+            .map(|cob| game::Explode::of_cob(cob, &self.scene));
+        let iced = ice_flag.unwrap_or(self.is_iced());
+        TraceReport {
+            warnings,
+            explode_center: explode.as_ref().and_then(game::Explode::center),
+            ticks: game::trace(
+                *garg_x,
+                *garg_row,
+                *rnd,
+                iced,
+                &self.scene,
+                explode.as_ref(),
+            ),
+            cob,
+            garg_x: *garg_x,
+            garg_row: *garg_row,
+            rnd: *rnd,
+            iced,
+        }
+    }
+
+    pub fn run_imp(
+        &self,
+        ImpCommand {
+            min_max_imp_x: (min_imp_x, max_imp_x),
+        }: &ImpCommand,
+    ) -> Result<ImpReport, String> {
+        let roof = self.scene.is_roof();
+        let (lower_imp_x, upper_imp_x) = constants::min_max_imp_x(roof);
+        let input = if min_imp_x == max_imp_x {
+            min_imp_x.to_string()
+        } else {
+            format!("{min_imp_x},{max_imp_x}")
+        };
+        if *min_imp_x < lower_imp_x || *max_imp_x > upper_imp_x {
+            return Err(format!(
+                "{} ({INPUT_ERROR_GOT}: {input})",
+                IMP_X_SHOULD_BE_IN_RANGE.format(&[lower_imp_x, upper_imp_x])
+            ));
+        }
+        // 屋顶场合有些小鬼x坐标没有巨人能投掷，跳过即可
+        (*min_imp_x..=*max_imp_x)
+            .filter_map(|imp_x| constants::min_max_garg_pos_of_imp_x(imp_x, roof))
+            .reduce(|(min_garg_x, max_garg_x), (min, max)| {
+                (min_garg_x.min(min), max_garg_x.max(max))
+            })
+            .map(|min_max_garg_x| ImpReport {
+                min_max_imp_x: (*min_imp_x, *max_imp_x),
+                scene: self.scene,
+                min_max_garg_x,
+            })
+            .ok_or_else(|| format!("{NO_GARG_THROWS_IMP_X} ({INPUT_ERROR_GOT}: {input})"))
+    }
+
+    pub fn run_land(
+        &self,
+        LandCommand {
+            min_max_garg_x,
+            ice_flag,
+        }: &LandCommand,
+    ) -> LandReport {
+        let mut warnings = vec![];
+        let garg_x_range =
+            game::GargXRange::of_min_max_garg_pos(min_max_garg_x.unwrap_or(self.min_max_garg_x));
+        let iced = ice_flag.unwrap_or(self.is_iced());
+        let landing = game::imp_landing_range(&garg_x_range, iced, &self.scene);
+        LandReport {
+            garg_x_range: self.judged_garg_x_range(&garg_x_range, *min_max_garg_x, &mut warnings),
+            warnings,
+            iced,
+            landing,
+        }
+    }
+
+    pub fn run_lawn(&mut self, command: &LawnCommand) -> game::Lawn {
+        match command {
+            LawnCommand::Show => {}
+            LawnCommand::Clear => self.lawn = game::Lawn::default(),
+            LawnCommand::Set(lawn) => self.lawn = lawn.clone(),
+        }
+        self.lawn.clone()
+    }
+
+    pub fn run_eat(
+        &self,
+        EatCommand {
+            min_max_garg_x,
+            ice_flag,
+        }: &EatCommand,
+    ) -> EatReport {
+        let mut warnings = vec![];
+        let garg_x_range =
+            game::GargXRange::of_min_max_garg_pos(min_max_garg_x.unwrap_or(self.min_max_garg_x));
+        let iced = ice_flag.unwrap_or(self.is_iced());
+        let rows = game::eat_targets(
+            &garg_x_range,
+            &self.scene.garg_rows(),
+            iced,
+            &self.scene,
+            &self.lawn,
+        );
+        EatReport {
+            garg_x_range: self.judged_garg_x_range(&garg_x_range, *min_max_garg_x, &mut warnings),
+            warnings,
+            iced,
+            rows,
+        }
+    }
+
+    /// 按当前设置重新计算 `explode` 指令，给出其爆炸及巨人x极值、rnd极值的各条小鬼轨迹
+    pub fn run_svg(&self, SvgCommand { path, explode }: &SvgCommand) -> SvgReport {
+        let (warnings, explode_and_garg_rows, garg_x_range, ice_flag) = match explode {
+            ExplodeCommand::Delay(command) => {
+                let report = self.run_delay(command);
+                let ice_flag = match command {
+                    DelayCommand::Default { .. } => None,
+                    DelayCommand::Specific { garg_pos, .. } => garg_pos.ice_flag,
+                };
+                let explode_and_garg_rows = report
+                    .cob_and_garg_rows
+                    .into_iter()
+                    .map(|(cob, garg_rows)| (game::Explode::of_cob(&cob, &self.scene), garg_rows))
+                    .collect::<Vec<_>>();
+                (
+                    report.warnings,
+                    explode_and_garg_rows,
+                    report.garg_x_range,
+                    ice_flag,
+                )
+            }
+            ExplodeCommand::Ash(command) => {
+                let report = self.run_ash(command);
+                (
+                    report.warnings,
+                    vec![(
+                        game::Explode::of_ash(&report.ash, &self.scene),
+                        report.garg_rows,
+                    )],
+                    report.garg_x_range,
+                    command
+                        .garg_pos
+                        .as_ref()
+                        .and_then(|garg_pos| garg_pos.ice_flag),
+                )
+            }
+        };
+        let iced = ice_flag.unwrap_or(self.is_iced());
+        // 所得巨人x范围已按不投掷小鬼的情况修正
+        let traces = game::trace_extremes(
+            &game::GargXRange::of_min_max_garg_pos(garg_x_range.unwrap_or(self.min_max_garg_x)),
+            &explode_and_garg_rows,
+            iced,
+            &self.scene,
+        );
+        SvgReport {
+            warnings,
+            path: path.clone(),
+            scene: self.scene,
+            explodes: explode_and_garg_rows
+                .into_iter()
+                .map(|(explode, _)| explode)
+                .collect(),
+            garg_x_range,
+            iced,
+            traces,
+        }
+    }
+
+    fn judge_explodes(
+        &self,
+        explode_and_garg_rows: &[(game::Explode, &Vec<i32>)],
+        min_max_garg_x: Option<(f32, f32)>,
+        iced: Option<bool>,
+    ) -> Judgement {
+        let garg_x_range =
+            game::GargXRange::of_min_max_garg_pos(min_max_garg_x.unwrap_or(self.min_max_garg_x));
+        let (eat, intercept) = game::judge(
+            &garg_x_range,
+            explode_and_garg_rows,
+            iced.unwrap_or(self.is_iced()),
+            &self.scene,
+        );
+        Judgement {
+            garg_x_range,
+            eat,
+            intercept,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::ScanOutcome;
+
+    #[test]
+    fn test_set_wave() {
+        let mut calculator = Calculator::default();
+        assert_eq!(calculator.scene(), &game::Scene::PE);
+        assert!(!calculator.is_iced());
+
+        calculator
+            .set_wave(&[1, 400], &[], 800, game::Activation::Cob)
+            .unwrap();
+        assert_eq!(calculator.ice_and_cob_times().ice_times, vec![1, 400]);
+        assert!(calculator.is_iced());
+
+        assert!(calculator
+            .set_wave(&[], &[], -1, game::Activation::Cob)
+            .is_err());
+        assert_eq!(calculator.ice_and_cob_times().cob_time, 800);
+
+        // 冰瓜减速只持续1000
+        calculator
+            .set_wave(&[], &[1], 1001, game::Activation::Cob)
+            .unwrap();
+        assert!(calculator.is_iced());
+        calculator
+            .set_wave(&[], &[1], 1002, game::Activation::Cob)
+            .unwrap();
+        assert!(!calculator.is_iced());
+    }
+
+    #[test]
+    fn test_garg_walk_extrapolated_warning() {
+        let mut calculator = Calculator::default();
+        let is_extrapolated =
+            |warning: &Warning| matches!(warning, Warning::GargWalkExtrapolated { .. });
+        let delay = |calculator: &Calculator| {
+            calculator.run_delay(&DelayCommand::Specific {
+                cob: game::Cob::ground(1, 8.8),
+                garg_pos: GargPos {
+                    garg_rows: vec![1],
+                    min_max_garg_x: None,
+                    ice_flag: None,
+                },
+            })
+        };
+        let wave = calculator.run_wave(&WaveCommand::Show).unwrap();
+        assert!(!wave.warnings.iter().any(is_extrapolated));
+        assert!(!delay(&calculator).warnings.iter().any(is_extrapolated));
+
+        // 原速行走超出 GARG_DATA_SIZE
+        let wave = calculator
+            .run_wave(&WaveCommand::Set {
+                ice_times: vec![],
+                slow_times: vec![],
+                cob_time: 5000,
+                activation: game::Activation::Cob,
+            })
+            .unwrap();
+        assert!(wave.warnings.iter().any(is_extrapolated));
+        assert!(delay(&calculator).warnings.iter().any(is_extrapolated));
+        let solve = calculator
+            .run_solve(&SolveCommand {
+                min_max_cob_time: (4000, 4200),
+                cob: game::Cob::ground(1, 8.8),
+                garg_rows: vec![1],
+            })
+            .unwrap();
+        assert_eq!(
+            solve.warnings,
+            vec![Warning::GargWalkExtrapolated { max_walk: 4200. }]
+        );
+    }
+
+    #[test]
+    fn test_plant_activation() {
+        let wave = |cob_time, activation| {
+            let mut calculator = Calculator::default();
+            calculator
+                .set_wave(&[1, 400], &[], cob_time, activation)
+                .unwrap();
+            calculator
+        };
+        let plant = wave(800, game::Activation::Plant);
+        let cob = wave(801, game::Activation::Cob);
+        // 植物激活按晚1cs的巨人坐标计算
+        assert_eq!(plant.min_max_garg_x(), cob.min_max_garg_x());
+        assert_eq!(plant.is_iced(), cob.is_iced());
+        let delay = DelayCommand::Default {
+            delay_mode: None,
+            hit_col: 8.8,
+            cob_col: None,
+            cob_row: None,
+        };
+        assert_eq!(plant.run_delay(&delay), cob.run_delay(&delay));
+        assert_eq!(
+            plant.delayed(100).unwrap().1,
+            wave(900, game::Activation::Cob).delayed(1).unwrap().1
+        );
+    }
+
+    #[test]
+    fn test_judge_cobs() {
+        let calculator = Calculator::default();
+        let judgement = calculator.judge_cobs(
+            &[(game::Cob::ground(1, 8.5), vec![1])],
+            Some((800., 800.)),
+            Some(false),
+        );
+        assert_eq!(
+            judgement.eat,
+            game::Eat::Some {
+                eat: 269,
+                iceable: 297
+            }
+        );
+        assert_eq!(judgement.intercept.interval(), Some((107, 128)));
+        assert_eq!(judgement.safe_intercept_interval(), Some((107, 128)));
+        assert_eq!(judgement.unsafe_intercept_interval(), None);
+
+        let judgement = calculator.judge_cobs(
+            &[(game::Cob::ground(1, 8.5), vec![1])],
+            Some((300., 400.)),
+            None,
+        );
+        assert_eq!(judgement.garg_x_range, game::GargXRange::Cancelled);
+        assert_eq!(judgement.eat, game::Eat::Empty);
+    }
+
+    #[test]
+    fn test_run_ash() {
+        let calculator = Calculator::default();
+        let run = |ash| {
+            calculator.run_ash(&AshCommand {
+                ash,
+                garg_pos: None,
+            })
+        };
+        let cherry = run(game::Ash::Cherry { row: 2, col: 8 });
+        assert_eq!(cherry.garg_rows, vec![1, 2]);
+        let jalapeno = run(game::Ash::Jalapeno { row: 2 });
+        assert_eq!(jalapeno.garg_rows, vec![2]);
+        // 辣椒炸到整行，不受爆炸范围限制
+        let row_2 = |ash| {
+            calculator.run_ash(&AshCommand {
+                ash,
+                garg_pos: Some(GargPos {
+                    garg_rows: vec![2],
+                    min_max_garg_x: None,
+                    ice_flag: None,
+                }),
+            })
+        };
+        let cherry = row_2(game::Ash::Cherry { row: 2, col: 8 });
+        let jalapeno = row_2(game::Ash::Jalapeno { row: 2 });
+        assert!(cherry.explode_center.is_some());
+        assert_eq!(jalapeno.explode_center, None);
+        assert_eq!(cherry.intercept.interval(), Some((108, 129)));
+        assert_eq!(
+            jalapeno.intercept.interval(),
+            Some((108, game::MAX_INTERCEPTION_DELAY))
+        );
+    }
+
+    #[test]
+    fn test_run_delay_corrects_hit_col() {
+        let calculator = Calculator::default();
+        let report = calculator.run_delay(&DelayCommand::Default {
+            delay_mode: None,
+            hit_col: 8.81,
+            cob_col: None,
+            cob_row: None,
+        });
+        assert_eq!(
+            report.warnings,
+            vec![Warning::HitColCorrected {
+                hit_col: 8.81,
+                corrected_hit_col: 8.8125
+            }]
+        );
+        assert_eq!(report.cob_and_garg_rows.len(), 2);
+    }
+
+    #[test]
+    fn test_run_delay_uses_cob_row() {
+        let mut calculator = Calculator::default();
+        calculator.set_scene(game::Scene::RE);
+        let cob_rows = |cob_row| {
+            calculator
+                .run_delay(&DelayCommand::Default {
+                    delay_mode: None,
+                    hit_col: 3.8375,
+                    cob_col: Some(4),
+                    cob_row,
+                })
+                .cob_and_garg_rows
+                .iter()
+                .map(|(cob, _)| cob.cob_row())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(cob_rows(None), vec![Some(DEFAULT_ROOF_COB_ROW); 2]);
+        assert_eq!(cob_rows(Some(1)), vec![Some(1); 2]);
+    }
+
+    #[test]
+    fn test_run_hit_uses_cob_row() {
+        let mut calculator = Calculator::default();
+        calculator.set_scene(game::Scene::RE);
+        let run_hit = |mode, cob_row| {
+            calculator
+                .run_hit(&HitCommand {
+                    mode,
+                    cob_col: Some(4),
+                    cob_row,
+                    delay_time: None,
+                })
+                .unwrap()
+        };
+        assert_eq!(
+            run_hit(HitMode::Hit, None).cob_tail,
+            Some((DEFAULT_ROOF_COB_ROW, 4))
+        );
+        let report = run_hit(HitMode::Nohit, Some(1));
+        assert_eq!(report.cob_tail, Some((1, 4)));
+        // 炮距只与炮尾列有关
+        assert_eq!(report.hit_pixels, run_hit(HitMode::Nohit, None).hit_pixels);
+    }
+
+    #[test]
+    fn test_run_solve() {
+        let mut calculator = Calculator::default();
+        let cob = game::Cob::ground(1, 8.8);
+        let solve = |calculator: &Calculator, min_max_cob_time| {
+            calculator
+                .run_solve(&SolveCommand {
+                    min_max_cob_time,
+                    cob: cob.clone(),
+                    garg_rows: vec![1],
+                })
+                .unwrap()
+                .ranges
+        };
+        assert_eq!(
+            solve(&calculator, (200, 700)),
+            vec![SolveRange {
+                min_max_cob_time: (200, 700),
+                max_delay: 127,
+                max_delay_cob_time: 200,
+            }]
+        );
+        // 与 delay 在相同激活时机下的结果一致
+        calculator
+            .set_wave(&[1], &[], 500, game::Activation::Cob)
+            .unwrap();
+        let delay = calculator.run_delay(&DelayCommand::Specific {
+            cob: cob.clone(),
+            garg_pos: GargPos {
+                garg_rows: vec![1],
+                min_max_garg_x: None,
+                ice_flag: None,
+            },
+        });
+        let (_, max_delay) = game::safe_intercept_interval(&delay.eat, &delay.intercept).unwrap();
+        assert_eq!(solve(&calculator, (500, 500))[0].max_delay, max_delay);
+    }
+
+    #[test]
+    fn test_run_scan() {
+        let calculator = Calculator::default();
+        let garg_pos = GargPos {
+            garg_rows: vec![1],
+            min_max_garg_x: None,
+            ice_flag: None,
+        };
+        let report = calculator.run_scan(&ScanCommand {
+            hit_row: 1,
+            min_max_hit_col: (8., 8.1),
+            cob_col: None,
+            cob_row: None,
+            garg_pos: garg_pos.clone(),
+            min_max_delay: Some((100, 130)),
+            csv: false,
+        });
+        assert_eq!(report.rows.len(), 9);
+        // 每个像素列与 delay 的结果一致
+        for row in &report.rows {
+            let delay = calculator.run_delay(&DelayCommand::Specific {
+                cob: game::Cob::ground(1, row.hit_col),
+                garg_pos: garg_pos.clone(),
+            });
+            assert_eq!((&row.eat, &row.intercept), (&delay.eat, &delay.intercept));
+        }
+        // 最大延迟与 max 一致
+        let max_delay = calculator
+            .run_max_delay(&MaxDelayCommand {
+                hit_row: 1,
+                min_max_hit_col: (8., 8.1),
+                cob_col: None,
+                cob_row: None,
+                garg_pos,
+            })
+            .max_delay
+            .unwrap();
+        let (_, max) = game::safe_intercept_interval(&max_delay.eat, &max_delay.intercept).unwrap();
+        let row = &report.rows[0];
+        assert_eq!(
+            game::safe_intercept_interval(&row.eat, &row.intercept).map(|(_, max)| max),
+            Some(max)
+        );
+        assert_eq!(row.outcome(max), ScanOutcome::Harmless);
+        assert_eq!(row.outcome(max + 1), ScanOutcome::Miss);
+
+        // 可拦区间不设上限时，晚于 MAX_INTERCEPTION_DELAY 依然可以拦截
+        let row = ScanRow {
+            hit_col: 8.8,
+            eat: game::Eat::Some {
+                eat: 269,
+                iceable: 297,
+            },
+            intercept: game::Intercept::Success {
+                min: 107,
+                max: game::MAX_INTERCEPTION_DELAY,
+            },
+        };
+        assert_eq!(row.outcome(200), ScanOutcome::Harmless);
+        assert_eq!(
+            row.outcome(game::MAX_INTERCEPTION_DELAY + 1),
+            ScanOutcome::Harmful
+        );
+        assert_eq!(row.outcome(100), ScanOutcome::Miss);
+    }
+
+    #[test]
+    fn test_run_svg() {
+        let calculator = Calculator::default();
+        let command = DelayCommand::Specific {
+            cob: game::Cob::ground(1, 8.8),
+            garg_pos: GargPos {
+                garg_rows: vec![1],
+                min_max_garg_x: None,
+                ice_flag: None,
+            },
+        };
+        let report = calculator.run_svg(&SvgCommand {
+            path: "a.svg".to_string(),
+            explode: ExplodeCommand::Delay(command.clone()),
+        });
+        assert_eq!(
+            report
+                .explodes
+                .iter()
+                .map(game::Explode::center)
+                .collect::<Vec<_>>(),
+            vec![calculator.run_delay(&command).explode_center]
+        );
+        // 巨人x极值及 rnd 极值的组合
+        assert_eq!(report.traces.len(), 4);
+        assert!(report.traces.iter().all(|trace| trace.garg_row == 1));
+
+        // 默认炮位的每门炮各有一个爆炸
+        let report = calculator.run_svg(&SvgCommand {
+            path: "a.svg".to_string(),
+            explode: ExplodeCommand::Delay(DelayCommand::Default {
+                delay_mode: Some(game::DelayMode::Delay1),
+                hit_col: 8.8,
+                cob_col: None,
+                cob_row: None,
+            }),
+        });
+        assert_eq!(report.explodes.len(), 2);
+
+        let report = calculator.run_svg(&SvgCommand {
+            path: "a.svg".to_string(),
+            explode: ExplodeCommand::Ash(AshCommand {
+                ash: game::Ash::Jalapeno { row: 2 },
+                garg_pos: None,
+            }),
+        });
+        assert_eq!(report.explodes[0].range, None);
+        assert!(report.traces.iter().all(|trace| trace.garg_row == 2));
+    }
+
+    #[test]
+    fn test_run_ice_solve() {
+        let mut calculator = Calculator::default();
+        calculator
+            .set_wave(&[], &[], 1200, game::Activation::Cob)
+            .unwrap();
+        let ice_solve = |min_max_ice_time, min_max_second_ice_time| {
+            calculator
+                .run_ice_solve(&IceSolveCommand {
+                    min_max_ice_time,
+                    min_max_second_ice_time,
+                    cob: game::Cob::ground(1, 7.),
+                    garg_rows: vec![1],
+                })
+                .unwrap()
+        };
+        let report = ice_solve((1, 1200), None);
+        assert_eq!(
+            report.ranges,
+            vec![IceSolveRange {
+                min_max_ice_times: vec![(716, 1200)],
+                max_delay: 243,
+                max_delay_ice_times: vec![1149],
+            }]
+        );
+        assert_eq!(report.best(), report.ranges.first());
+        // wave 692 701 1200 $ delay 1 7 > 1 的可拦区间为 216~233
+        let report = ice_solve((692, 692), Some((701, 701)));
+        assert_eq!(report.min_max_ice_times, vec![(692, 692), (701, 701)]);
+        assert_eq!(report.best().map(|best| best.max_delay), Some(233));
+        assert!(ice_solve((1, 50), Some((1101, 1200))).ranges.is_empty());
+    }
+
+    #[test]
+    fn test_run_imp_depends_on_roof() {
+        let mut calculator = Calculator::default();
+        let imp = |calculator: &Calculator, imp_x| {
+            calculator
+                .run_imp(&ImpCommand {
+                    min_max_imp_x: (imp_x, imp_x),
+                })
+                .map(|report| report.min_max_garg_x)
+        };
+        assert_eq!(imp(&calculator, 67), Ok((401., 401.999)));
+        assert!(imp(&calculator, 13).is_err());
+        calculator.set_scene(game::Scene::ME);
+        assert_eq!(imp(&calculator, 13), Ok((401., 401.999)));
+        assert!(imp(&calculator, 400).is_ok());
+        // 屋顶场合有些小鬼x坐标没有巨人能投掷
+        assert_eq!(
+            imp(&calculator, 148),
+            Err(format!("{NO_GARG_THROWS_IMP_X} ({INPUT_ERROR_GOT}: 148)"))
+        );
+    }
+
+    #[test]
+    fn test_run_imp_range() {
+        let mut calculator = Calculator::default();
+        let imp = |calculator: &Calculator, min_max_imp_x| {
+            calculator
+                .run_imp(&ImpCommand { min_max_imp_x })
+                .map(|report| report.min_max_garg_x)
+        };
+        let single = |calculator: &Calculator, imp_x| imp(calculator, (imp_x, imp_x)).unwrap();
+        // 各小鬼x坐标对应的巨人x范围并不随小鬼x坐标单调
+        assert_eq!(imp(&calculator, (150, 180)), Ok((500.001, 854.)));
+        assert_eq!(
+            imp(&calculator, (150, 180)),
+            Ok((single(&calculator, 151).0, single(&calculator, 172).1))
+        );
+        assert_eq!(imp(&calculator, (67, 67)), Ok(single(&calculator, 67)));
+        assert!(imp(&calculator, (60, 180)).is_err());
+        calculator.set_scene(game::Scene::ME);
+        // 范围内没有巨人能投掷的小鬼x坐标被跳过
+        assert_eq!(
+            imp(&calculator, (147, 150)),
+            Ok((single(&calculator, 147).0, single(&calculator, 150).1))
+        );
+        assert_eq!(
+            imp(&calculator, (148, 149)),
+            Err(format!(
+                "{NO_GARG_THROWS_IMP_X} ({INPUT_ERROR_GOT}: 148,149)"
+            ))
+        );
+    }
+}
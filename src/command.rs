@@ -0,0 +1,193 @@
+use crate::game;
+
+/// 结果的输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// 供阅读的文本
+    Text,
+    /// 每个结果一行 JSON，格式见 doc/json.md
+    Json,
+}
+
+/// ">" 之后指定的巨人位置
+#[derive(Debug, Clone, PartialEq)]
+pub struct GargPos {
+    pub garg_rows: Vec<i32>,
+    /// 为 `None` 时使用当前设置
+    pub min_max_garg_x: Option<(f32, f32)>,
+    /// 是否按减速计算；为 `None` 时使用当前设置
+    pub ice_flag: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WaveCommand {
+    Show,
+    Set {
+        ice_times: Vec<i32>,
+        slow_times: Vec<i32>,
+        cob_time: i32,
+        activation: game::Activation,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DelayCommand {
+    /// 按拦截行数计算所有默认炮位
+    Default {
+        delay_mode: Option<game::DelayMode>,
+        hit_col: f32,
+        cob_col: Option<i32>,
+        /// 炮尾所在行（仅屋顶）；为 `None` 时使用默认值
+        cob_row: Option<i32>,
+    },
+    /// 计算一门炮拦截特定巨人
+    Specific { cob: game::Cob, garg_pos: GargPos },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AshCommand {
+    pub ash: game::Ash,
+    /// 为 `None` 时计算所有可炸到的巨人
+    pub garg_pos: Option<GargPos>,
+}
+
+/// 秒杀植物能否在啃食前杀死小鬼
+#[derive(Debug, Clone, PartialEq)]
+pub struct KillerCommand {
+    pub plant: game::KillerPlant,
+    /// 为 `None` 时计算植物所在行的巨人
+    pub garg_pos: Option<GargPos>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitMode {
+    Hit,
+    Nohit,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HitCommand {
+    pub mode: HitMode,
+    pub cob_col: Option<i32>,
+    /// 炮尾所在行（仅屋顶）；炮距只与炮尾列有关，仅用于回显
+    pub cob_row: Option<i32>,
+    pub delay_time: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxDelayCommand {
+    pub hit_row: i32,
+    pub min_max_hit_col: (f32, f32),
+    pub cob_col: Option<i32>,
+    /// 炮尾所在行（仅屋顶）；为 `None` 时使用默认值
+    pub cob_row: Option<i32>,
+    pub garg_pos: GargPos,
+}
+
+/// 列出炮落点列范围内每个像素列的计算结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanCommand {
+    pub hit_row: i32,
+    pub min_max_hit_col: (f32, f32),
+    pub cob_col: Option<i32>,
+    /// 炮尾所在行（仅屋顶）；为 `None` 时使用默认值
+    pub cob_row: Option<i32>,
+    pub garg_pos: GargPos,
+    /// 给出时逐个延迟标出能否拦截
+    pub min_max_delay: Option<(i32, i32)>,
+    /// 以 CSV 而非表格打印
+    pub csv: bool,
+}
+
+/// 按当前用冰扫描激活时机，寻找可无伤拦截的时机
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveCommand {
+    pub min_max_cob_time: (i32, i32),
+    pub cob: game::Cob,
+    pub garg_rows: Vec<i32>,
+}
+
+/// 固定激活时机，扫描一或两次用冰的时机，寻找可无伤拦截的用冰
+#[derive(Debug, Clone, PartialEq)]
+pub struct IceSolveCommand {
+    pub min_max_ice_time: (i32, i32),
+    /// 为 `None` 时只用一次冰；第二次用冰晚于第一次
+    pub min_max_second_ice_time: Option<(i32, i32)>,
+    pub cob: game::Cob,
+    pub garg_rows: Vec<i32>,
+}
+
+/// 在指定时机生效的炮
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedCob {
+    pub cob: game::Cob,
+    /// 生效时机，与可拦区间的含义相同
+    pub time: i32,
+    pub garg_rows: Vec<i32>,
+}
+
+/// 多门炮在不同时机生效的拦截
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiCommand {
+    pub cobs: Vec<TimedCob>,
+    /// 为 `None` 时使用当前设置
+    pub min_max_garg_x: Option<(f32, f32)>,
+    /// 是否按减速计算；为 `None` 时使用当前设置
+    pub ice_flag: Option<bool>,
+}
+
+/// 逐刻查看一只小鬼的轨迹
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceCommand {
+    /// 给定时标出能炸到小鬼的时刻
+    pub cob: Option<game::Cob>,
+    pub garg_x: f32,
+    pub garg_row: i32,
+    pub rnd: i32,
+    /// 是否按减速计算；为 `None` 时使用当前设置
+    pub ice_flag: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImpCommand {
+    /// 单个小鬼x坐标时两端相同
+    pub min_max_imp_x: (i32, i32),
+}
+
+/// 计算小鬼落地位置
+#[derive(Debug, Clone, PartialEq)]
+pub struct LandCommand {
+    /// 为 `None` 时使用当前设置
+    pub min_max_garg_x: Option<(f32, f32)>,
+    pub ice_flag: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LawnCommand {
+    Show,
+    Clear,
+    Set(game::Lawn),
+}
+
+/// 按场地布局计算小鬼的啃食目标
+#[derive(Debug, Clone, PartialEq)]
+pub struct EatCommand {
+    /// 为 `None` 时使用当前设置
+    pub min_max_garg_x: Option<(f32, f32)>,
+    pub ice_flag: Option<bool>,
+}
+
+/// 可导出为图片的爆炸指令
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExplodeCommand {
+    Delay(DelayCommand),
+    Ash(AshCommand),
+}
+
+/// 将爆炸及小鬼轨迹导出为 SVG 图片
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgCommand {
+    pub path: String,
+    /// 上次执行的 delay、doom 等指令
+    pub explode: ExplodeCommand,
+}
@@ -1,43 +1,76 @@
 pub const GARG_DATA_SIZE: usize = 4100;
 const DIVISOR: f32 = 32768.;
 
-pub fn garg_fast_of_half_ticks(half_ticks: i32) -> Option<f32> {
-    garg_of_half_ticks(&GARG_FAST, half_ticks)
+// 巨人行走动画一个循环的帧数（原速）；循环间位移基本相同
+const GARG_FAST_CYCLE: usize = 714;
+const GARG_SLOW_CYCLE: usize = 1148;
+
+/// 巨人行走 `half_ticks` 个 0.5cs 的位移（原速行走较快的情形）
+pub fn garg_fast_of_half_ticks(half_ticks: i32) -> f32 {
+    garg_of_half_ticks(&GARG_FAST, GARG_FAST_CYCLE, half_ticks)
 }
 
-pub fn garg_slow_of_half_ticks(half_ticks: i32) -> Option<f32> {
-    garg_of_half_ticks(&GARG_SLOW, half_ticks)
+/// 巨人行走 `half_ticks` 个 0.5cs 的位移（原速行走较慢的情形）
+pub fn garg_slow_of_half_ticks(half_ticks: i32) -> f32 {
+    garg_of_half_ticks(&GARG_SLOW, GARG_SLOW_CYCLE, half_ticks)
 }
 
-fn garg_of_half_ticks(array: &[i32; GARG_DATA_SIZE], half_ticks: i32) -> Option<f32> {
-    if half_ticks % 2 == 0 {
-        array
-            .get((half_ticks / 2) as usize)
-            .map(|v| *v as f32 / DIVISOR)
+fn garg_of_half_ticks(table: &[i32], cycle: usize, half_ticks: i32) -> f32 {
+    debug_assert!(half_ticks >= 0, "{half_ticks}");
+    let ticks = (half_ticks / 2) as usize;
+    let walk = if half_ticks % 2 == 0 {
+        garg_walk_of_ticks(table, cycle, ticks)
     } else {
-        let (lower, higher) = (half_ticks - 1, half_ticks + 1);
-        match (
-            array.get((lower / 2) as usize),
-            array.get((higher / 2) as usize),
-        ) {
-            (None, _) | (_, None) => None,
-            (Some(lower_walk), Some(higher_walk)) => {
-                Some((*lower_walk as f32 * 0.5 + *higher_walk as f32 * 0.5) / DIVISOR)
-            }
-        }
+        garg_walk_of_ticks(table, cycle, ticks) * 0.5
+            + garg_walk_of_ticks(table, cycle, ticks + 1) * 0.5
+    };
+    (walk / DIVISOR as f64) as f32
+}
+
+// 表内直接查表；超出表的部分按行走周期外推（并非位移模型）：将表中最后一个完整循环
+// 平移整数个循环，每个循环的位移取表中各完整循环的平均值。巨人进家前的行走时间均可外推
+fn garg_walk_of_ticks(table: &[i32], cycle: usize, ticks: usize) -> f64 {
+    if let Some(&walk) = table.get(ticks) {
+        return walk as f64;
     }
+    let full_cycles = (table.len() - 1) / cycle;
+    let walk_per_cycle = (table[full_cycles * cycle] - table[0]) as f64 / full_cycles as f64;
+    let last_cycle_start = table.len() - cycle;
+    let shifted_cycles = (ticks - last_cycle_start) / cycle;
+    table[ticks - shifted_cycles * cycle] as f64 + shifted_cycles as f64 * walk_per_cycle
+}
+
+/// 行走 `half_ticks` 个 0.5cs 的位移是否超出表长、按行走周期外推
+pub fn garg_walk_extrapolated(half_ticks: i32) -> bool {
+    half_ticks > (GARG_DATA_SIZE as i32 - 1) * 2
 }
 
-pub fn min_max_garg_pos_of_imp_x(imp_x: i32) -> Option<(f32, f32)> {
-    MIN_MAX_GARG_X_OF_IMP_POS
-        .get((imp_x - MIN_IMP_X) as usize)
+/// 小鬼x坐标对照表的范围
+pub fn min_max_imp_x(roof: bool) -> (i32, i32) {
+    if roof {
+        (ROOF_MIN_IMP_X, ROOF_MAX_IMP_X)
+    } else {
+        (MIN_IMP_X, MAX_IMP_X)
+    }
+}
+
+pub fn min_max_garg_pos_of_imp_x(imp_x: i32, roof: bool) -> Option<(f32, f32)> {
+    let table: &[Option<(f32, f32)>] = if roof {
+        &ROOF_MIN_MAX_GARG_X_OF_IMP_POS
+    } else {
+        &MIN_MAX_GARG_X_OF_IMP_POS
+    };
+    table
+        .get((imp_x - min_max_imp_x(roof).0) as usize)
         .cloned()
+        .flatten()
 }
 
 // GARG_FAST and GARG_SLOW are provided by Reisen.
 // See alumkal/pvz-interception-calculator
+// 超出表长的部分由 garg_walk_of_ticks 按行走周期外推，表本身兼作其回归测试数据
 
-const GARG_FAST: [i32; GARG_DATA_SIZE] = [
+static GARG_FAST: [i32; GARG_DATA_SIZE] = [
     0, 10124, 20248, 30372, 40496, 50620, 60744, 70868, 80992, 91116, 101240, 111364, 121488,
     131612, 141736, 151860, 161984, 172108, 182232, 192356, 202480, 212604, 222728, 232852, 242976,
     253100, 263224, 273348, 283472, 293596, 303720, 313844, 323968, 334092, 344216, 354340, 364464,
@@ -476,7 +509,7 @@ const GARG_FAST: [i32; GARG_DATA_SIZE] = [
     24498576, 24508471, 24518366, 24528262, 24538157,
 ];
 
-const GARG_SLOW: [i32; GARG_DATA_SIZE] = [
+static GARG_SLOW: [i32; GARG_DATA_SIZE] = [
     0, 6294, 12588, 18882, 25176, 31470, 37764, 44058, 50352, 56646, 62940, 69234, 75528, 81822,
     88116, 94410, 100704, 106998, 113292, 119586, 125880, 132174, 138468, 144762, 151056, 157350,
     163644, 169938, 176232, 182526, 188820, 195114, 201408, 207702, 213996, 220290, 226584, 232878,
@@ -903,203 +936,673 @@ const GARG_SLOW: [i32; GARG_DATA_SIZE] = [
     14878932, 14882289, 14885646, 14889003,
 ];
 
-pub const MIN_IMP_X: i32 = 67; // MIN_MAX_GARG_X_OF_IMP_POS第一个值对应imp_pos=67，第二个对应68，依次类推
-                               // 之所以这样储存，主要是因为rust不支持编译期定义HashMap
-                               // 此表由遍历x=401~854所有巨人得到，遍历精度Δx=0.001，并非100%精确
+// 以下小鬼x坐标对照表由 `cargo run --release --example gen_imp_table` 生成，请勿手动修改。
+// 第一个值对应小鬼x坐标为 MIN_IMP_X 时投掷该小鬼的巨人x范围，第二个对应 MIN_IMP_X+1，依次类推；
+// 没有巨人会投掷该坐标的小鬼时为 None。之所以这样储存，主要是因为rust不支持编译期定义HashMap
+
+pub const MIN_IMP_X: i32 = 67;
 pub const MAX_IMP_X: i32 = 260;
-const MIN_MAX_GARG_X_OF_IMP_POS: [(f32, f32); (MAX_IMP_X - MIN_IMP_X + 1) as usize] = [
-    (401.000, 401.999),
-    (402.000, 402.999),
-    (403.000, 406.999),
-    (404.000, 407.999),
-    (405.000, 411.999),
-    (406.000, 412.999),
-    (410.000, 416.999),
-    (411.000, 417.999),
-    (415.000, 418.999),
-    (416.000, 422.999),
-    (420.000, 423.999),
-    (421.000, 427.999),
-    (422.000, 428.999),
-    (426.000, 432.999),
-    (427.000, 433.999),
-    (431.000, 437.999),
-    (432.000, 438.999),
-    (436.000, 442.999),
-    (437.000, 443.999),
-    (441.000, 447.999),
-    (442.000, 448.999),
-    (446.000, 452.999),
-    (447.000, 456.999),
-    (451.000, 457.999),
-    (452.000, 461.999),
-    (456.000, 462.999),
-    (460.000, 466.999),
-    (461.000, 467.999),
-    (465.000, 471.999),
-    (466.000, 475.999),
-    (470.000, 476.999),
-    (474.000, 480.999),
-    (475.000, 484.999),
-    (479.000, 485.999),
-    (480.000, 489.999),
-    (484.000, 493.999),
-    (488.000, 494.999),
-    (489.000, 498.999),
-    (493.000, 502.999),
-    (497.000, 503.999),
-    (498.000, 507.999),
-    (500.001, 511.999),
-    (500.001, 515.999),
-    (500.001, 519.999),
-    (500.001, 520.999),
-    (500.001, 524.999),
-    (500.001, 528.999),
-    (500.001, 532.999),
-    (500.001, 536.999),
-    (500.001, 537.999),
-    (500.001, 541.999),
-    (500.001, 545.999),
-    (500.001, 549.999),
-    (500.001, 553.999),
-    (500.001, 557.999),
-    (500.001, 561.999),
-    (500.001, 565.999),
-    (500.001, 569.999),
-    (500.001, 573.999),
-    (500.001, 577.999),
-    (500.001, 581.999),
-    (500.001, 585.999),
-    (500.001, 589.999),
-    (500.001, 593.999),
-    (500.001, 600.999),
-    (500.001, 604.999),
-    (500.001, 608.999),
-    (500.001, 612.999),
-    (500.001, 619.999),
-    (500.001, 623.999),
-    (500.001, 627.999),
-    (500.001, 631.999),
-    (500.001, 638.999),
-    (500.001, 642.999),
-    (500.001, 649.999),
-    (500.001, 653.999),
-    (500.001, 660.999),
-    (500.001, 664.999),
-    (500.001, 671.999),
-    (500.001, 675.999),
-    (500.001, 682.999),
-    (500.001, 689.999),
-    (500.001, 696.999),
-    (500.001, 700.999),
-    (500.001, 707.999),
-    (500.001, 714.999),
-    (500.001, 721.999),
-    (500.001, 728.999),
-    (500.001, 735.999),
-    (500.001, 742.999),
-    (500.001, 752.999),
-    (500.001, 759.999),
-    (500.001, 766.999),
-    (500.001, 773.999),
-    (500.001, 783.999),
-    (500.001, 790.999),
-    (500.001, 800.999),
-    (500.001, 810.999),
-    (500.001, 820.999),
-    (500.001, 827.999),
-    (500.001, 837.999),
-    (500.001, 850.999),
-    (500.001, 851.999),
-    (504.000, 852.999),
-    (505.000, 853.999),
-    (506.000, 854.000),
-    (510.000, 854.000),
-    (511.000, 854.000),
-    (515.000, 854.000),
-    (516.000, 854.000),
-    (520.000, 854.000),
-    (521.000, 854.000),
-    (522.000, 854.000),
-    (526.000, 854.000),
-    (527.000, 854.000),
-    (531.000, 854.000),
-    (532.000, 854.000),
-    (536.000, 854.000),
-    (537.000, 854.000),
-    (541.000, 854.000),
-    (542.000, 854.000),
-    (546.000, 854.000),
-    (547.000, 854.000),
-    (551.000, 854.000),
-    (552.000, 854.000),
-    (556.000, 854.000),
-    (560.000, 854.000),
-    (561.000, 854.000),
-    (565.000, 854.000),
-    (566.000, 854.000),
-    (570.000, 854.000),
-    (574.000, 854.000),
-    (575.000, 854.000),
-    (579.000, 854.000),
-    (580.000, 854.000),
-    (584.000, 854.000),
-    (588.000, 854.000),
-    (589.000, 854.000),
-    (593.000, 854.000),
-    (597.000, 854.000),
-    (598.000, 854.000),
-    (602.000, 854.000),
-    (606.000, 854.000),
-    (610.000, 854.000),
-    (611.000, 854.000),
-    (615.000, 854.000),
-    (619.000, 854.000),
-    (623.000, 854.000),
-    (624.000, 854.000),
-    (628.000, 854.000),
-    (632.000, 854.000),
-    (636.000, 854.000),
-    (640.000, 854.000),
-    (644.000, 854.000),
-    (645.000, 854.000),
-    (649.000, 854.000),
-    (653.000, 854.000),
-    (657.000, 854.000),
-    (661.000, 854.000),
-    (665.000, 854.000),
-    (669.000, 854.000),
-    (673.000, 854.000),
-    (677.000, 854.000),
-    (681.000, 854.000),
-    (685.000, 854.000),
-    (689.000, 854.000),
-    (693.000, 854.000),
-    (700.000, 854.000),
-    (704.000, 854.000),
-    (708.000, 854.000),
-    (712.000, 854.000),
-    (716.000, 854.000),
-    (723.000, 854.000),
-    (727.000, 854.000),
-    (731.000, 854.000),
-    (738.000, 854.000),
-    (742.000, 854.000),
-    (746.000, 854.000),
-    (753.000, 854.000),
-    (757.000, 854.000),
-    (764.000, 854.000),
-    (768.000, 854.000),
-    (775.000, 854.000),
-    (782.000, 854.000),
-    (786.000, 854.000),
-    (793.000, 854.000),
-    (800.000, 854.000),
-    (804.000, 854.000),
-    (811.000, 854.000),
-    (818.000, 854.000),
-    (825.000, 854.000),
-    (832.000, 854.000),
-    (839.000, 854.000),
-    (846.000, 852.980),
+const MIN_MAX_GARG_X_OF_IMP_POS: [Option<(f32, f32)>; (MAX_IMP_X - MIN_IMP_X + 1) as usize] = [
+    Some((401.000, 401.999)),
+    Some((402.000, 402.999)),
+    Some((403.000, 406.999)),
+    Some((404.000, 407.999)),
+    Some((405.000, 411.999)),
+    Some((406.000, 412.999)),
+    Some((410.000, 416.999)),
+    Some((411.000, 417.999)),
+    Some((415.000, 418.999)),
+    Some((416.000, 422.999)),
+    Some((420.000, 423.999)),
+    Some((421.000, 427.999)),
+    Some((422.000, 428.999)),
+    Some((426.000, 432.999)),
+    Some((427.000, 433.999)),
+    Some((431.000, 437.999)),
+    Some((432.000, 438.999)),
+    Some((436.000, 442.999)),
+    Some((437.000, 443.999)),
+    Some((441.000, 447.999)),
+    Some((442.000, 448.999)),
+    Some((446.000, 452.999)),
+    Some((447.000, 456.999)),
+    Some((451.000, 457.999)),
+    Some((452.000, 461.999)),
+    Some((456.000, 462.999)),
+    Some((460.000, 466.999)),
+    Some((461.000, 467.999)),
+    Some((465.000, 471.999)),
+    Some((466.000, 475.999)),
+    Some((470.000, 476.999)),
+    Some((474.000, 480.999)),
+    Some((475.000, 484.999)),
+    Some((479.000, 485.999)),
+    Some((480.000, 489.999)),
+    Some((484.000, 493.999)),
+    Some((488.000, 494.999)),
+    Some((489.000, 498.999)),
+    Some((493.000, 502.999)),
+    Some((497.000, 503.999)),
+    Some((498.000, 507.999)),
+    Some((502.000, 511.999)),
+    Some((500.001, 515.999)),
+    Some((501.000, 519.999)),
+    Some((502.000, 520.999)),
+    Some((500.001, 524.999)),
+    Some((501.000, 528.999)),
+    Some((502.000, 532.999)),
+    Some((500.001, 536.999)),
+    Some((501.000, 537.999)),
+    Some((502.000, 541.999)),
+    Some((500.001, 545.999)),
+    Some((501.000, 549.999)),
+    Some((502.000, 553.999)),
+    Some((500.001, 557.999)),
+    Some((501.000, 561.999)),
+    Some((502.000, 565.999)),
+    Some((500.001, 569.999)),
+    Some((501.000, 573.999)),
+    Some((502.000, 577.999)),
+    Some((500.001, 581.999)),
+    Some((501.000, 585.999)),
+    Some((502.000, 589.999)),
+    Some((500.001, 593.999)),
+    Some((501.000, 600.999)),
+    Some((502.000, 604.999)),
+    Some((500.001, 608.999)),
+    Some((501.000, 612.999)),
+    Some((502.000, 619.999)),
+    Some((500.001, 623.999)),
+    Some((501.000, 627.999)),
+    Some((502.000, 631.999)),
+    Some((500.001, 638.999)),
+    Some((501.000, 642.999)),
+    Some((502.000, 649.999)),
+    Some((500.001, 653.999)),
+    Some((501.000, 660.999)),
+    Some((502.000, 664.999)),
+    Some((500.001, 671.999)),
+    Some((501.000, 675.999)),
+    Some((502.000, 682.999)),
+    Some((500.001, 689.999)),
+    Some((501.000, 696.999)),
+    Some((502.000, 700.999)),
+    Some((500.001, 707.999)),
+    Some((501.000, 714.999)),
+    Some((502.000, 721.999)),
+    Some((500.001, 728.999)),
+    Some((501.000, 735.999)),
+    Some((502.000, 742.999)),
+    Some((500.001, 752.999)),
+    Some((501.000, 759.999)),
+    Some((502.000, 766.999)),
+    Some((500.001, 773.999)),
+    Some((501.000, 783.999)),
+    Some((502.000, 790.999)),
+    Some((500.001, 800.999)),
+    Some((501.000, 810.999)),
+    Some((502.000, 820.999)),
+    Some((500.001, 827.999)),
+    Some((501.000, 837.999)),
+    Some((502.000, 850.999)),
+    Some((500.001, 851.999)),
+    Some((504.000, 852.999)),
+    Some((505.000, 853.999)),
+    Some((506.000, 854.000)),
+    Some((510.000, 852.999)),
+    Some((511.000, 853.999)),
+    Some((515.000, 854.000)),
+    Some((516.000, 852.999)),
+    Some((520.000, 853.999)),
+    Some((521.000, 854.000)),
+    Some((522.000, 852.999)),
+    Some((526.000, 853.999)),
+    Some((527.000, 854.000)),
+    Some((531.000, 852.999)),
+    Some((532.000, 853.999)),
+    Some((536.000, 854.000)),
+    Some((537.000, 852.999)),
+    Some((541.000, 853.999)),
+    Some((542.000, 854.000)),
+    Some((546.000, 852.999)),
+    Some((547.000, 853.999)),
+    Some((551.000, 854.000)),
+    Some((552.000, 852.999)),
+    Some((556.000, 853.999)),
+    Some((560.000, 854.000)),
+    Some((561.000, 852.999)),
+    Some((565.000, 853.999)),
+    Some((566.000, 854.000)),
+    Some((570.000, 852.999)),
+    Some((574.000, 853.999)),
+    Some((575.000, 854.000)),
+    Some((579.000, 852.999)),
+    Some((580.000, 853.999)),
+    Some((584.000, 854.000)),
+    Some((588.000, 852.999)),
+    Some((589.000, 853.999)),
+    Some((593.000, 854.000)),
+    Some((597.000, 852.999)),
+    Some((598.000, 853.999)),
+    Some((602.000, 854.000)),
+    Some((606.000, 852.999)),
+    Some((610.000, 853.999)),
+    Some((611.000, 854.000)),
+    Some((615.000, 852.999)),
+    Some((619.000, 853.999)),
+    Some((623.000, 854.000)),
+    Some((624.000, 852.999)),
+    Some((628.000, 853.999)),
+    Some((632.000, 854.000)),
+    Some((636.000, 852.999)),
+    Some((640.000, 853.999)),
+    Some((644.000, 854.000)),
+    Some((645.000, 852.999)),
+    Some((649.000, 853.999)),
+    Some((653.000, 854.000)),
+    Some((657.000, 852.999)),
+    Some((661.000, 853.999)),
+    Some((665.000, 854.000)),
+    Some((669.000, 852.999)),
+    Some((673.000, 853.999)),
+    Some((677.000, 854.000)),
+    Some((681.000, 852.999)),
+    Some((685.000, 853.999)),
+    Some((689.000, 854.000)),
+    Some((693.000, 852.999)),
+    Some((700.000, 853.999)),
+    Some((704.000, 854.000)),
+    Some((708.000, 852.999)),
+    Some((712.000, 853.999)),
+    Some((716.000, 854.000)),
+    Some((723.000, 852.999)),
+    Some((727.000, 853.999)),
+    Some((731.000, 854.000)),
+    Some((738.000, 852.999)),
+    Some((742.000, 853.999)),
+    Some((746.000, 854.000)),
+    Some((753.000, 852.999)),
+    Some((757.000, 853.999)),
+    Some((764.000, 854.000)),
+    Some((768.000, 852.999)),
+    Some((775.000, 853.999)),
+    Some((782.000, 854.000)),
+    Some((786.000, 852.999)),
+    Some((793.000, 853.999)),
+    Some((800.000, 854.000)),
+    Some((804.000, 852.999)),
+    Some((811.000, 853.999)),
+    Some((818.000, 854.000)),
+    Some((825.000, 852.999)),
+    Some((832.000, 853.999)),
+    Some((839.000, 854.000)),
+    Some((846.000, 852.980)),
+];
+
+pub const ROOF_MIN_IMP_X: i32 = 13;
+pub const ROOF_MAX_IMP_X: i32 = 408;
+const ROOF_MIN_MAX_GARG_X_OF_IMP_POS: [Option<(f32, f32)>;
+    (ROOF_MAX_IMP_X - ROOF_MIN_IMP_X + 1) as usize] = [
+    Some((401.000, 401.999)),
+    Some((402.000, 402.999)),
+    Some((403.000, 403.999)),
+    Some((404.000, 404.999)),
+    Some((405.000, 405.999)),
+    Some((406.000, 406.999)),
+    Some((407.000, 407.999)),
+    Some((408.000, 408.999)),
+    Some((409.000, 409.999)),
+    Some((410.000, 410.999)),
+    Some((411.000, 411.999)),
+    Some((412.000, 412.999)),
+    Some((413.000, 413.999)),
+    Some((414.000, 414.999)),
+    Some((415.000, 415.999)),
+    Some((416.000, 416.999)),
+    Some((417.000, 417.999)),
+    Some((418.000, 418.999)),
+    Some((419.000, 419.999)),
+    Some((420.000, 420.999)),
+    Some((421.000, 421.999)),
+    Some((422.000, 422.999)),
+    Some((423.000, 423.999)),
+    Some((424.000, 424.999)),
+    Some((425.000, 425.999)),
+    Some((426.000, 426.999)),
+    Some((427.000, 427.999)),
+    Some((428.000, 428.999)),
+    Some((429.000, 429.999)),
+    Some((430.000, 430.999)),
+    Some((431.000, 431.999)),
+    Some((432.000, 432.999)),
+    Some((433.000, 433.999)),
+    Some((434.000, 434.999)),
+    Some((435.000, 435.999)),
+    Some((436.000, 436.999)),
+    Some((437.000, 437.999)),
+    Some((438.000, 438.999)),
+    Some((439.000, 439.999)),
+    Some((440.000, 440.999)),
+    Some((441.000, 441.999)),
+    Some((442.000, 442.999)),
+    Some((443.000, 443.999)),
+    Some((444.000, 444.999)),
+    Some((445.000, 445.999)),
+    Some((446.000, 446.999)),
+    Some((447.000, 447.999)),
+    Some((448.000, 448.999)),
+    Some((449.000, 449.999)),
+    Some((450.000, 450.999)),
+    Some((451.000, 451.999)),
+    Some((452.000, 452.999)),
+    Some((453.000, 453.999)),
+    Some((454.000, 454.999)),
+    Some((455.000, 455.999)),
+    Some((456.000, 456.999)),
+    Some((457.000, 457.999)),
+    Some((458.000, 458.999)),
+    Some((459.000, 459.999)),
+    Some((460.000, 460.999)),
+    Some((461.000, 461.999)),
+    Some((462.000, 462.999)),
+    Some((463.000, 463.999)),
+    Some((464.000, 464.999)),
+    Some((465.000, 465.999)),
+    Some((466.000, 466.999)),
+    Some((467.000, 467.999)),
+    Some((468.000, 468.999)),
+    Some((469.000, 469.999)),
+    Some((470.000, 470.999)),
+    Some((471.000, 471.999)),
+    Some((472.000, 472.999)),
+    Some((473.000, 473.999)),
+    Some((474.000, 474.999)),
+    Some((475.000, 475.999)),
+    Some((476.000, 476.999)),
+    Some((477.000, 477.999)),
+    Some((478.000, 478.999)),
+    Some((479.000, 479.999)),
+    Some((480.000, 480.999)),
+    Some((481.000, 481.999)),
+    Some((482.000, 482.999)),
+    Some((483.000, 483.999)),
+    Some((484.000, 484.999)),
+    Some((485.000, 485.999)),
+    Some((486.000, 486.999)),
+    Some((487.000, 487.999)),
+    Some((488.000, 488.999)),
+    Some((489.000, 489.999)),
+    Some((490.000, 490.999)),
+    Some((491.000, 491.999)),
+    Some((492.000, 492.999)),
+    Some((493.000, 493.999)),
+    Some((494.000, 494.999)),
+    Some((495.000, 495.999)),
+    Some((496.000, 496.999)),
+    Some((497.000, 497.999)),
+    Some((498.000, 498.999)),
+    Some((499.000, 499.999)),
+    Some((500.000, 500.999)),
+    Some((501.000, 501.999)),
+    Some((502.000, 502.999)),
+    Some((503.000, 503.999)),
+    Some((504.000, 504.999)),
+    Some((505.000, 505.999)),
+    Some((506.000, 506.999)),
+    Some((507.000, 507.999)),
+    Some((508.000, 508.999)),
+    Some((509.000, 509.999)),
+    Some((510.000, 510.999)),
+    Some((511.000, 511.999)),
+    Some((512.000, 512.999)),
+    Some((513.000, 513.999)),
+    Some((514.000, 514.999)),
+    Some((515.000, 515.999)),
+    Some((516.000, 516.999)),
+    Some((517.000, 517.999)),
+    Some((518.000, 518.999)),
+    Some((519.000, 519.999)),
+    Some((520.000, 520.999)),
+    Some((521.000, 521.999)),
+    Some((522.000, 522.999)),
+    Some((523.000, 523.999)),
+    Some((524.000, 524.999)),
+    Some((525.000, 525.999)),
+    Some((526.000, 526.999)),
+    Some((527.000, 527.999)),
+    Some((528.000, 528.999)),
+    Some((529.000, 529.999)),
+    Some((530.000, 530.999)),
+    Some((531.000, 531.999)),
+    Some((532.000, 532.999)),
+    Some((533.000, 533.999)),
+    Some((534.000, 534.999)),
+    Some((535.000, 535.000)),
+    None,
+    None,
+    Some((535.001, 535.999)),
+    Some((536.000, 536.999)),
+    Some((537.000, 537.999)),
+    Some((538.000, 538.999)),
+    Some((539.000, 539.999)),
+    Some((540.000, 540.999)),
+    Some((541.000, 541.999)),
+    Some((542.000, 542.999)),
+    Some((543.000, 543.999)),
+    Some((544.000, 544.999)),
+    Some((545.000, 545.999)),
+    Some((546.000, 546.999)),
+    Some((547.000, 547.466)),
+    None,
+    None,
+    Some((547.467, 547.999)),
+    Some((548.000, 548.999)),
+    Some((549.000, 549.999)),
+    Some((550.000, 550.999)),
+    Some((551.000, 551.999)),
+    Some((552.000, 552.999)),
+    Some((553.000, 553.999)),
+    Some((554.000, 554.999)),
+    Some((555.000, 555.999)),
+    Some((556.000, 556.999)),
+    Some((557.000, 557.999)),
+    Some((558.000, 558.999)),
+    Some((559.000, 559.733)),
+    None,
+    None,
+    Some((559.734, 559.999)),
+    Some((560.000, 560.999)),
+    Some((561.000, 561.999)),
+    Some((562.000, 562.999)),
+    Some((563.000, 563.999)),
+    Some((564.000, 564.999)),
+    Some((565.000, 565.999)),
+    Some((566.000, 566.999)),
+    Some((567.000, 567.999)),
+    Some((568.000, 568.999)),
+    Some((569.000, 569.999)),
+    Some((570.000, 570.999)),
+    Some((571.000, 571.800)),
+    None,
+    None,
+    Some((571.801, 571.999)),
+    Some((572.000, 572.999)),
+    Some((573.000, 573.999)),
+    Some((574.000, 574.999)),
+    Some((575.000, 575.999)),
+    Some((576.000, 576.999)),
+    Some((577.000, 577.999)),
+    Some((578.000, 578.999)),
+    Some((579.000, 579.999)),
+    Some((580.000, 580.999)),
+    Some((581.000, 584.999)),
+    Some((582.000, 585.999)),
+    Some((583.000, 586.999)),
+    Some((584.000, 587.999)),
+    Some((588.000, 591.999)),
+    Some((589.000, 592.999)),
+    Some((590.000, 593.999)),
+    Some((591.000, 594.999)),
+    Some((595.000, 598.999)),
+    Some((596.000, 599.999)),
+    Some((597.000, 600.999)),
+    Some((598.000, 601.999)),
+    Some((602.000, 605.999)),
+    Some((603.000, 606.999)),
+    Some((604.000, 607.999)),
+    Some((608.000, 611.999)),
+    Some((609.000, 612.999)),
+    Some((610.000, 613.999)),
+    Some((611.000, 617.999)),
+    Some((615.000, 618.999)),
+    Some((616.000, 619.999)),
+    Some((617.000, 620.999)),
+    Some((621.000, 624.999)),
+    Some((622.000, 625.999)),
+    Some((623.000, 626.999)),
+    Some((627.000, 630.999)),
+    Some((628.000, 631.999)),
+    Some((629.000, 632.999)),
+    Some((630.000, 636.999)),
+    Some((634.000, 637.999)),
+    Some((635.000, 638.999)),
+    Some((636.000, 642.999)),
+    Some((640.000, 643.999)),
+    Some((641.000, 647.999)),
+    Some((645.000, 648.999)),
+    Some((646.000, 649.999)),
+    Some((647.000, 653.999)),
+    Some((651.000, 654.999)),
+    Some((652.000, 655.999)),
+    Some((653.000, 659.999)),
+    Some((657.000, 660.999)),
+    Some((658.000, 664.999)),
+    Some((659.000, 665.999)),
+    Some((663.000, 666.999)),
+    Some((664.000, 670.999)),
+    Some((668.000, 671.999)),
+    Some((669.000, 675.999)),
+    Some((670.000, 676.999)),
+    Some((674.000, 680.999)),
+    Some((675.000, 681.999)),
+    Some((679.000, 682.999)),
+    Some((680.000, 686.999)),
+    Some((681.000, 687.999)),
+    Some((682.000, 691.999)),
+    Some((680.001, 692.999)),
+    Some((681.000, 696.999)),
+    Some((682.000, 697.999)),
+    Some((680.001, 701.999)),
+    Some((681.000, 702.999)),
+    Some((682.000, 706.999)),
+    Some((680.001, 707.999)),
+    Some((681.000, 711.999)),
+    Some((682.000, 712.999)),
+    Some((680.001, 716.999)),
+    Some((681.000, 717.999)),
+    Some((682.000, 721.999)),
+    Some((680.001, 722.999)),
+    Some((681.000, 726.999)),
+    Some((682.000, 730.999)),
+    Some((680.001, 731.999)),
+    Some((681.000, 735.999)),
+    Some((682.000, 736.999)),
+    Some((680.001, 740.999)),
+    Some((681.000, 744.999)),
+    Some((682.000, 745.999)),
+    Some((680.001, 749.999)),
+    Some((681.000, 753.999)),
+    Some((682.000, 754.999)),
+    Some((680.001, 758.999)),
+    Some((681.000, 762.999)),
+    Some((682.000, 763.999)),
+    Some((680.001, 767.999)),
+    Some((681.000, 771.999)),
+    Some((682.000, 772.999)),
+    Some((680.001, 776.999)),
+    Some((681.000, 780.999)),
+    Some((682.000, 784.999)),
+    Some((680.001, 785.999)),
+    Some((681.000, 789.999)),
+    Some((682.000, 793.999)),
+    Some((680.001, 797.999)),
+    Some((681.000, 801.999)),
+    Some((682.000, 805.999)),
+    Some((680.001, 806.999)),
+    Some((681.000, 810.999)),
+    Some((682.000, 814.999)),
+    Some((680.001, 818.999)),
+    Some((681.000, 822.999)),
+    Some((682.000, 826.999)),
+    Some((680.001, 830.999)),
+    Some((681.000, 834.999)),
+    Some((682.000, 838.999)),
+    Some((680.001, 842.999)),
+    Some((681.000, 846.999)),
+    Some((682.000, 850.999)),
+    Some((680.001, 851.999)),
+    Some((681.000, 852.999)),
+    Some((682.000, 853.999)),
+    Some((680.001, 854.000)),
+    Some((681.000, 852.999)),
+    Some((682.000, 853.999)),
+    Some((680.001, 854.000)),
+    Some((681.000, 852.999)),
+    Some((682.000, 853.999)),
+    Some((680.001, 854.000)),
+    Some((681.000, 852.999)),
+    Some((682.000, 853.999)),
+    Some((680.001, 854.000)),
+    Some((681.000, 852.999)),
+    Some((682.000, 853.999)),
+    Some((680.001, 854.000)),
+    Some((681.000, 852.999)),
+    Some((682.000, 853.999)),
+    Some((680.001, 854.000)),
+    Some((681.000, 852.999)),
+    Some((682.000, 853.999)),
+    Some((680.001, 854.000)),
+    Some((681.000, 852.999)),
+    Some((682.000, 853.999)),
+    Some((683.000, 854.000)),
+    Some((684.000, 852.999)),
+    Some((685.000, 853.999)),
+    Some((686.000, 854.000)),
+    Some((690.000, 852.999)),
+    Some((691.000, 853.999)),
+    Some((692.000, 854.000)),
+    Some((693.000, 852.999)),
+    Some((697.000, 853.999)),
+    Some((698.000, 854.000)),
+    Some((699.000, 852.999)),
+    Some((703.000, 853.999)),
+    Some((704.000, 854.000)),
+    Some((705.000, 852.999)),
+    Some((706.000, 853.999)),
+    Some((710.000, 854.000)),
+    Some((711.000, 852.999)),
+    Some((712.000, 853.999)),
+    Some((713.000, 854.000)),
+    Some((717.000, 852.999)),
+    Some((718.000, 853.999)),
+    Some((719.000, 854.000)),
+    Some((723.000, 852.999)),
+    Some((724.000, 853.999)),
+    Some((725.000, 854.000)),
+    Some((729.000, 852.999)),
+    Some((730.000, 853.999)),
+    Some((731.000, 854.000)),
+    Some((735.000, 852.999)),
+    Some((736.000, 853.999)),
+    Some((737.000, 854.000)),
+    Some((741.000, 852.999)),
+    Some((742.000, 853.999)),
+    Some((743.000, 854.000)),
+    Some((747.000, 852.999)),
+    Some((748.000, 853.999)),
+    Some((749.000, 854.000)),
+    Some((753.000, 852.999)),
+    Some((754.000, 853.999)),
+    Some((755.000, 854.000)),
+    Some((759.000, 852.999)),
+    Some((760.000, 853.999)),
+    Some((764.000, 854.000)),
+    Some((765.000, 852.999)),
+    Some((769.000, 853.999)),
+    Some((770.000, 854.000)),
+    Some((771.000, 852.999)),
+    Some((775.000, 853.999)),
+    Some((776.000, 854.000)),
+    Some((780.000, 852.999)),
+    Some((781.000, 853.999)),
+    Some((785.000, 854.000)),
+    Some((786.000, 852.999)),
+    Some((790.000, 853.999)),
+    Some((791.000, 854.000)),
+    Some((795.000, 852.999)),
+    Some((796.000, 853.999)),
+    Some((800.000, 854.000)),
+    Some((801.000, 852.999)),
+    Some((805.000, 853.999)),
+    Some((806.000, 854.000)),
+    Some((810.000, 852.999)),
+    Some((811.000, 853.999)),
+    Some((815.000, 854.000)),
+    Some((816.000, 852.999)),
+    Some((820.000, 853.999)),
+    Some((824.000, 854.000)),
+    Some((825.000, 852.999)),
+    Some((829.000, 853.999)),
+    Some((833.000, 854.000)),
+    Some((837.000, 852.999)),
+    Some((841.000, 853.999)),
+    Some((845.000, 854.000)),
+    Some((849.000, 852.999)),
+    Some((853.000, 853.461)),
 ];
+
+// 以上小鬼x坐标对照表
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game;
+
+    #[test]
+    fn test_garg_walk_model_matches_table() {
+        // 去掉表中最后一个循环，用其余部分生成被去掉的部分。
+        // 动画循环并非整数个 cs，整循环平移存在相位误差，外推一个循环实测最大偏差
+        // 为 0.197px（较快）及 0.146px（较慢）；误差随外推循环数累积，故超出表长时给出警告
+        for (table, cycle, tolerance) in [
+            (&GARG_FAST, GARG_FAST_CYCLE, 0.2),
+            (&GARG_SLOW, GARG_SLOW_CYCLE, 0.15),
+        ] {
+            let fixture = &table[..GARG_DATA_SIZE - cycle];
+            for (ticks, &walk) in table.iter().enumerate() {
+                let diff = (garg_walk_of_ticks(fixture, cycle, ticks) - walk as f64).abs();
+                assert!(diff / (DIVISOR as f64) < tolerance, "{cycle} {ticks}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_garg_of_half_ticks() {
+        assert_eq!(garg_fast_of_half_ticks(0), 0.);
+        let last = (GARG_DATA_SIZE as i32 - 1) * 2;
+        assert_eq!(
+            garg_fast_of_half_ticks(last),
+            GARG_FAST[GARG_DATA_SIZE - 1] as f32 / DIVISOR
+        );
+        assert_eq!(
+            garg_slow_of_half_ticks(last),
+            GARG_SLOW[GARG_DATA_SIZE - 1] as f32 / DIVISOR
+        );
+        assert!(!garg_walk_extrapolated(last));
+        assert!(garg_walk_extrapolated(last + 1));
+        // 表外依然单调递增
+        let mut prev = garg_slow_of_half_ticks(last);
+        for half_ticks in last + 1..last * 3 {
+            let walk = garg_slow_of_half_ticks(half_ticks);
+            assert!(walk >= prev, "{half_ticks}");
+            prev = walk;
+        }
+    }
+
+    // 对照表与模拟结果不符时，运行 `cargo run --release --example gen_imp_table` 重新生成
+    #[test]
+    fn test_imp_x_tables_match_generator() {
+        for (scene, roof) in [(game::Scene::PE, false), (game::Scene::RE, true)] {
+            let (min_imp_x, table) = game::min_max_garg_x_of_imp_x_table(&scene);
+            let (checked_in_min_imp_x, checked_in_max_imp_x) = min_max_imp_x(roof);
+            assert_eq!(min_imp_x, checked_in_min_imp_x);
+            assert_eq!(min_imp_x + table.len() as i32 - 1, checked_in_max_imp_x);
+            for (imp_x, min_max_garg_x) in (min_imp_x..).zip(table) {
+                assert_eq!(
+                    min_max_garg_pos_of_imp_x(imp_x, roof),
+                    min_max_garg_x,
+                    "{imp_x}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,679 @@
+//! 交互模式下的指令补全、参数提示、输入校验及高亮。
+//!
+//! 每条指令的参数格式由 [`forms`] 给出，需与 [`crate::parser::Parser`] 保持一致。
+use crate::game;
+use crate::lang::*;
+use crate::parser;
+use dyn_fmt::AsStrFormatExt;
+use rustyline::completion::Completer;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::{Context, Helper};
+use std::borrow::Cow;
+
+const RED: &str = "\x1b[31m";
+const GRAY: &str = "\x1b[90m";
+const RESET: &str = "\x1b[0m";
+
+/// 可补全的指令（不含别名）
+const COMMANDS: [&str; 35] = [
+    "de", "ne", "pe", "fe", "re", "me", "wave", "delay", "delay1", "delay2", "delay3", "doom",
+    "cherry", "jalapeno", "squash", "chomper", "hit", "nohit", "max", "scan", "solve", "icesolve",
+    "multi", "trace", "imp", "land", "lawn", "eat", "svg", "save", "load", "format", "lang",
+    "help", "about",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Arg {
+    IceTimes,
+    CobTime,
+    HitRow,
+    HitCol,
+    CobCol,
+    /// ">"
+    GargPos,
+    GargRows,
+    GargXRange,
+    IceFlag,
+    DoomRow,
+    DoomCol,
+    PlantRow,
+    PlantCol,
+    Delay,
+    HitColRange,
+    Csv,
+    DelayRange,
+    CobTimeRange,
+    IceTimeRange,
+    ImpX,
+    GargRow,
+    GargX,
+    Rnd,
+    LawnCells,
+    Clear,
+    Format,
+    Lang,
+    SetupName,
+    SvgFile,
+}
+
+impl Arg {
+    fn name(&self) -> &'static str {
+        match self {
+            Arg::IceTimes => &ARG_ICE_TIMES,
+            Arg::CobTime => &ARG_COB_TIME,
+            Arg::HitRow => &ARG_HIT_ROW,
+            Arg::HitCol => &ARG_HIT_COL,
+            Arg::CobCol => &ARG_COB_COL,
+            Arg::GargPos => ">",
+            Arg::GargRows => &ARG_GARG_ROWS,
+            Arg::GargXRange => &ARG_GARG_X_RANGE,
+            Arg::IceFlag => "u/i",
+            Arg::DoomRow => &ARG_DOOM_ROW,
+            Arg::DoomCol => &ARG_DOOM_COL,
+            Arg::PlantRow => &ARG_PLANT_ROW,
+            Arg::PlantCol => &ARG_PLANT_COL,
+            Arg::Delay => &ARG_DELAY,
+            Arg::HitColRange => &ARG_HIT_COL_RANGE,
+            Arg::Csv => "csv",
+            Arg::DelayRange => &ARG_DELAY_RANGE,
+            Arg::CobTimeRange => &ARG_COB_TIME_RANGE,
+            Arg::IceTimeRange => &ARG_ICE_TIME_RANGE,
+            Arg::ImpX => &ARG_IMP_X,
+            Arg::GargRow => &ARG_GARG_ROW,
+            Arg::GargX => &ARG_GARG_X,
+            Arg::Rnd => &ARG_RND,
+            Arg::LawnCells => &ARG_LAWN_CELLS,
+            Arg::Clear => "clear",
+            Arg::Format => "text/json",
+            Arg::Lang => "en/zh",
+            Arg::SetupName => &ARG_SETUP_NAME,
+            Arg::SvgFile => &ARG_SVG_FILE,
+        }
+    }
+
+    /// 可输入多个，如用冰时机
+    fn repeats(&self) -> bool {
+        matches!(self, Arg::IceTimes | Arg::LawnCells)
+    }
+
+    fn choices(&self) -> &'static [&'static str] {
+        match self {
+            Arg::GargPos => &[">"],
+            Arg::IceFlag => &["u", "i"],
+            Arg::Csv => &["csv"],
+            Arg::Clear => &["clear"],
+            Arg::Format => &["text", "json"],
+            Arg::Lang => &["en", "zh"],
+            _ => &[],
+        }
+    }
+
+    // 与 parser 中的检查保持一致；仅依赖当前设置的检查（如小鬼x范围）留给计算器
+    fn accepts(&self, token: &str, scene: &game::Scene) -> bool {
+        let int = || token.parse::<i32>().ok();
+        let number = || token.parse::<f32>().ok();
+        let pair = || match token.replace('，', ",").split(',').collect::<Vec<&str>>()[..] {
+            [min, max] => match (min.parse::<f32>(), max.parse::<f32>()) {
+                (Ok(min), Ok(max)) if min <= max => Some((min, max)),
+                _ => None,
+            },
+            _ => None,
+        };
+        let is_hit_col = |hit_col: f32| (0. ..10.).contains(&hit_col);
+        match self {
+            Arg::IceTimes => token
+                .strip_suffix(['i', 'm'])
+                .unwrap_or(token)
+                .parse::<i32>()
+                .is_ok(),
+            Arg::Delay => int().is_some(),
+            Arg::ImpX => match token.replace('，', ",").split(',').collect::<Vec<&str>>()[..] {
+                [imp_x] => imp_x.parse::<i32>().is_ok(),
+                [min, max] => match (min.parse::<i32>(), max.parse::<i32>()) {
+                    (Ok(min), Ok(max)) => min <= max,
+                    _ => false,
+                },
+                _ => false,
+            },
+            Arg::CobTime => token
+                .strip_suffix('a')
+                .unwrap_or(token)
+                .parse::<i32>()
+                .is_ok_and(|cob_time| cob_time >= 0),
+            Arg::HitRow | Arg::DoomRow | Arg::PlantRow | Arg::GargRow => {
+                int().is_some_and(|row| scene.all_rows().contains(&row))
+            }
+            Arg::HitCol => number().is_some_and(is_hit_col),
+            Arg::CobCol => {
+                let (cob_col, cob_row) = match token.split_once('@') {
+                    None => (token, None),
+                    Some((cob_col, cob_row)) => (cob_col, Some(cob_row)),
+                };
+                cob_col
+                    .parse::<i32>()
+                    .is_ok_and(|cob_col| (1..=8).contains(&cob_col))
+                    && cob_row.is_none_or(|cob_row| {
+                        cob_row
+                            .parse::<i32>()
+                            .is_ok_and(|cob_row| (1..=5).contains(&cob_row))
+                    })
+            }
+            Arg::GargX => number()
+                .is_some_and(|garg_x| garg_x > game::MIN_GARG_X && garg_x <= game::MAX_GARG_X),
+            Arg::Rnd => int().is_some_and(|rnd| (0..=100).contains(&rnd)),
+            Arg::DoomCol | Arg::PlantCol => int().is_some_and(|col| (1..=9).contains(&col)),
+            Arg::GargRows => token
+                .replace('，', ",")
+                .split(',')
+                .all(|row| row.parse::<i32>().is_ok()),
+            // "imp" 为上次 imp 指令算得的巨人x范围
+            Arg::GargXRange => {
+                token == "imp"
+                    || pair()
+                        .is_some_and(|(min, max)| min > game::MIN_GARG_X && max <= game::MAX_GARG_X)
+            }
+            Arg::HitColRange => pair().is_some_and(|(min, max)| is_hit_col(min) && is_hit_col(max)),
+            Arg::DelayRange => match token.replace('，', ",").split(',').collect::<Vec<&str>>()[..]
+            {
+                [min, max] => match (min.parse::<i32>(), max.parse::<i32>()) {
+                    (Ok(min), Ok(max)) => min <= max,
+                    _ => false,
+                },
+                _ => false,
+            },
+            // 用冰时机不晚于激活时机的检查留给 parser
+            Arg::CobTimeRange | Arg::IceTimeRange => {
+                match token.replace('，', ",").split(',').collect::<Vec<&str>>()[..] {
+                    [min, max] => match (min.parse::<i32>(), max.parse::<i32>()) {
+                        (Ok(min), Ok(max)) => 0 <= min && min <= max,
+                        _ => false,
+                    },
+                    _ => false,
+                }
+            }
+            Arg::LawnCells => match token.replace('：', ":").split_once(':') {
+                None => false,
+                Some((row, cols)) => {
+                    row.parse::<i32>()
+                        .is_ok_and(|row| scene.all_rows().contains(&row))
+                        && cols.replace('，', ",").split(',').all(|cols| {
+                            let (min_col, max_col) = cols.split_once('-').unwrap_or((cols, cols));
+                            match (min_col.parse::<i32>(), max_col.parse::<i32>()) {
+                                (Ok(min_col), Ok(max_col)) => {
+                                    1 <= min_col && min_col <= max_col && max_col <= 9
+                                }
+                                _ => false,
+                            }
+                        })
+                }
+            },
+            Arg::GargPos | Arg::IceFlag | Arg::Csv | Arg::Clear | Arg::Format | Arg::Lang => {
+                self.choices().contains(&token)
+            }
+            Arg::SetupName | Arg::SvgFile => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Param {
+    arg: Arg,
+    optional: bool,
+}
+
+impl Param {
+    fn hint(&self) -> String {
+        match self.arg {
+            Arg::GargPos => ">".to_string(),
+            arg if self.optional => ARG_OPTIONAL.format(&[arg.name()]),
+            arg => ARG_REQUIRED.format(&[arg.name()]),
+        }
+    }
+}
+
+const fn req(arg: Arg) -> Param {
+    Param {
+        arg,
+        optional: false,
+    }
+}
+
+const fn opt(arg: Arg) -> Param {
+    Param {
+        arg,
+        optional: true,
+    }
+}
+
+/// 指令的各种参数格式；未知指令及 `multi` 等可重复多段的指令返回 `None`
+fn forms(command: &str, scene: &game::Scene) -> Option<Vec<Vec<Param>>> {
+    use Arg::*;
+    let garg_pos = [req(GargPos), req(GargRows), opt(GargXRange), opt(IceFlag)];
+    let roof = scene.is_roof();
+    let forms = match command {
+        "de" | "ne" | "pe" | "fe" | "re" | "me" | "help" | "?" | "？" | "about" => vec![vec![]],
+        "wave" => vec![vec![opt(IceTimes), opt(CobTime)]],
+        "delay1" | "delay2" | "delay3" if roof => vec![vec![req(HitCol), req(CobCol)]],
+        "delay1" | "delay2" | "delay3" => vec![vec![req(HitCol)]],
+        "delay" if roof => vec![
+            vec![req(HitCol), req(CobCol)],
+            [req(HitRow), req(HitCol), req(CobCol)]
+                .into_iter()
+                .chain(garg_pos)
+                .collect(),
+        ],
+        "delay" => vec![
+            vec![req(HitCol)],
+            [req(HitRow), req(HitCol)]
+                .into_iter()
+                .chain(garg_pos)
+                .collect(),
+        ],
+        "doom" => vec![
+            vec![req(DoomRow), req(DoomCol)],
+            [req(DoomRow), req(DoomCol)]
+                .into_iter()
+                .chain(garg_pos)
+                .collect(),
+        ],
+        "cherry" | "squash" | "chomper" => vec![
+            vec![req(PlantRow), req(PlantCol)],
+            [req(PlantRow), req(PlantCol)]
+                .into_iter()
+                .chain(garg_pos)
+                .collect(),
+        ],
+        "jalapeno" => vec![
+            vec![req(PlantRow)],
+            [req(PlantRow)].into_iter().chain(garg_pos).collect(),
+        ],
+        "hit" | "nohit" if roof => vec![vec![req(CobCol), opt(Delay)]],
+        "hit" | "nohit" => vec![vec![opt(Delay)]],
+        "max" if roof => vec![[req(HitRow), req(HitColRange), req(CobCol)]
+            .into_iter()
+            .chain(garg_pos)
+            .collect()],
+        "max" => vec![[req(HitRow), req(HitColRange)]
+            .into_iter()
+            .chain(garg_pos)
+            .collect()],
+        // csv 与延迟范围均可省略，各列出一种格式
+        "scan" => {
+            let max = forms("max", scene)?.remove(0);
+            [
+                vec![],
+                vec![req(Csv)],
+                vec![req(DelayRange)],
+                vec![req(Csv), req(DelayRange)],
+            ]
+            .into_iter()
+            .map(|prefix| prefix.into_iter().chain(max.clone()).collect())
+            .collect()
+        }
+        "solve" | "icesolve" => {
+            let cob = if roof {
+                vec![
+                    req(HitRow),
+                    req(HitCol),
+                    req(CobCol),
+                    opt(GargPos),
+                    opt(GargRows),
+                ]
+            } else {
+                vec![req(HitRow), req(HitCol), opt(GargPos), opt(GargRows)]
+            };
+            let times: &[&[Param]] = if command == "solve" {
+                &[&[req(CobTimeRange)]]
+            } else {
+                &[
+                    &[req(IceTimeRange)],
+                    &[req(IceTimeRange), req(IceTimeRange)],
+                ]
+            };
+            times
+                .iter()
+                .map(|times| times.iter().copied().chain(cob.clone()).collect())
+                .collect()
+        }
+        "trace" => {
+            let garg = [req(GargRow), req(GargX), req(Rnd), opt(IceFlag)];
+            let cob = if roof {
+                vec![req(HitRow), req(HitCol), req(CobCol)]
+            } else {
+                vec![req(HitRow), req(HitCol)]
+            };
+            vec![
+                garg.to_vec(),
+                cob.into_iter().chain([req(GargPos)]).chain(garg).collect(),
+            ]
+        }
+        "imp" => vec![vec![req(ImpX)]],
+        "land" | "eat" => vec![vec![opt(GargXRange), opt(IceFlag)], vec![req(IceFlag)]],
+        "lawn" => vec![vec![opt(LawnCells)], vec![req(Clear)]],
+        "format" => vec![vec![req(Format)]],
+        "lang" => vec![vec![req(Lang)]],
+        "save" | "load" => vec![vec![req(SetupName)]],
+        "svg" => vec![vec![req(SvgFile)]],
+        _ => return None,
+    };
+    Some(forms)
+}
+
+/// 已输入参数与格式相符时，返回下一个参数的位置；可选参数只能省略末尾的
+fn match_form(form: &[Param], args: &[&str], scene: &game::Scene) -> Option<usize> {
+    let mut index = 0;
+    for arg in args {
+        let mut param = form.get(index)?;
+        // 可重复的参数不符合时视为下一个参数（如植物激活的激活时机）
+        if param.arg.repeats() && !param.arg.accepts(arg, scene) {
+            index += 1;
+            param = form.get(index)?;
+        }
+        if !param.arg.accepts(arg, scene) {
+            return None;
+        }
+        if !param.arg.repeats() {
+            index += 1;
+        }
+    }
+    Some(index)
+}
+
+/// 以空白分隔的各段及其起始位置
+fn tokens(line: &str) -> Vec<(usize, &str)> {
+    let mut tokens = vec![];
+    let mut start = None;
+    for (i, c) in line.char_indices() {
+        match (c.is_whitespace(), start) {
+            (true, Some(s)) => {
+                tokens.push((s, &line[s..i]));
+                start = None;
+            }
+            (false, None) => start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        tokens.push((s, &line[s..]));
+    }
+    tokens
+}
+
+/// 第一个有误的参数的位置，及该处应输入的参数（参数过多时为 `None`）
+fn first_invalid(line: &str, scene: &game::Scene) -> Option<(usize, Option<Param>)> {
+    let line = parser::lowercase_command(line);
+    let tokens = tokens(&line);
+    let (command, args) = tokens.split_first()?;
+    let forms = forms(command.1, scene)?;
+    let args = args.iter().map(|(_, arg)| *arg).collect::<Vec<&str>>();
+    let matched_len = (0..=args.len())
+        .rev()
+        .find(|&len| {
+            forms
+                .iter()
+                .any(|form| match_form(form, &args[..len], scene).is_some())
+        })
+        .unwrap_or(0);
+    if matched_len == args.len() {
+        return None;
+    }
+    let expected = forms.iter().find_map(|form| {
+        match_form(form, &args[..matched_len], scene).and_then(|index| form.get(index).copied())
+    });
+    Some((matched_len + 1, expected))
+}
+
+pub struct EditorHelper {
+    scene: game::Scene,
+    setup_names: Vec<String>,
+}
+
+impl EditorHelper {
+    pub fn new(scene: game::Scene) -> EditorHelper {
+        EditorHelper {
+            scene,
+            setup_names: vec![],
+        }
+    }
+
+    /// 参数格式随场合变化，每条指令执行后需同步
+    pub fn set_scene(&mut self, scene: game::Scene) {
+        self.scene = scene;
+    }
+
+    /// 供 `save`/`load` 补全的设置名称
+    pub fn set_setup_names(&mut self, setup_names: Vec<String>) {
+        self.setup_names = setup_names;
+    }
+
+    fn candidates(&self, command: &str, args: &[&str]) -> Vec<String> {
+        let Some(forms) = forms(command, &self.scene) else {
+            return vec![];
+        };
+        let mut candidates = vec![];
+        for form in forms {
+            if let Some(param) =
+                match_form(&form, args, &self.scene).and_then(|index| form.get(index))
+            {
+                if param.arg == Arg::SetupName {
+                    candidates.extend(self.setup_names.iter().cloned());
+                } else {
+                    candidates.extend(param.arg.choices().iter().map(|c| c.to_string()));
+                }
+            }
+        }
+        candidates.dedup();
+        candidates
+    }
+}
+
+impl Completer for EditorHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let line = &line[..pos];
+        let start = line.rfind(char::is_whitespace).map(|i| i + 1).unwrap_or(0);
+        let words = parser::lowercase_command(&line[..start]);
+        let words = words.split_whitespace().collect::<Vec<&str>>();
+        let prefix = match words.first() {
+            Some(command) if parser::keeps_arg_case(command) => line[start..].to_string(),
+            _ => line[start..].to_lowercase(),
+        };
+        let candidates = match words.split_first() {
+            None => COMMANDS.iter().map(|c| c.to_string()).collect(),
+            Some((command, args)) => self.candidates(command, args),
+        };
+        Ok((
+            start,
+            candidates
+                .into_iter()
+                .filter(|c| c.starts_with(&prefix))
+                .collect(),
+        ))
+    }
+}
+
+impl Hinter for EditorHelper {
+    type Hint = String;
+
+    // 提示剩余参数；正在输入的参数视为已输入
+    fn hint(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> Option<String> {
+        if pos < line.len() || first_invalid(line, &self.scene).is_some() {
+            return None;
+        }
+        let lowercase_line = parser::lowercase_command(line);
+        let words = lowercase_line.split_whitespace().collect::<Vec<&str>>();
+        let (command, args) = words.split_first()?;
+        let forms = forms(command, &self.scene)?;
+        let (form, index) = forms.iter().find_map(|form| {
+            match_form(form, args, &self.scene)
+                .filter(|&index| index < form.len())
+                .map(|index| (form, index))
+        })?;
+        // 已输入用冰或激活时机、植物格子时不再提示
+        if form[index].arg.repeats() && !args.is_empty() {
+            return None;
+        }
+        let hint = form[index..]
+            .iter()
+            .map(Param::hint)
+            .collect::<Vec<String>>()
+            .join(" ");
+        if line.ends_with(char::is_whitespace) {
+            Some(hint)
+        } else {
+            Some(format!(" {hint}"))
+        }
+    }
+}
+
+impl Highlighter for EditorHelper {
+    fn highlight<'l>(&self, line: &'l str, _pos: usize) -> Cow<'l, str> {
+        let Some((index, _)) = first_invalid(line, &self.scene) else {
+            return Cow::Borrowed(line);
+        };
+        let Some((start, token)) = tokens(line).get(index).copied() else {
+            return Cow::Borrowed(line);
+        };
+        let end = start + token.len();
+        Cow::Owned(format!(
+            "{}{RED}{}{RESET}{}",
+            &line[..start],
+            &line[start..end],
+            &line[end..]
+        ))
+    }
+
+    fn highlight_hint<'h>(&self, hint: &'h str) -> Cow<'h, str> {
+        Cow::Owned(format!("{GRAY}{hint}{RESET}"))
+    }
+
+    fn highlight_char(&self, _line: &str, _pos: usize) -> bool {
+        true
+    }
+}
+
+impl Validator for EditorHelper {
+    // 只拦截参数有误的已知指令，缺少参数等情况交由解析器提示
+    fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
+        Ok(match first_invalid(ctx.input(), &self.scene) {
+            None => ValidationResult::Valid(None),
+            Some((_, None)) => {
+                ValidationResult::Invalid(Some(format!("  ← {INPUT_ERROR_TOO_MANY_ARGUMENTS}")))
+            }
+            Some((_, Some(param))) => {
+                ValidationResult::Invalid(Some(format!("  ← {}", EXPECTED.format(&[param.hint()]))))
+            }
+        })
+    }
+}
+
+impl Helper for EditorHelper {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_invalid() {
+        let ground = game::Scene::PE;
+        let roof = game::Scene::RE;
+        assert_eq!(first_invalid("delay 8.8", &ground).map(|(i, _)| i), None);
+        assert_eq!(first_invalid("delay 1 8.8 > 1,2 700,800 u", &ground), None);
+        assert_eq!(
+            first_invalid("delay 1 8.8 > 1,2 u", &ground).map(|(i, _)| i),
+            Some(5)
+        );
+        assert_eq!(first_invalid("wave 1 400 800", &ground), None);
+        assert_eq!(first_invalid("wave 1i 400m 800a", &ground), None);
+        assert_eq!(
+            first_invalid("wave 800a 1", &ground).map(|(i, _)| i),
+            Some(2)
+        );
+        assert_eq!(
+            first_invalid("wave 1x 800", &ground).map(|(i, _)| i),
+            Some(1)
+        );
+        assert_eq!(first_invalid("lawn 1:1-6 2：1，3", &ground), None);
+        assert_eq!(first_invalid("lawn clear", &ground), None);
+        assert_eq!(
+            first_invalid("lawn 1:1-6 7:1", &ground).map(|(i, _)| i),
+            Some(2)
+        );
+        assert_eq!(first_invalid("bogus 1", &ground), None);
+        assert_eq!(
+            first_invalid("delay 1 10 > 1", &ground).map(|(i, _)| i),
+            Some(2)
+        );
+        assert_eq!(
+            first_invalid("delay 1 8.8 > 1 700,800 x", &ground).map(|(i, _)| i),
+            Some(6)
+        );
+        assert_eq!(
+            first_invalid("delay 3.5 9", &roof).map(|(i, p)| (i, p.map(|p| p.arg))),
+            Some((2, Some(Arg::CobCol)))
+        );
+        assert_eq!(
+            first_invalid("hit 1 2 3", &ground).map(|(i, p)| (i, p.map(|p| p.arg))),
+            Some((2, None))
+        );
+        assert_eq!(first_invalid("trace 1 750 0 u", &ground), None);
+        assert_eq!(first_invalid("land u", &ground), None);
+        assert_eq!(first_invalid("land 700,800 u", &ground), None);
+        assert_eq!(first_invalid("imp 150,180", &ground), None);
+        assert_eq!(
+            first_invalid("imp 180,150", &ground).map(|(i, _)| i),
+            Some(1)
+        );
+        assert_eq!(first_invalid("delay 1 8.8 > 1 imp u", &ground), None);
+        assert_eq!(first_invalid("trace 1 8.8 > 1 750 100", &ground), None);
+        assert_eq!(first_invalid("svg trace.svg", &ground), None);
+        assert_eq!(
+            first_invalid("svg a.svg b.svg", &ground).map(|(i, _)| i),
+            Some(2)
+        );
+        assert_eq!(first_invalid("scan 1 8,8.8 > 1", &ground), None);
+        assert_eq!(first_invalid("scan csv 100,130 1 8,8.8 > 1", &ground), None);
+        assert_eq!(first_invalid("scan 100,130 1 3,4 4 > 1", &roof), None);
+        assert_eq!(
+            first_invalid("scan 130,100 1 8,8.8 > 1", &ground).map(|(i, _)| i),
+            Some(1)
+        );
+        assert_eq!(first_invalid("solve 300,600 1 8.8 > 1", &ground), None);
+        assert_eq!(first_invalid("solve 300,600 1 3.5 4 > 1", &roof), None);
+        assert_eq!(first_invalid("icesolve 1,100 1 8.8 > 1", &ground), None);
+        assert_eq!(first_invalid("icesolve 1,100 200,300 1 8.8", &ground), None);
+        assert_eq!(
+            first_invalid("icesolve 1,100 200,300 400,500 1 8.8", &ground).map(|(i, _)| i),
+            Some(3)
+        );
+        assert_eq!(
+            first_invalid("solve 300,600 1 8.8 > 1 700,800", &ground).map(|(i, _)| i),
+            Some(6)
+        );
+        assert_eq!(
+            first_invalid("trace 1 750 101", &ground).map(|(i, _)| i),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_candidates() {
+        let mut helper = EditorHelper::new(game::Scene::PE);
+        assert_eq!(helper.candidates("delay", &["1", "8.8"]), vec![">"]);
+        assert_eq!(
+            helper.candidates("delay", &["1", "8.8", ">", "1", "700,800"]),
+            vec!["u", "i"]
+        );
+        assert_eq!(helper.candidates("trace", &["1", "8.8"]), vec![">"]);
+        helper.set_setup_names(vec!["roof1".to_string()]);
+        assert_eq!(helper.candidates("load", &[]), vec!["roof1"]);
+    }
+}
@@ -1,12 +1,11 @@
 use crate::constants;
-use dyn_fmt::AsStrFormatExt;
-use std::{cmp, ops::Add};
-
-#[cfg(feature = "en")]
-use crate::lang::en::*;
+use std::{
+    cmp,
+    collections::{BTreeMap, BTreeSet},
+    ops::Add,
+};
 
-#[cfg(feature = "zh")]
-use crate::lang::zh::*;
+use crate::lang::*;
 
 const GRAVITY: Vec2 = Vec2 { x: 0., y: -0.05 };
 const COL_WIDTH: i32 = 80;
@@ -14,19 +13,40 @@ const COB_RADIUS: i32 = 115;
 const COB_HIT_RANGE: i32 = 1;
 const DOOM_RADIUS: i32 = 250;
 const DOOM_HIT_RANGE: i32 = 3;
+const CHERRY_RADIUS: i32 = 115;
+const CHERRY_HIT_RANGE: i32 = 1;
+/// 窝瓜发现目标后依次观望80、蓄力45、跃起50、下落10
+const SQUASH_KILL_DELAY: i32 = 185;
+/// 大嘴花发现目标后咬下的时长
+const CHOMPER_KILL_DELAY: i32 = 70;
+/// 小鬼原速行走的速度（像素/cs），减速时减半
+const IMP_WALK_SPEED: f32 = 0.9;
+/// 小鬼啃食判定域相对小鬼x坐标的左边界及宽度
+const IMP_ATTACK_SHIFT: i32 = 50;
+const IMP_ATTACK_WIDTH: i32 = 20;
+/// 植物被啃食判定域相对植物坐标（所在格左边界右移40）的左边界及宽度
+const PLANT_RECT_SHIFT: i32 = 10;
+const PLANT_RECT_WIDTH: i32 = 60;
+/// 小鬼啃食判定域与植物重叠至少此值才会啃食
+const EAT_OVERLAP: i32 = 20;
 const GARG_THROW_IMP_THRES: f32 = 401.;
 const IMP_DEFENSE_SHIFT: IntVec2 = IntVec2 { x: 36, y: 0 };
 const IMP_DEFENSE_WIDTH: i32 = 42;
 const IMP_DEFENSE_HEIGHT: i32 = 115;
 const MIN_GARG_START_POS: f32 = 845.;
 const MAX_GARG_START_POS: f32 = 854.;
-pub const MIN_GARG_X: f32 = -152.; // 如果 x <= -152., 巨人将进家
+/// 巨人x坐标下界（不含）。如果 x <= -152., 巨人将进家
+pub const MIN_GARG_X: f32 = -152.;
+/// 巨人x坐标上界（含）
 pub const MAX_GARG_X: f32 = MAX_GARG_START_POS;
 const MIN_ICE_TIME_FOR_UNICED: i32 = 400;
 const MAX_ICE_TIME_FOR_UNICED: i32 = 600;
 const MIN_ICE_TIME_FOR_ICED: i32 = 300;
 const MAX_ICE_TIME_FOR_ICED: i32 = 400;
+/// 冰冻后减速（含冻结）持续的总时长
 pub const ICE_SLOW_TOTAL_TIME: i32 = 2000;
+/// 冰瓜、寒冰射手减速持续的时长（不冻结）
+pub const SLOW_TIME: i32 = 1000;
 const DE_COB_DIST: CobDist = CobDist {
     hit_above: 111,
     hit_same: 125,
@@ -80,6 +100,7 @@ const RE_COB_DIST: [CobDist; 8] = [
     },
 ];
 const FLOAT_INT_DIFF_TOLERANCE: f32 = 0.01;
+/// 可拦区间上限；可拦区间的 `max` 等于此值表示"此后均可拦截"
 pub const MAX_INTERCEPTION_DELAY: i32 = 999;
 
 #[derive(Debug)]
@@ -99,7 +120,8 @@ impl Add for Vec2 {
     }
 }
 
-#[derive(Debug, Clone)]
+/// 整数像素坐标
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct IntVec2 {
     pub x: i32,
     pub y: i32,
@@ -116,18 +138,27 @@ impl Add for IntVec2 {
     }
 }
 
-struct Rectangle {
-    upper_left: IntVec2,
-    width: i32,
-    height: i32,
+/// 矩形判定范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rectangle {
+    pub upper_left: IntVec2,
+    pub width: i32,
+    pub height: i32,
 }
 
-#[derive(Debug, Clone)]
+/// 爆炸的圆形判定范围
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Circle {
     pub center: IntVec2,
     radius: i32,
 }
 
+impl Circle {
+    pub fn radius(&self) -> i32 {
+        self.radius
+    }
+}
+
 fn circle_rectangle_intersect(
     Circle {
         center: IntVec2 { x: cir_x, y: cir_y },
@@ -159,34 +190,81 @@ fn circle_rectangle_intersect(
     ((*cir_x - x).pow(2) + (*cir_y - y).pow(2)) <= radius.pow(2)
 }
 
-#[derive(Clone)]
+/// 默认拦截时拦截的行数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DelayMode {
     Delay1, // 拦一行（下）
     Delay2, // 拦两行（本、下）
     Delay3, // 拦三行（上、本、下）
 }
 
+/// 炮落点与巨人x坐标之差（像素），恰好能炸到对应行的巨人
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CobDist {
     pub hit_above: i32, // 炸上行巨人炮距
     pub hit_same: i32,  // 炸本行巨人炮距
     pub hit_below: i32, // 炸下行巨人炮距
 }
 
-#[derive(PartialEq, Eq)]
+/// 场合
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Scene {
+    /// 前院（5行）
     DE,
+    /// 前院夜间（5行）
+    NE,
+    /// 后院（6行，3、4路为水路）
     PE,
+    /// 后院雾夜（6行，3、4路为水路）
+    FE,
+    /// 屋顶（5行）
     RE,
+    /// 屋顶月夜（5行）
+    ME,
 }
 
 impl Scene {
+    pub fn from_code(code: &str) -> Option<Scene> {
+        match code {
+            "de" => Some(Scene::DE),
+            "ne" => Some(Scene::NE),
+            "pe" => Some(Scene::PE),
+            "fe" => Some(Scene::FE),
+            "re" => Some(Scene::RE),
+            "me" => Some(Scene::ME),
+            _ => None,
+        }
+    }
+
+    /// 对应的场合指令
+    pub fn code(&self) -> &'static str {
+        match self {
+            Scene::DE => "de",
+            Scene::NE => "ne",
+            Scene::PE => "pe",
+            Scene::FE => "fe",
+            Scene::RE => "re",
+            Scene::ME => "me",
+        }
+    }
+
+    /// 可能出现巨人的行
+    pub fn garg_rows(&self) -> Vec<i32> {
+        match self {
+            Scene::DE | Scene::NE | Scene::RE | Scene::ME => vec![1, 2, 3, 4, 5],
+            Scene::PE | Scene::FE => vec![1, 2, 5, 6],
+        }
+    }
+
+    /// 该场合的所有行
     pub fn all_rows(&self) -> Vec<i32> {
         match self {
-            Scene::DE | Scene::RE => vec![1, 2, 3, 4, 5],
-            Scene::PE => vec![1, 2, 3, 4, 5, 6],
+            Scene::DE | Scene::NE | Scene::RE | Scene::ME => vec![1, 2, 3, 4, 5],
+            Scene::PE | Scene::FE => vec![1, 2, 3, 4, 5, 6],
         }
     }
 
+    /// 落在 `hit_row` 行的炮可以炸到的巨人所在行
     pub fn garg_rows_for_cob(&self, hit_row: i32) -> Vec<i32> {
         let mut garg_rows = self
             .hittable_rows(hit_row, COB_HIT_RANGE)
@@ -196,38 +274,41 @@ impl Scene {
         garg_rows
     }
 
-    pub fn garg_rows_for_doom(&self, doom_row: i32) -> Vec<i32> {
+    /// 灰烬植物 `ash` 可以炸到的巨人所在行
+    pub fn garg_rows_for_ash(&self, ash: &Ash) -> Vec<i32> {
         let mut garg_rows = self
-            .hittable_rows(doom_row, DOOM_HIT_RANGE)
+            .hittable_rows(ash.row(), ash.hit_range())
             .into_iter()
             .collect::<Vec<i32>>();
         garg_rows.sort();
         garg_rows
     }
 
+    /// 秒杀植物可以杀死其所投掷小鬼的巨人所在行
+    pub fn garg_rows_for_killer(&self, plant: &KillerPlant) -> Vec<i32> {
+        self.hittable_rows(plant.row(), 0)
+    }
+
     fn hittable_rows(&self, hit_row: i32, hit_range: i32) -> Vec<i32> {
-        let garg_rows = match self {
-            Scene::DE | Scene::RE => vec![1, 2, 3, 4, 5],
-            Scene::PE => vec![1, 2, 5, 6],
-        };
-        garg_rows
+        self.garg_rows()
             .into_iter()
             .filter(|&v| (v - hit_row).abs() <= hit_range)
             .collect()
     }
 
+    /// 不指定拦截行数时使用的默认值；屋顶场合需提供炮尾所在列
     pub fn default_delay_mode(&self, hit_col: f32, cob_col: Option<i32>) -> DelayMode {
         match self {
-            Scene::DE => {
+            Scene::DE | Scene::NE => {
                 if hit_col <= 5. {
                     DelayMode::Delay3
                 } else {
                     DelayMode::Delay2
                 }
             }
-            Scene::PE => DelayMode::Delay2,
-            Scene::RE => {
-                if hit_col <= 5. || cob_col.expect(NEED_COB_COL) <= 4 {
+            Scene::PE | Scene::FE => DelayMode::Delay2,
+            Scene::RE | Scene::ME => {
+                if hit_col <= 5. || cob_col.expect(&NEED_COB_COL) <= 4 {
                     DelayMode::Delay3
                 } else {
                     DelayMode::Delay2
@@ -236,59 +317,63 @@ impl Scene {
         }
     }
 
+    /// 该拦截行数下的所有（炮落点行，巨人所在行）组合
     pub fn hit_row_and_garg_rows_of_delay_mode(
         &self,
         delay_mode: &DelayMode,
     ) -> Vec<(i32, Vec<i32>)> {
         match delay_mode {
             DelayMode::Delay1 => match self {
-                Scene::DE => vec![(1, vec![2]), (4, vec![5])],
-                Scene::PE => vec![(1, vec![2]), (5, vec![6])],
-                Scene::RE => vec![(2, vec![3]), (4, vec![5])],
+                Scene::DE | Scene::NE => vec![(1, vec![2]), (4, vec![5])],
+                Scene::PE | Scene::FE => vec![(1, vec![2]), (5, vec![6])],
+                Scene::RE | Scene::ME => vec![(2, vec![3]), (4, vec![5])],
             },
             DelayMode::Delay2 => match self {
-                Scene::DE => vec![(1, vec![1, 2]), (4, vec![4, 5])],
-                Scene::PE => vec![(1, vec![1, 2]), (5, vec![5, 6])],
-                Scene::RE => vec![(2, vec![2, 3]), (4, vec![4, 5])],
+                Scene::DE | Scene::NE => vec![(1, vec![1, 2]), (4, vec![4, 5])],
+                Scene::PE | Scene::FE => vec![(1, vec![1, 2]), (5, vec![5, 6])],
+                Scene::RE | Scene::ME => vec![(2, vec![2, 3]), (4, vec![4, 5])],
             },
             DelayMode::Delay3 => match self {
-                Scene::DE => vec![(2, vec![1, 2, 3]), (4, vec![3, 4, 5])],
-                Scene::PE => vec![(2, vec![1, 2]), (6, vec![5, 6])],
-                Scene::RE => vec![(2, vec![1, 2, 3]), (4, vec![3, 4, 5])],
+                Scene::DE | Scene::NE => vec![(2, vec![1, 2, 3]), (4, vec![3, 4, 5])],
+                Scene::PE | Scene::FE => vec![(2, vec![1, 2]), (6, vec![5, 6])],
+                Scene::RE | Scene::ME => vec![(2, vec![1, 2, 3]), (4, vec![3, 4, 5])],
             },
         }
     }
 
+    /// 炮距；屋顶场合需提供炮尾所在列
     pub fn cob_dist(&self, cob_col: Option<i32>) -> &CobDist {
         match self {
-            Scene::DE => &DE_COB_DIST,
-            Scene::PE => &PE_COB_DIST,
-            Scene::RE => RE_COB_DIST
-                .get((cob_col.expect(NEED_COB_COL) - 1) as usize)
+            Scene::DE | Scene::NE => &DE_COB_DIST,
+            Scene::PE | Scene::FE => &PE_COB_DIST,
+            Scene::RE | Scene::ME => RE_COB_DIST
+                .get((cob_col.expect(&NEED_COB_COL) - 1) as usize)
                 .unwrap(),
         }
     }
 
-    fn row_height(&self) -> i32 {
+    /// 每行的高度（像素）
+    pub fn row_height(&self) -> i32 {
         match self {
-            Scene::DE => 100,
-            Scene::PE | Scene::RE => 85,
+            Scene::DE | Scene::NE => 100,
+            Scene::PE | Scene::FE | Scene::RE | Scene::ME => 85,
         }
     }
 
     fn zombie_base_y(&self) -> i32 {
         match self {
-            Scene::DE | Scene::PE => 50,
-            Scene::RE => 40,
+            Scene::DE | Scene::NE | Scene::PE | Scene::FE => 50,
+            Scene::RE | Scene::ME => 40,
         }
     }
 
     pub fn is_roof(&self) -> bool {
-        *self == Scene::RE
+        matches!(self, Scene::RE | Scene::ME)
     }
 }
 
-#[derive(Clone)]
+/// 玉米加农炮；屋顶场合的落点与炮所在位置有关
+#[derive(Debug, Clone, PartialEq)]
 pub enum Cob {
     Ground {
         row: i32,
@@ -303,6 +388,19 @@ pub enum Cob {
 }
 
 impl Cob {
+    pub fn ground(row: i32, col: f32) -> Cob {
+        Cob::Ground { row, col }
+    }
+
+    pub fn roof(row: i32, col: f32, cob_col: i32, cob_row: i32) -> Cob {
+        Cob::Roof {
+            row,
+            col,
+            cob_col,
+            cob_row,
+        }
+    }
+
     pub fn row(&self) -> i32 {
         match self {
             Cob::Ground { row, col: _ } => *row,
@@ -325,20 +423,89 @@ impl Cob {
             } => *col,
         }
     }
+
+    /// 炮尾所在列（仅屋顶）
+    pub fn cob_col(&self) -> Option<i32> {
+        match self {
+            Cob::Ground { row: _, col: _ } => None,
+            Cob::Roof { cob_col, .. } => Some(*cob_col),
+        }
+    }
+
+    /// 炮尾所在行（仅屋顶）
+    pub fn cob_row(&self) -> Option<i32> {
+        match self {
+            Cob::Ground { row: _, col: _ } => None,
+            Cob::Roof { cob_row, .. } => Some(*cob_row),
+        }
+    }
 }
 
-pub struct Doom {
-    pub row: i32,
-    pub col: i32,
+/// 灰烬植物；爆炸中心为植物中心
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ash {
+    /// 毁灭菇
+    Doom { row: i32, col: i32 },
+    /// 樱桃炸弹
+    Cherry { row: i32, col: i32 },
+    /// 火爆辣椒，炸到整行
+    Jalapeno { row: i32 },
 }
 
-#[derive(Debug, Clone)]
+impl Ash {
+    pub fn row(&self) -> i32 {
+        match self {
+            Ash::Doom { row, .. } | Ash::Cherry { row, .. } | Ash::Jalapeno { row } => *row,
+        }
+    }
+
+    /// 火爆辣椒为 `None`
+    pub fn col(&self) -> Option<i32> {
+        match self {
+            Ash::Doom { col, .. } | Ash::Cherry { col, .. } => Some(*col),
+            Ash::Jalapeno { .. } => None,
+        }
+    }
+
+    /// 指令名称
+    pub fn code(&self) -> &'static str {
+        match self {
+            Ash::Doom { .. } => "doom",
+            Ash::Cherry { .. } => "cherry",
+            Ash::Jalapeno { .. } => "jalapeno",
+        }
+    }
+
+    fn hit_range(&self) -> i32 {
+        match self {
+            Ash::Doom { .. } => DOOM_HIT_RANGE,
+            Ash::Cherry { .. } => CHERRY_HIT_RANGE,
+            Ash::Jalapeno { .. } => 0,
+        }
+    }
+}
+
+/// 爆炸：判定范围及可以炸到的行
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Explode {
-    pub range: Circle,
+    /// 为 `None` 时可以炸到所在行的所有小鬼
+    pub range: Option<Circle>,
     hittable_rows: Vec<i32>,
 }
 
 impl Explode {
+    pub fn center(&self) -> Option<IntVec2> {
+        self.range.as_ref().map(|range| range.center)
+    }
+
+    pub fn radius(&self) -> Option<i32> {
+        self.range.as_ref().map(|range| range.radius)
+    }
+
+    pub fn hittable_rows(&self) -> &[i32] {
+        &self.hittable_rows
+    }
+
     pub fn of_cob(cob: &Cob, scene: &Scene) -> Explode {
         let row_height = scene.row_height();
         match cob {
@@ -347,10 +514,10 @@ impl Explode {
                 x = if x >= 7 { x - 7 } else { x - 6 };
                 let y = 120 + ((row - 1) * row_height);
                 Explode {
-                    range: Circle {
+                    range: Some(Circle {
                         center: IntVec2 { x, y },
                         radius: COB_RADIUS,
-                    },
+                    }),
                     hittable_rows: scene.hittable_rows(*row, COB_HIT_RANGE),
                 }
             }
@@ -410,34 +577,111 @@ impl Explode {
                 y = cmp::max(y, 0);
                 x = if x >= 7 { x - 7 } else { x - 6 };
                 Explode {
-                    range: Circle {
+                    range: Some(Circle {
                         center: IntVec2 { x, y },
                         radius: COB_RADIUS,
-                    },
+                    }),
                     hittable_rows: scene.hittable_rows(*row, COB_HIT_RANGE),
                 }
             }
         }
     }
 
-    pub fn of_doom(Doom { row, col }: &Doom, scene: &Scene) -> Explode {
-        Explode {
-            range: Circle {
-                center: IntVec2 {
-                    x: col * COL_WIDTH,
-                    y: 120 + (row - 1) * scene.row_height(),
-                },
+    pub fn of_ash(ash: &Ash, scene: &Scene) -> Explode {
+        let center = |row: i32, col: i32| IntVec2 {
+            x: col * COL_WIDTH,
+            y: 120 + (row - 1) * scene.row_height(),
+        };
+        let range = match *ash {
+            Ash::Doom { row, col } => Some(Circle {
+                center: center(row, col),
                 radius: DOOM_RADIUS,
+            }),
+            Ash::Cherry { row, col } => Some(Circle {
+                center: center(row, col),
+                radius: CHERRY_RADIUS,
+            }),
+            Ash::Jalapeno { .. } => None,
+        };
+        Explode {
+            range,
+            hittable_rows: scene.hittable_rows(ash.row(), ash.hit_range()),
+        }
+    }
+}
+
+/// 秒杀小鬼的植物；地刺类植物每次接触只造成少量伤害，无法秒杀小鬼，不在此列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KillerPlant {
+    /// 窝瓜
+    Squash { row: i32, col: i32 },
+    /// 大嘴花
+    Chomper { row: i32, col: i32 },
+}
+
+impl KillerPlant {
+    pub fn row(&self) -> i32 {
+        match self {
+            KillerPlant::Squash { row, .. } | KillerPlant::Chomper { row, .. } => *row,
+        }
+    }
+
+    pub fn col(&self) -> i32 {
+        match self {
+            KillerPlant::Squash { col, .. } | KillerPlant::Chomper { col, .. } => *col,
+        }
+    }
+
+    /// 指令名称
+    pub fn code(&self) -> &'static str {
+        match self {
+            KillerPlant::Squash { .. } => "squash",
+            KillerPlant::Chomper { .. } => "chomper",
+        }
+    }
+}
+
+/// 秒杀：发现小鬼的x范围及发现后生效的时长，只能杀死所在行的小鬼
+#[derive(Debug, Clone)]
+pub struct Killer {
+    /// 与小鬼防御域x范围重叠即发现小鬼（含两端）
+    pub min_max_x: (i32, i32),
+    pub kill_delay: i32,
+    /// 生效时小鬼是否须仍在范围内；窝瓜会追踪目标，大嘴花则会咬空
+    recheck: bool,
+    row: i32,
+}
+
+impl Killer {
+    pub fn of_plant(plant: &KillerPlant) -> Killer {
+        // 植物坐标为所在格左边界右移40
+        let plant_x = plant.col() * COL_WIDTH - 40;
+        match *plant {
+            // 攻击域为 [x+20, x+65]，与小鬼相距70以内即会发现
+            KillerPlant::Squash { row, .. } => Killer {
+                min_max_x: (plant_x + 20 - 70, plant_x + 65 + 70),
+                kill_delay: SQUASH_KILL_DELAY,
+                recheck: false,
+                row,
+            },
+            KillerPlant::Chomper { row, .. } => Killer {
+                min_max_x: (plant_x + 80, plant_x + 120),
+                kill_delay: CHOMPER_KILL_DELAY,
+                recheck: true,
+                row,
             },
-            hittable_rows: scene.hittable_rows(*row, DOOM_HIT_RANGE),
         }
     }
 }
 
-#[derive(Debug)]
-enum ImpState {
+/// 小鬼状态；编号与游戏内存中的状态值相同
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImpState {
+    /// 落地后行走、啃食
     S0,
+    /// 飞行
     S71,
+    /// 落地僵直，`countdown` 为剩余时长
     S72 { countdown: i32 },
 }
 
@@ -451,6 +695,17 @@ struct Position {
 }
 
 impl Position {
+    fn defense(&self) -> Rectangle {
+        Rectangle {
+            upper_left: IntVec2 {
+                x: self.x as i32,
+                y: (self.y - self.h + self.y_shift) as i32,
+            } + IMP_DEFENSE_SHIFT,
+            width: IMP_DEFENSE_WIDTH,
+            height: IMP_DEFENSE_HEIGHT,
+        }
+    }
+
     fn interceptable(
         &self,
         Explode {
@@ -459,17 +714,16 @@ impl Position {
         }: &Explode,
     ) -> bool {
         hittable_rows.contains(&self.row)
-            && circle_rectangle_intersect(
-                range,
-                &Rectangle {
-                    upper_left: IntVec2 {
-                        x: self.x as i32,
-                        y: (self.y - self.h + self.y_shift) as i32,
-                    } + IMP_DEFENSE_SHIFT,
-                    width: IMP_DEFENSE_WIDTH,
-                    height: IMP_DEFENSE_HEIGHT,
-                },
-            )
+            && range
+                .as_ref()
+                .is_none_or(|range| circle_rectangle_intersect(range, &self.defense()))
+    }
+
+    fn killable(&self, Killer { min_max_x, row, .. }: &Killer) -> bool {
+        let defense = self.defense();
+        self.row == *row
+            && defense.upper_left.x <= min_max_x.1
+            && defense.upper_left.x + defense.width >= min_max_x.0
     }
 }
 
@@ -481,13 +735,32 @@ struct Imp {
     exist_time: i32,
 }
 
-#[derive(PartialEq, Debug)]
+/// 小鬼最早啃食、最早可冰时机
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum Eat {
+    /// 不会投掷小鬼
     Empty,
-    Some { eat: i32, iceable: i32 },
+    Some {
+        eat: i32,
+        iceable: i32,
+    },
 }
 
 impl Eat {
+    pub fn eat(&self) -> Option<i32> {
+        match self {
+            Eat::Empty => None,
+            Eat::Some { eat, iceable: _ } => Some(*eat),
+        }
+    }
+
+    pub fn iceable(&self) -> Option<i32> {
+        match self {
+            Eat::Empty => None,
+            Eat::Some { eat: _, iceable } => Some(*iceable),
+        }
+    }
+
     fn new(eat: Option<i32>, iceable: Option<i32>) -> Self {
         match (eat, iceable) {
             (Some(e), Some(i)) => Self::Some { eat: e, iceable: i },
@@ -524,15 +797,29 @@ impl Eat {
     }
 }
 
-#[derive(PartialEq, Debug)]
+/// 可拦区间
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum Intercept {
+    /// 不会投掷小鬼
     Empty,
     Fail,
+    /// 只能炸到先生成的小鬼
     OnlyHighIndexImp,
-    Success { min: i32, max: i32 },
+    /// `max` 为 [`MAX_INTERCEPTION_DELAY`] 表示此后均可拦截
+    Success {
+        min: i32,
+        max: i32,
+    },
 }
 
 impl Intercept {
+    pub fn interval(&self) -> Option<(i32, i32)> {
+        match self {
+            Intercept::Success { min, max } => Some((*min, *max)),
+            _ => None,
+        }
+    }
+
     fn update(&mut self, curr_time: i32, position: &Position, explode: &Explode) {
         let interceptable = position.interceptable(explode);
         match self {
@@ -587,16 +874,68 @@ impl Intercept {
     pub fn shift_to_plant_intercept(&mut self) {
         match self {
             Intercept::Empty | Intercept::Fail | Intercept::OnlyHighIndexImp => {}
+            // 此后均可拦截时保持不设上限
             Intercept::Success { min, max } => {
                 *self = Intercept::Success {
                     min: *min + 1,
-                    max: *max + 1,
+                    max: if *max == MAX_INTERCEPTION_DELAY {
+                        *max
+                    } else {
+                        *max + 1
+                    },
                 }
             }
         }
     }
 }
 
+/// 秒杀时机
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Kill {
+    /// 不会投掷小鬼
+    Empty,
+    /// 存在未被秒杀的小鬼
+    Fail,
+    /// 所有小鬼均被秒杀，最早、最晚秒杀时机
+    Success { min: i32, max: i32 },
+}
+
+impl Kill {
+    pub fn interval(&self) -> Option<(i32, i32)> {
+        match self {
+            Kill::Success { min, max } => Some((*min, *max)),
+            _ => None,
+        }
+    }
+
+    /// 所有小鬼是否均在最早啃食前被秒杀
+    pub fn before_eat(&self, eat: &Eat) -> bool {
+        match (self, eat.eat()) {
+            (Kill::Success { min: _, max }, Some(eat)) => *max < eat,
+            _ => false,
+        }
+    }
+
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Empty, any) | (any, Self::Empty) => any,
+            (
+                Self::Success { min, max },
+                Self::Success {
+                    min: other_min,
+                    max: other_max,
+                },
+            ) => Self::Success {
+                min: cmp::min(min, other_min),
+                max: cmp::max(max, other_max),
+            },
+            _ => Self::Fail,
+        }
+    }
+}
+
+/// 用于计算的巨人x范围；x<401的巨人不会投掷小鬼
+#[derive(Debug, Clone, PartialEq)]
 pub enum GargXRange {
     Cancelled,
     Modified { min: f32, max: f32 },
@@ -631,6 +970,9 @@ impl GargXRange {
     }
 }
 
+/// 计算 `garg_x_range` 内、位于各自对应行的巨人投掷的小鬼被爆炸拦截的情况。
+/// 结果以巨人位于 `garg_x_range` 的时刻为起点（植物激活已计入 [`min_max_garg_x`]）；
+/// 炮拦截，植物拦截请使用 `shift_to_plant_intercept`
 pub fn judge(
     garg_x_range: &GargXRange,
     explode_and_garg_rows: &[(Explode, &Vec<i32>)],
@@ -665,6 +1007,573 @@ pub fn judge(
     (eat, intercept)
 }
 
+/// 一条小鬼轨迹（巨人x坐标、所在行及随机数）的拦截情况
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trajectory {
+    pub garg_x: f32,
+    pub garg_row: i32,
+    pub rnd: i32,
+    /// 小鬼啃食时机
+    pub eat: i32,
+    /// 最先拦截该小鬼的爆炸序号（从0开始）及其生效时机；为 `None` 表示未被拦截
+    pub killer: Option<(usize, i32)>,
+}
+
+impl Trajectory {
+    /// 是否在啃食前被拦截
+    pub fn is_safe(&self) -> bool {
+        matches!(self.killer, Some((_, time)) if time < self.eat)
+    }
+}
+
+/// 计算秒杀植物对 `garg_x_range` 内巨人投掷的小鬼的秒杀时机，时机与 [`judge`] 所得最早啃食的含义相同
+pub fn judge_killer(
+    garg_x_range: &GargXRange,
+    garg_rows: &[i32],
+    iced: bool,
+    scene: &Scene,
+    killer: &Killer,
+) -> (Eat, Kill) {
+    let mut eat = Eat::Empty;
+    let mut kill = Kill::Empty;
+    for garg_x in garg_x_range.to_list() {
+        for &garg_row in garg_rows {
+            for rnd in [0, 100] {
+                let (new_eat, new_kill) = judge_killer_internal(
+                    &Vec2 {
+                        x: garg_x,
+                        y: (scene.zombie_base_y() + (garg_row - 1) * scene.row_height()) as f32,
+                    },
+                    garg_row,
+                    rnd,
+                    iced,
+                    scene,
+                    killer,
+                );
+                eat = eat.merge(new_eat);
+                kill = kill.merge(new_kill);
+            }
+        }
+    }
+    (eat, kill)
+}
+
+/// 计算在不同时机生效的多个爆炸对 `garg_x_range` 内巨人投掷的各小鬼的拦截情况。
+/// 每个爆炸只考虑其对应行的巨人；生效时机与 [`judge`] 所得可拦区间的含义相同
+pub fn judge_timed(
+    garg_x_range: &GargXRange,
+    explode_time_and_garg_rows: &[(Explode, i32, &Vec<i32>)],
+    iced: bool,
+    scene: &Scene,
+) -> Vec<Trajectory> {
+    let mut garg_xs = garg_x_range.to_list();
+    garg_xs.dedup();
+    let mut all_garg_rows = explode_time_and_garg_rows
+        .iter()
+        .flat_map(|(_, _, garg_rows)| garg_rows.iter().copied())
+        .collect::<Vec<i32>>();
+    all_garg_rows.sort();
+    all_garg_rows.dedup();
+    let mut trajectories = vec![];
+    for garg_x in garg_xs {
+        for &garg_row in &all_garg_rows {
+            for rnd in [0, 100] {
+                let garg_pos = Vec2 {
+                    x: garg_x,
+                    y: (scene.zombie_base_y() + (garg_row - 1) * scene.row_height()) as f32,
+                };
+                let mut eat = Eat::Empty;
+                let mut killer: Option<(usize, i32)> = None;
+                for (index, (explode, time, garg_rows)) in
+                    explode_time_and_garg_rows.iter().enumerate()
+                {
+                    if !garg_rows.contains(&garg_row) {
+                        continue;
+                    }
+                    let (new_eat, intercept) =
+                        judge_internal(&garg_pos, garg_row, rnd, iced, scene, explode);
+                    eat = new_eat;
+                    let killed = intercept
+                        .interval()
+                        .is_some_and(|interval| interval_contains(interval, *time));
+                    if killed && killer.is_none_or(|(_, killer_time)| *time < killer_time) {
+                        killer = Some((index, *time));
+                    }
+                }
+                // 不会投掷小鬼时没有轨迹
+                if let Eat::Some { eat, iceable: _ } = eat {
+                    trajectories.push(Trajectory {
+                        garg_x,
+                        garg_row,
+                        rnd,
+                        eat,
+                        killer,
+                    });
+                }
+            }
+        }
+    }
+    trajectories
+}
+
+/// 小鬼在某一时刻的状态
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImpTick {
+    pub tick: i32,
+    pub state: ImpState,
+    pub x: f32,
+    pub y: f32,
+    pub h: f32,
+    pub y_shift: f32,
+    pub defense: Rectangle,
+    /// 给定爆炸此刻能否炸到该小鬼；未给定爆炸时为 `None`
+    pub hit: Option<bool>,
+}
+
+/// 逐刻给出巨人投掷的小鬼的状态，直到啃食及可冰时机均已确定；不会投掷小鬼时为空。
+/// 时刻与 [`judge`] 所得可拦区间的含义相同；首个时刻只能炸到先生成的小鬼，不计入可拦区间
+pub fn trace(
+    garg_x: f32,
+    garg_row: i32,
+    rnd: i32,
+    iced: bool,
+    scene: &Scene,
+    explode: Option<&Explode>,
+) -> Vec<ImpTick> {
+    let garg_pos = Vec2 {
+        x: garg_x,
+        y: (scene.zombie_base_y() + (garg_row - 1) * scene.row_height()) as f32,
+    };
+    let mut ticks = vec![];
+    simulate_imp(&garg_pos, garg_row, rnd, iced, scene, |tick, imp| {
+        ticks.push(ImpTick {
+            tick,
+            state: imp.state,
+            x: imp.position.x,
+            y: imp.position.y,
+            h: imp.position.h,
+            y_shift: imp.position.y_shift,
+            defense: imp.position.defense(),
+            hit: explode.map(|explode| imp.position.interceptable(explode)),
+        })
+    });
+    ticks
+}
+
+/// 一条小鬼轨迹（巨人x坐标、所在行及随机数）逐刻的状态
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImpTrace {
+    pub garg_x: f32,
+    pub garg_row: i32,
+    pub rnd: i32,
+    /// `hit` 为对应爆炸此刻能否炸到该小鬼
+    pub ticks: Vec<ImpTick>,
+}
+
+/// 逐刻给出 [`judge`] 所考虑的各条小鬼轨迹（巨人x极值、所在行及rnd极值）；不会投掷小鬼的轨迹不计入
+pub fn trace_extremes(
+    garg_x_range: &GargXRange,
+    explode_and_garg_rows: &[(Explode, Vec<i32>)],
+    iced: bool,
+    scene: &Scene,
+) -> Vec<ImpTrace> {
+    let mut garg_xs = garg_x_range.to_list();
+    garg_xs.dedup();
+    let mut traces = vec![];
+    for (explode, garg_rows) in explode_and_garg_rows {
+        for &garg_x in &garg_xs {
+            for &garg_row in garg_rows {
+                for rnd in [0, 100] {
+                    let ticks = trace(garg_x, garg_row, rnd, iced, scene, Some(explode));
+                    if !ticks.is_empty() {
+                        traces.push(ImpTrace {
+                            garg_x,
+                            garg_row,
+                            rnd,
+                            ticks,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    traces
+}
+
+/// 小鬼落地（进入 `S72`）时的x坐标及时刻
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImpLanding {
+    pub x: f32,
+    pub tick: i32,
+}
+
+/// 巨人投掷的小鬼落地时的x坐标及时刻，与巨人所在行无关；不会投掷小鬼时返回 `None`
+pub fn imp_landing(garg_x: f32, rnd: i32, iced: bool, scene: &Scene) -> Option<ImpLanding> {
+    let garg_pos = Vec2 {
+        x: garg_x,
+        y: scene.zombie_base_y() as f32,
+    };
+    let mut landing = None;
+    simulate_imp(&garg_pos, 1, rnd, iced, scene, |tick, imp| {
+        if landing.is_none() && imp.state != ImpState::S71 {
+            landing = Some(ImpLanding {
+                x: imp.position.x,
+                tick,
+            });
+        }
+    })?;
+    landing
+}
+
+/// 小鬼落地x坐标及时刻的范围
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImpLandingRange {
+    pub min_max_x: (f32, f32),
+    pub min_max_tick: (i32, i32),
+}
+
+// 二分查找落地时刻变化处的精度
+const LANDING_GARG_X_PRECISION: f32 = 0.001;
+
+/// `garg_x_range` 内的巨人以任意 rnd (0~100) 投掷的小鬼的落地范围；均不会投掷小鬼时返回 `None`
+///
+/// 落地时刻相同时落地x坐标随巨人x线性变化，只需二分找出落地时刻变化处，精度为 0.001。
+pub fn imp_landing_range(
+    garg_x_range: &GargXRange,
+    iced: bool,
+    scene: &Scene,
+) -> Option<ImpLandingRange> {
+    let (min_garg_x, max_garg_x) = match *garg_x_range {
+        GargXRange::Cancelled => return None,
+        GargXRange::Modified { min, max } | GargXRange::Ok { min, max } => (min, max),
+    };
+    // 小鬼y初速为 40 及 140 处，其两侧的投掷规则不同
+    let roof_offset = if scene.is_roof() { 180. } else { 0. };
+    let mut garg_xs = vec![min_garg_x];
+    garg_xs.extend(
+        [400. + roof_offset, 500. + roof_offset]
+            .into_iter()
+            .filter(|&garg_x| garg_x > min_garg_x && garg_x < max_garg_x),
+    );
+    garg_xs.push(max_garg_x);
+    let mut landings = vec![];
+    for rnd in 0..=100 {
+        let landing = |garg_x: f32| imp_landing(garg_x, rnd, iced, scene);
+        for pair in garg_xs.windows(2) {
+            let (min, max) = (pair[0], pair[1]);
+            find_landings(
+                min,
+                max,
+                landing(min),
+                landing(max),
+                &landing,
+                &mut landings,
+            );
+        }
+    }
+    let mut landings = landings.into_iter();
+    let first = landings.next()?;
+    let init = ImpLandingRange {
+        min_max_x: (first.x, first.x),
+        min_max_tick: (first.tick, first.tick),
+    };
+    Some(landings.fold(init, |range, landing| ImpLandingRange {
+        min_max_x: (
+            range.min_max_x.0.min(landing.x),
+            range.min_max_x.1.max(landing.x),
+        ),
+        min_max_tick: (
+            range.min_max_tick.0.min(landing.tick),
+            range.min_max_tick.1.max(landing.tick),
+        ),
+    }))
+}
+
+// 两端落地时刻相同时区间内各处均相同，落地x坐标的极值在两端取得
+fn find_landings(
+    min_garg_x: f32,
+    max_garg_x: f32,
+    min_landing: Option<ImpLanding>,
+    max_landing: Option<ImpLanding>,
+    landing: &impl Fn(f32) -> Option<ImpLanding>,
+    landings: &mut Vec<ImpLanding>,
+) {
+    let tick = |landing: Option<ImpLanding>| landing.map(|landing| landing.tick);
+    if tick(min_landing) == tick(max_landing) || max_garg_x - min_garg_x < LANDING_GARG_X_PRECISION
+    {
+        landings.extend(min_landing);
+        landings.extend(max_landing);
+        return;
+    }
+    let mid_garg_x = (min_garg_x + max_garg_x) / 2.;
+    let mid_landing = landing(mid_garg_x);
+    find_landings(
+        min_garg_x,
+        mid_garg_x,
+        min_landing,
+        mid_landing,
+        landing,
+        landings,
+    );
+    find_landings(
+        mid_garg_x,
+        max_garg_x,
+        mid_landing,
+        max_landing,
+        landing,
+        landings,
+    );
+}
+
+/// 场地上种有植物的格子（行、列）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Lawn {
+    cells: BTreeSet<(i32, i32)>,
+}
+
+impl Lawn {
+    pub fn new(cells: impl IntoIterator<Item = (i32, i32)>) -> Lawn {
+        Lawn {
+            cells: cells.into_iter().collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// 按行、列从小到大
+    pub fn cells(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.cells.iter().copied()
+    }
+
+    /// `row` 行种有植物的列，从小到大
+    pub fn cols(&self, row: i32) -> Vec<i32> {
+        self.cells
+            .iter()
+            .filter(|(r, _)| *r == row)
+            .map(|(_, col)| *col)
+            .collect()
+    }
+}
+
+/// 小鬼的啃食目标
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EatTarget {
+    /// 啃食 `col` 列的植物，开始啃食的时机与 [`Eat`] 的含义相同
+    Plant { col: i32, eat: i32 },
+    /// 越过所有植物继续左行
+    Pass,
+}
+
+/// 小鬼可以啃食 `col` 列植物的x坐标范围（含两端）
+fn eat_x_range(col: i32) -> (f32, f32) {
+    let plant_left = col * COL_WIDTH - 40 + PLANT_RECT_SHIFT;
+    (
+        (plant_left + EAT_OVERLAP - IMP_ATTACK_SHIFT - IMP_ATTACK_WIDTH) as f32,
+        (plant_left + PLANT_RECT_WIDTH - EAT_OVERLAP - IMP_ATTACK_SHIFT) as f32,
+    )
+}
+
+/// 巨人投掷的小鬼落地后在 `plant_cols` 列有植物的行中的啃食目标；不会投掷小鬼时返回 `None`
+///
+/// 小鬼落地后站定，僵直结束后向左行走，遇到第一个可以啃食的植物即开始啃食；
+/// 僵直结束时已可啃食的结果与 [`judge`] 所得最早啃食相同
+pub fn eat_target(
+    garg_x: f32,
+    garg_row: i32,
+    rnd: i32,
+    iced: bool,
+    scene: &Scene,
+    plant_cols: &[i32],
+) -> Option<EatTarget> {
+    let garg_pos = Vec2 {
+        x: garg_x,
+        y: (scene.zombie_base_y() + (garg_row - 1) * scene.row_height()) as f32,
+    };
+    let mut stood = None;
+    simulate_imp(&garg_pos, garg_row, rnd, iced, scene, |tick, imp| {
+        if stood.is_none() && imp.state == ImpState::S0 {
+            stood = Some((tick, imp.position.x, imp.exist_time));
+        }
+    })?;
+    // 僵直结束的一刻即可啃食
+    let (stood_tick, x, exist_time) = stood?;
+    let Some((col, (_, max_x))) = plant_cols
+        .iter()
+        .map(|&col| (col, eat_x_range(col)))
+        .filter(|(_, (min_x, _))| x >= *min_x)
+        .max_by_key(|(col, _)| *col)
+    else {
+        return Some(EatTarget::Pass);
+    };
+    let speed = if iced {
+        IMP_WALK_SPEED / 2.
+    } else {
+        IMP_WALK_SPEED
+    };
+    let walk_ticks = if x <= max_x {
+        0
+    } else {
+        ((x - max_x) / speed).ceil() as i32
+    };
+    let eat_loop = if iced { 8 } else { 4 };
+    let wait = (eat_loop - (exist_time + walk_ticks) % eat_loop) % eat_loop;
+    Some(EatTarget::Plant {
+        col,
+        eat: stood_tick + walk_ticks + wait,
+    })
+}
+
+/// 一行小鬼的啃食目标
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowEatTargets {
+    pub garg_row: i32,
+    /// 被啃食的各列及该列的最早啃食时机，按列从大到小
+    pub targets: Vec<(i32, i32)>,
+    /// 是否有小鬼越过所有植物
+    pub passes: bool,
+}
+
+/// `garg_x_range` 内的巨人以任意 rnd (0~100) 投掷的小鬼在 `lawn` 中各行的啃食目标；
+/// 均不会投掷小鬼的行没有目标
+pub fn eat_targets(
+    garg_x_range: &GargXRange,
+    garg_rows: &[i32],
+    iced: bool,
+    scene: &Scene,
+    lawn: &Lawn,
+) -> Vec<RowEatTargets> {
+    garg_rows
+        .iter()
+        .map(|&garg_row| {
+            let plant_cols = lawn.cols(garg_row);
+            let mut targets = BTreeMap::new();
+            let mut passes = false;
+            for garg_x in garg_x_range.to_list() {
+                // 落地x坐标并非随 rnd 单调变化，需遍历所有 rnd
+                for rnd in 0..=100 {
+                    match eat_target(garg_x, garg_row, rnd, iced, scene, &plant_cols) {
+                        None => {}
+                        Some(EatTarget::Pass) => passes = true,
+                        Some(EatTarget::Plant { col, eat }) => {
+                            let entry = targets.entry(col).or_insert(eat);
+                            *entry = cmp::min(*entry, eat);
+                        }
+                    }
+                }
+            }
+            RowEatTargets {
+                garg_row,
+                targets: targets.into_iter().rev().collect(),
+                passes,
+            }
+        })
+        .collect()
+}
+
+// 小鬼x坐标对照表中巨人x的精度为 0.001，以千分之一像素为单位遍历
+const IMP_X_TABLE_GRID: f32 = 1000.;
+
+/// 生成 `constants` 中的小鬼x坐标对照表：落地x坐标取整为各小鬼x坐标时，投掷该小鬼的巨人x范围。
+/// 精确遍历 401~854 间精度为 0.001 的所有巨人x及 0~100 的所有 rnd；
+/// 返回最小的小鬼x坐标及依次对应的巨人x范围，没有巨人会投掷该坐标的小鬼时为 `None`
+pub fn min_max_garg_x_of_imp_x_table(scene: &Scene) -> (i32, Vec<Option<(f32, f32)>>) {
+    let garg_x_of = |n: i32| n as f32 / IMP_X_TABLE_GRID;
+    let (min_n, max_n) = (
+        (GARG_THROW_IMP_THRES * IMP_X_TABLE_GRID) as i32,
+        (MAX_GARG_START_POS * IMP_X_TABLE_GRID) as i32,
+    );
+    // 小鬼y初速为 40 及 140 处，其两侧的投掷规则不同
+    let roof_offset = if scene.is_roof() { 180 } else { 0 };
+    let mut splits = vec![min_n];
+    splits.extend(
+        [400 + roof_offset, 500 + roof_offset]
+            .map(|garg_x| garg_x * IMP_X_TABLE_GRID as i32)
+            .into_iter()
+            .filter(|&n| n > min_n && n < max_n),
+    );
+    splits.push(max_n);
+    let mut table = BTreeMap::<i32, (i32, i32)>::new();
+    for rnd in 0..=100 {
+        let landing = |n: i32| imp_landing(garg_x_of(n), rnd, false, scene);
+        let imp_x = |n: i32| landing(n).unwrap().x.floor() as i32;
+        let mut pieces = vec![];
+        for pair in splits.windows(2) {
+            let (min, max) = (pair[0], pair[1]);
+            find_landing_pieces(min, max, landing(min), landing(max), &landing, &mut pieces);
+        }
+        // 落地时刻相同时小鬼x坐标随巨人x单调递增
+        for (min, max) in pieces {
+            let mut start = min;
+            for next_imp_x in imp_x(min) + 1..=imp_x(max) {
+                let end = partition_point(start, max, |n| imp_x(n) >= next_imp_x);
+                update_imp_x_table(&mut table, next_imp_x - 1, start, end - 1);
+                start = end;
+            }
+            update_imp_x_table(&mut table, imp_x(max), start, max);
+        }
+    }
+    let min_imp_x = *table.keys().next().unwrap();
+    let max_imp_x = *table.keys().last().unwrap();
+    let min_max_garg_x = (min_imp_x..=max_imp_x)
+        .map(|imp_x| {
+            table
+                .get(&imp_x)
+                .map(|&(min, max)| (garg_x_of(min), garg_x_of(max)))
+        })
+        .collect::<Vec<Option<(f32, f32)>>>();
+    (min_imp_x, min_max_garg_x)
+}
+
+// 将 [min, max] 分为落地时刻相同的各段，不会投掷小鬼的段被略去
+fn find_landing_pieces(
+    min: i32,
+    max: i32,
+    min_landing: Option<ImpLanding>,
+    max_landing: Option<ImpLanding>,
+    landing: &impl Fn(i32) -> Option<ImpLanding>,
+    pieces: &mut Vec<(i32, i32)>,
+) {
+    let tick = |landing: Option<ImpLanding>| landing.map(|landing| landing.tick);
+    if tick(min_landing) == tick(max_landing) || max - min <= 1 {
+        match (min_landing, max_landing) {
+            (Some(_), Some(_)) if tick(min_landing) == tick(max_landing) => pieces.push((min, max)),
+            _ => {
+                if min_landing.is_some() {
+                    pieces.push((min, min));
+                }
+                if max_landing.is_some() {
+                    pieces.push((max, max));
+                }
+            }
+        }
+        return;
+    }
+    let mid = (min + max) / 2;
+    let mid_landing = landing(mid);
+    find_landing_pieces(min, mid, min_landing, mid_landing, landing, pieces);
+    find_landing_pieces(mid, max, mid_landing, max_landing, landing, pieces);
+}
+
+// [min, max] 中使 `pred` 成立的最小值；`pred` 单调且 `pred(max)` 成立
+fn partition_point(mut min: i32, mut max: i32, pred: impl Fn(i32) -> bool) -> i32 {
+    while min < max {
+        let mid = (min + max) / 2;
+        if pred(mid) {
+            max = mid;
+        } else {
+            min = mid + 1;
+        }
+    }
+    min
+}
+
+fn update_imp_x_table(table: &mut BTreeMap<i32, (i32, i32)>, imp_x: i32, min: i32, max: i32) {
+    let entry = table.entry(imp_x).or_insert((min, max));
+    *entry = (entry.0.min(min), entry.1.max(max));
+}
+
 // 默认炮激活、炮拦截
 fn judge_internal(
     garg_pos: &Vec2,
@@ -674,15 +1583,86 @@ fn judge_internal(
     scene: &Scene,
     explode: &Explode,
 ) -> (Eat, Intercept) {
-    if garg_pos.x < GARG_THROW_IMP_THRES {
+    let mut intercept = Intercept::Empty;
+    let simulated = simulate_imp(garg_pos, garg_row, rnd, iced, scene, |tick, imp| {
+        intercept.update(tick, &imp.position, explode)
+    });
+    let Some((eat, iceable, last_tick)) = simulated else {
         return (Eat::Empty, Intercept::Empty);
+    };
+    if let Intercept::Success { min: _, max } = &mut intercept {
+        if *max == last_tick {
+            *max = MAX_INTERCEPTION_DELAY;
+        }
+    }
+    (Eat::new(Some(eat), Some(iceable)), intercept)
+}
+
+fn judge_killer_internal(
+    garg_pos: &Vec2,
+    garg_row: i32,
+    rnd: i32,
+    iced: bool,
+    scene: &Scene,
+    killer: &Killer,
+) -> (Eat, Kill) {
+    let mut killable_ticks = vec![];
+    let simulated = simulate_imp(garg_pos, garg_row, rnd, iced, scene, |tick, imp| {
+        killable_ticks.push((tick, imp.position.killable(killer)))
+    });
+    let Some((eat, iceable, _)) = simulated else {
+        return (Eat::Empty, Kill::Empty);
+    };
+    // 模拟结束后小鬼停在原地啃食
+    let killable_at = |tick: i32| {
+        killable_ticks
+            .iter()
+            .find(|(t, _)| *t == tick)
+            .or(killable_ticks.last())
+            .is_some_and(|(_, killable)| *killable)
+    };
+    let mut ready = 0;
+    let kill = loop {
+        // 需要复查的植物咬空后重新寻找目标
+        let found = match killable_ticks
+            .iter()
+            .find(|(tick, killable)| *tick >= ready && *killable)
+        {
+            Some(&(found, _)) => found,
+            None if killable_at(ready) => ready,
+            None => break Kill::Fail,
+        };
+        let kill_time = found + killer.kill_delay;
+        if !killer.recheck || killable_at(kill_time) {
+            break Kill::Success {
+                min: kill_time,
+                max: kill_time,
+            };
+        }
+        ready = kill_time;
+    };
+    (Eat::new(Some(eat), Some(iceable)), kill)
+}
+
+// 逐刻模拟小鬼，每刻更新后调用 `on_tick`；返回啃食、可冰时机及最后模拟的时刻，不会投掷小鬼时返回 `None`
+#[allow(clippy::excessive_precision)] // 与游戏内 0.05f 的双精度值保持一致
+fn simulate_imp(
+    garg_pos: &Vec2,
+    garg_row: i32,
+    rnd: i32,
+    iced: bool,
+    scene: &Scene,
+    mut on_tick: impl FnMut(i32, &Imp),
+) -> Option<(i32, i32, i32)> {
+    if garg_pos.x < GARG_THROW_IMP_THRES {
+        return None;
     }
     let mut imp_velocity_y = garg_pos.x - 360. - (if scene.is_roof() { 180. } else { 0. });
     if imp_velocity_y >= 40. {
         if imp_velocity_y > 140. {
             imp_velocity_y -= rnd as f32;
         } else if rnd != 0 {
-            return (Eat::Empty, Intercept::Empty);
+            return None;
         }
     } else {
         imp_velocity_y = 40.;
@@ -713,9 +1693,8 @@ fn judge_internal(
     };
     let mut eat: Option<i32> = None;
     let mut iceable: Option<i32> = None;
-    let mut intercept = Intercept::Empty;
     let mut tick = imp_spawn_time + 1;
-    while eat.is_none() || iceable.is_none() {
+    loop {
         imp.exist_time += 1;
         match imp.state {
             ImpState::S71 => {
@@ -751,176 +1730,212 @@ fn judge_internal(
                 }
             }
         }
-        intercept.update(tick, &imp.position, explode);
-        tick += 1;
-    }
-    if let Intercept::Success { min: _, max } = &mut intercept {
-        if *max == tick - 1 {
-            *max = MAX_INTERCEPTION_DELAY;
+        on_tick(tick, &imp);
+        if let (Some(eat), Some(iceable)) = (eat, iceable) {
+            return Some((eat, iceable, tick));
         }
+        tick += 1;
     }
-    (Eat::new(eat, iceable), intercept)
 }
 
+/// 用冰时机及激活时机
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct IceAndCobTimes {
+    /// 寒冰菇生效时机（冻结并减速）
     pub ice_times: Vec<i32>,
+    /// 冰瓜、寒冰射手命中时机（仅减速）
+    pub slow_times: Vec<i32>,
     pub cob_time: i32,
+    pub activation: Activation,
+}
+
+/// 激活方式；计算结果均以激活时机为起点
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Activation {
+    #[default]
+    Cob,
+    /// 灰烬等植物激活，结果比炮激活少1
+    Plant,
+}
+
+impl Activation {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Activation::Cob => "cob",
+            Activation::Plant => "plant",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Activation> {
+        match code {
+            "cob" => Some(Activation::Cob),
+            "plant" => Some(Activation::Plant),
+            _ => None,
+        }
+    }
+}
+
+// 同一时刻先冰冻后减速
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum IceKind {
+    Ice,
+    Slow,
 }
 
 impl IceAndCobTimes {
     pub fn of_ice_times_and_cob_time(
         ice_times: &[i32],
         cob_time: i32,
+    ) -> Result<IceAndCobTimes, String> {
+        IceAndCobTimes::of_times(ice_times, &[], cob_time, Activation::Cob)
+    }
+
+    /// 激活之后及负数的用冰时机被忽略
+    pub fn of_times(
+        ice_times: &[i32],
+        slow_times: &[i32],
+        cob_time: i32,
+        activation: Activation,
     ) -> Result<IceAndCobTimes, String> {
         if cob_time < 0 {
             return Err(format!(
                 "{COB_TIME_SHOULD_BE_NON_NEGATIVE} ({INPUT_ERROR_GOT}: {cob_time})"
             ));
         }
-        let mut ice_times = ice_times
-            .iter()
-            .filter(|&&v| v >= 0 && v <= cob_time)
-            .cloned()
-            .collect::<Vec<i32>>();
-        ice_times.sort();
-        Ok(IceAndCobTimes {
-            ice_times,
+        let valid_times = |times: &[i32]| {
+            let mut times = times
+                .iter()
+                .filter(|&&v| v >= 0 && v <= cob_time)
+                .cloned()
+                .collect::<Vec<i32>>();
+            times.sort();
+            times
+        };
+        let ice_and_cob_times = IceAndCobTimes {
+            ice_times: valid_times(ice_times),
+            slow_times: valid_times(slow_times),
             cob_time,
-        })
+            activation,
+        };
+        // 行走最快的巨人已进家时，巨人x范围没有意义
+        let (min_garg_x, _) = min_max_garg_x(&ice_and_cob_times);
+        if min_garg_x <= MIN_GARG_X {
+            return Err(format!(
+                "{GARG_REACHED_HOUSE} ({INPUT_ERROR_GOT}: {cob_time})"
+            ));
+        }
+        Ok(ice_and_cob_times)
     }
 
+    /// 激活时巨人是否处于减速状态
     pub fn is_iced(&self) -> bool {
-        match (self.ice_times.last(), self.cob_time) {
-            (None, _) => false,
-            (Some(last_ice_time), cob_time) => cob_time - last_ice_time <= ICE_SLOW_TOTAL_TIME,
-        }
+        self.chill_end()
+            .is_some_and(|chill_end| self.garg_time() <= chill_end)
     }
-}
 
-pub fn min_max_garg_x(
-    IceAndCobTimes {
-        ice_times,
-        cob_time,
-    }: &IceAndCobTimes,
-) -> Result<(f32, f32), String> {
-    let (min_half_ticks, max_half_ticks) = min_max_garg_walk_in_half_ticks(ice_times, *cob_time);
-    match (
-        constants::garg_slow_of_half_ticks(min_half_ticks),
-        constants::garg_fast_of_half_ticks(max_half_ticks),
-    ) {
-        (None, _) => Err(GARG_MIN_WALK_OUT_OF_RANGE.format(&[
-            (min_half_ticks as f32 / 2.).to_string(),
-            0.to_string(),
-            (constants::GARG_DATA_SIZE - 1).to_string(),
-        ])),
-        (_, None) => Err(GARG_MAX_WALK_OUT_OF_RANGE.format(&[
-            (max_half_ticks as f32 / 2.).to_string(),
-            0.to_string(),
-            (constants::GARG_DATA_SIZE - 1).to_string(),
-        ])),
-        (Some(min_walk), Some(max_walk)) => {
-            Ok((MIN_GARG_START_POS - max_walk, MAX_GARG_START_POS - min_walk))
+    // 计算结果所对应的巨人坐标时刻；植物激活时按晚1cs的巨人坐标计算，结果即比炮激活少1
+    fn garg_time(&self) -> i64 {
+        match self.activation {
+            Activation::Cob => self.cob_time as i64,
+            Activation::Plant => self.cob_time as i64 + 1,
         }
     }
+
+    // 按时间顺序排列的各次冰冻、减速
+    fn ice_events(&self) -> Vec<(i32, IceKind)> {
+        let mut events = self
+            .ice_times
+            .iter()
+            .map(|&time| (time, IceKind::Ice))
+            .chain(self.slow_times.iter().map(|&time| (time, IceKind::Slow)))
+            .collect::<Vec<_>>();
+        events.sort();
+        events
+    }
+
+    // 最后一次处于减速状态的时刻；再次减速时取较晚者
+    fn chill_end(&self) -> Option<i64> {
+        self.ice_events()
+            .iter()
+            .map(|&(time, kind)| match kind {
+                IceKind::Ice => time as i64 + ICE_SLOW_TOTAL_TIME as i64,
+                IceKind::Slow => time as i64 + SLOW_TIME as i64,
+            })
+            .max()
+    }
+}
+
+/// 激活时巨人x坐标的最小、最大值
+pub fn min_max_garg_x(ice_and_cob_times: &IceAndCobTimes) -> (f32, f32) {
+    let (min_half_ticks, max_half_ticks) = min_max_garg_walk_in_half_ticks(ice_and_cob_times);
+    let min_walk = constants::garg_slow_of_half_ticks(min_half_ticks);
+    let max_walk = constants::garg_fast_of_half_ticks(max_half_ticks);
+    (MIN_GARG_START_POS - max_walk, MAX_GARG_START_POS - min_walk)
 }
 
-fn min_max_garg_walk_in_half_ticks(valid_ice_times: &[i32], cob_time: i32) -> (i32, i32) {
+/// 巨人最长行走时间超出行走数据表长、激活时巨人x坐标按行走周期外推时，返回该时间（cs）
+pub fn extrapolated_garg_walk(ice_and_cob_times: &IceAndCobTimes) -> Option<f32> {
+    let (_, max_half_ticks) = min_max_garg_walk_in_half_ticks(ice_and_cob_times);
+    constants::garg_walk_extrapolated(max_half_ticks).then_some(max_half_ticks as f32 / 2.)
+}
+
+// 超出 i32 范围时取 i32::MAX，此时巨人早已进家
+fn min_max_garg_walk_in_half_ticks(ice_and_cob_times: &IceAndCobTimes) -> (i32, i32) {
+    let walk = |ice_length_for_iced, ice_length_for_uniced| {
+        i32::try_from(garg_walk_in_half_ticks(
+            ice_and_cob_times,
+            ice_length_for_iced,
+            ice_length_for_uniced,
+        ))
+        .unwrap_or(i32::MAX)
+    };
     (
-        garg_walk_in_half_ticks(
-            valid_ice_times,
-            cob_time,
-            MAX_ICE_TIME_FOR_ICED,
-            MAX_ICE_TIME_FOR_UNICED,
-        ),
-        garg_walk_in_half_ticks(
-            valid_ice_times,
-            cob_time,
-            MIN_ICE_TIME_FOR_ICED,
-            MIN_ICE_TIME_FOR_UNICED,
-        ),
+        walk(MAX_ICE_TIME_FOR_ICED, MAX_ICE_TIME_FOR_UNICED),
+        walk(MIN_ICE_TIME_FOR_ICED, MIN_ICE_TIME_FOR_UNICED),
     )
 }
 
+// 返回值单位为 0.5cs：原速每刻计2，减速每刻计1，冻结不计；以 i64 计算以免激活时机过大时溢出
 fn garg_walk_in_half_ticks(
-    valid_ice_times: &[i32],
-    cob_time: i32,
+    ice_and_cob_times: &IceAndCobTimes,
     ice_length_for_iced: i32,
     ice_length_for_uniced: i32,
-) -> i32 {
-    enum Tick {
-        Start(i32),
-        Ice { time: i32, length: i32 },
-        Cob(i32),
-    }
-
-    impl Tick {
-        // 返回值单位为 0.5cs
-        fn diff_in_half_ticks(old_tick: &Tick, new_tick: &Tick) -> i32 {
-            let prorated_walk = |walk: i32, ice_length| {
-                let uniced_walk = cmp::max(walk - (ICE_SLOW_TOTAL_TIME - ice_length), 0);
-                (walk - uniced_walk) + uniced_walk * 2
-            };
-            match (new_tick, old_tick) {
-                (Tick::Start(_), _) => panic!("Tick::Start can only be minuend."),
-                (Tick::Ice { time: _, length: _ }, Tick::Cob(_)) => {
-                    panic!("Tick::Cob must be later than Tick::Ice.")
-                }
-                (Tick::Cob(_), Tick::Cob(_)) => panic!("Tick::Cob must be unique."),
-                (
-                    Tick::Ice {
-                        time: new_time,
-                        length: _,
-                    },
-                    Tick::Start(old_time),
-                ) => cmp::max((new_time - old_time - 1) * 2, 0),
-                (
-                    Tick::Ice {
-                        time: new_time,
-                        length: _,
-                    },
-                    Tick::Ice {
-                        time: old_time,
-                        length,
-                    },
-                ) => prorated_walk(cmp::max(new_time - old_time - (length - 1), 0), length),
-                (Tick::Cob(new_time), Tick::Start(old_time)) => {
-                    cmp::max((new_time - old_time) * 2, 0)
-                }
-                (
-                    Tick::Cob(new_time),
-                    Tick::Ice {
-                        time: old_time,
-                        length,
-                    },
-                ) => prorated_walk(cmp::max(new_time - old_time - (length - 2), 0), length),
+) -> i64 {
+    // [from, to) 内巨人在 frozen_end 之前冻结，chill_end - 1 之前减速行走
+    let walk = |from: i64, to: i64, frozen_end: i64, chill_end: i64| {
+        let clamp = |time: i64| time.clamp(from, cmp::max(from, to));
+        let frozen = clamp(frozen_end) - from;
+        let slowed = cmp::max(clamp(chill_end - 1) - clamp(frozen_end), 0);
+        let walked = cmp::max(to - from, 0) - frozen;
+        walked * 2 - slowed
+    };
+
+    let mut time = 0;
+    let mut frozen_end = 0;
+    let mut chill_end = -1; // 尚未减速
+    let mut half_ticks = 0;
+    for (event_time, kind) in ice_and_cob_times.ice_events() {
+        let event_time = event_time as i64;
+        half_ticks += walk(time, event_time, frozen_end, chill_end);
+        time = event_time;
+        match kind {
+            // 已处于减速状态时冻结时间较短
+            IceKind::Ice => {
+                let ice_length = if event_time <= chill_end {
+                    ice_length_for_iced
+                } else {
+                    ice_length_for_uniced
+                };
+                frozen_end = event_time + ice_length as i64 - 1;
+                chill_end = cmp::max(chill_end, event_time + ICE_SLOW_TOTAL_TIME as i64);
             }
+            IceKind::Slow => chill_end = cmp::max(chill_end, event_time + SLOW_TIME as i64),
         }
     }
-
-    let mut ticks: Vec<Tick> = vec![Tick::Start(0)];
-    let mut prev_ice_time = None;
-    for &ice_time in valid_ice_times {
-        let iced: bool = match prev_ice_time {
-            None => false,
-            Some(prev_ice_time) => ice_time - prev_ice_time < ICE_SLOW_TOTAL_TIME,
-        };
-        ticks.push(Tick::Ice {
-            time: ice_time + 1,
-            length: if iced {
-                ice_length_for_iced
-            } else {
-                ice_length_for_uniced
-            },
-        });
-        prev_ice_time = Some(ice_time + 1);
-    }
-    ticks.push(Tick::Cob(cob_time));
-    ticks
-        .windows(2)
-        .map(|pair| Tick::diff_in_half_ticks(&pair[0], &pair[1]))
-        .sum::<i32>()
+    half_ticks + walk(time, ice_and_cob_times.garg_time(), frozen_end, chill_end)
 }
 
+/// 若炮列数*80不是整数，返回最接近的整数像素对应的列数
 pub fn hit_col_matching_int_pixel(unvalidated_hit_col: f32) -> Option<f32> {
     let pixel = unvalidated_hit_col * 80.;
     let diff_to_int_pixel = f32::min(pixel - pixel.floor(), pixel.ceil() - pixel);
@@ -932,6 +1947,7 @@ pub fn hit_col_matching_int_pixel(unvalidated_hit_col: f32) -> Option<f32> {
     }
 }
 
+/// 可拦区间中会造成伤害（小鬼已开始啃食）的部分
 pub fn unsafe_intercept_interval(eat: &Eat, intercept: &Intercept) -> Option<(i32, i32)> {
     match (&eat, &intercept) {
         (Eat::Some { eat, iceable: _ }, Intercept::Success { min, max }) if eat <= max => {
@@ -941,6 +1957,12 @@ pub fn unsafe_intercept_interval(eat: &Eat, intercept: &Intercept) -> Option<(i3
     }
 }
 
+/// 区间是否包含 `time`；`max` 为 [`MAX_INTERCEPTION_DELAY`] 时不设上限
+pub fn interval_contains((min, max): (i32, i32), time: i32) -> bool {
+    time >= min && (max == MAX_INTERCEPTION_DELAY || time <= max)
+}
+
+/// 可拦区间中不会造成伤害的部分
 pub fn safe_intercept_interval(eat: &Eat, intercept: &Intercept) -> Option<(i32, i32)> {
     match (&eat, &intercept) {
         (Eat::Some { eat, iceable: _ }, Intercept::Success { min, max }) if eat > min => {
@@ -950,71 +1972,6 @@ pub fn safe_intercept_interval(eat: &Eat, intercept: &Intercept) -> Option<(i32,
     }
 }
 
-// fn get_imp_x(garg_pos: &Vec2, garg_row: i32, rnd: i32, iced: bool, roof: bool) -> f32 {
-//     if garg_pos.x < GARG_THROW_IMP_THRES {
-//         return 0.;
-//     }
-//     let mut imp_velocity_y = garg_pos.x - 360. - (if roof { 180. } else { 0. });
-//     if imp_velocity_y >= 40. {
-//         if imp_velocity_y > 140. {
-//             imp_velocity_y -= rnd as f32;
-//         } else if rnd != 0 {
-//             return 0.;
-//         }
-//     } else {
-//         imp_velocity_y = 40.;
-//     }
-//     let imp_spawn_time = if iced { 210 } else { 105 };
-//     let shifted_y_of_y = |x: f32, roof: bool| {
-//         if !roof || x >= 400. {
-//             0.
-//         } else {
-//             (400. - x) / 4.
-//         }
-//     };
-//     let mut imp = Imp {
-//         state: ImpState::S71,
-//         position: Position {
-//             x: garg_pos.x - 133.,
-//             y: garg_pos.y,
-//             h: 88.,
-//             y_shift: shifted_y_of_y(garg_pos.x - 133., roof),
-//             row: garg_row,
-//         },
-//         velocity: Vec2 {
-//             x: -3.,
-//             y: imp_velocity_y / 3. * 0.5 * 0.05000000074505806,
-//         },
-//         exist_time: 0,
-//     };
-//     for _ in (imp_spawn_time + 1).. {
-//         imp.exist_time += 1;
-//         match imp.state {
-//             ImpState::S71 => {
-//                 imp.velocity = imp.velocity + GRAVITY;
-//                 imp.position.x += imp.velocity.x;
-//                 imp.position.h += imp.velocity.y;
-//                 imp.position.y_shift = shifted_y_of_y(imp.position.x, roof);
-//                 if imp.position.y_shift + imp.position.h < 0. {
-//                     return imp.position.x;
-//                 }
-//             }
-//             ImpState::S72 { countdown } => {
-//                 imp.state = ImpState::S72 {
-//                     countdown: (countdown - 1),
-//                 };
-//                 if countdown - 1 == 0 {
-//                     imp.state = ImpState::S0;
-//                 }
-//             }
-//             ImpState::S0 => {
-//                 break;
-//             }
-//         }
-//     }
-//     0.
-// }
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1074,7 +2031,7 @@ mod tests {
                 min: 800.,
                 max: 800.,
             },
-            &vec![(explode.clone(), &vec![1])],
+            &[(explode.clone(), &vec![1])],
             false,
             &scene,
         );
@@ -1092,7 +2049,7 @@ mod tests {
                 min: 555.,
                 max: 666.,
             },
-            &vec![(explode.clone(), &vec![1, 2])],
+            &[(explode.clone(), &vec![1, 2])],
             true,
             &scene,
         );
@@ -1110,7 +2067,7 @@ mod tests {
     fn test_roof_judge() {
         let scene = Scene::RE;
         let explode = Explode::of_cob(
-            &&Cob::Roof {
+            &Cob::Roof {
                 row: 1,
                 col: 8.5,
                 cob_col: 3,
@@ -1143,7 +2100,7 @@ mod tests {
                 min: 800.,
                 max: 800.,
             },
-            &vec![(explode.clone(), &vec![1])],
+            &[(explode.clone(), &vec![1])],
             false,
             &scene,
         );
@@ -1161,7 +2118,7 @@ mod tests {
                 min: 555.,
                 max: 666.,
             },
-            &vec![(explode.clone(), &vec![1, 2])],
+            &[(explode.clone(), &vec![1, 2])],
             true,
             &scene,
         );
@@ -1175,76 +2132,399 @@ mod tests {
         assert_eq!(intercept, Intercept::Fail);
     }
 
+    #[test]
+    fn test_min_max_garg_x_beyond_walk_table() {
+        // 原速行走超出 GARG_DATA_SIZE，此前会报错
+        let (min, max) =
+            min_max_garg_x(&IceAndCobTimes::of_ice_times_and_cob_time(&[], 5000).unwrap());
+        assert!(min < max, "{min} {max}");
+        let (shorter_min, shorter_max) =
+            min_max_garg_x(&IceAndCobTimes::of_ice_times_and_cob_time(&[], 4000).unwrap());
+        assert!(min < shorter_min && max < shorter_max);
+
+        // 巨人进家后的激活时机均有误，过大时也不会溢出
+        for (ice_times, cob_time, activation) in [
+            (vec![1, 400], 99999, Activation::Cob),
+            (vec![], 1_500_000_000, Activation::Cob),
+            (vec![i32::MAX - 1], i32::MAX, Activation::Plant),
+        ] {
+            assert!(IceAndCobTimes::of_times(&ice_times, &[], cob_time, activation).is_err());
+        }
+    }
+
     #[test]
     fn test_min_max_walk() {
-        let (min, max) = min_max_garg_walk_in_half_ticks(&(Vec::new()), 10);
+        let min_max_garg_walk_in_half_ticks = |ice_times: &[i32], cob_time| {
+            min_max_garg_walk_in_half_ticks(
+                &IceAndCobTimes::of_ice_times_and_cob_time(ice_times, cob_time).unwrap(),
+            )
+        };
+        let (min, max) = min_max_garg_walk_in_half_ticks(&[], 10);
         assert_eq!((min, max), (20, 20));
-        let (min, max) = min_max_garg_walk_in_half_ticks(&(vec![0]), 0);
+        let (min, max) = min_max_garg_walk_in_half_ticks(&[0], 0);
         assert_eq!((min, max), (0, 0));
-        let (min, max) = min_max_garg_walk_in_half_ticks(&(vec![0]), 400);
+        let (min, max) = min_max_garg_walk_in_half_ticks(&[0], 400);
         assert_eq!((min, max), (0, 1));
-        let (min, max) = min_max_garg_walk_in_half_ticks(&(vec![0]), 500);
+        let (min, max) = min_max_garg_walk_in_half_ticks(&[0], 500);
         assert_eq!((min, max), (0, 101));
-        let (min, max) = min_max_garg_walk_in_half_ticks(&(vec![0]), 600);
+        let (min, max) = min_max_garg_walk_in_half_ticks(&[0], 600);
         assert_eq!((min, max), (1, 201));
-        let (min, max) = min_max_garg_walk_in_half_ticks(&(vec![0]), 1999);
+        let (min, max) = min_max_garg_walk_in_half_ticks(&[0], 1999);
         assert_eq!((min, max), (1400, 1600));
-        let (min, max) = min_max_garg_walk_in_half_ticks(&(vec![0]), 2000);
+        let (min, max) = min_max_garg_walk_in_half_ticks(&[0], 2000);
         assert_eq!((min, max), (1402, 1602));
-        let (min, max) = min_max_garg_walk_in_half_ticks(&(vec![0, 1]), 301);
+        let (min, max) = min_max_garg_walk_in_half_ticks(&[0, 1], 301);
         assert_eq!((min, max), (0, 1));
-        let (min, max) = min_max_garg_walk_in_half_ticks(&(vec![0, 1]), 501);
+        let (min, max) = min_max_garg_walk_in_half_ticks(&[0, 1], 501);
         assert_eq!((min, max), (101, 201));
-        let (min, max) = min_max_garg_walk_in_half_ticks(&(vec![0, 499]), 1000);
+        let (min, max) = min_max_garg_walk_in_half_ticks(&[0, 499], 1000);
         assert_eq!((min, max), (102, 302));
-        let (min, max) = min_max_garg_walk_in_half_ticks(&(vec![0, 2499]), 3000);
+        let (min, max) = min_max_garg_walk_in_half_ticks(&[0, 2499], 3000);
         assert_eq!((min, max), (2400, 2702));
+
+        // 冰瓜只减速不冻结，且之后的冰冻时间较短
+        let walk_with_slow = |ice_times: &[i32], slow_times: &[i32], cob_time| {
+            super::min_max_garg_walk_in_half_ticks(
+                &IceAndCobTimes::of_times(ice_times, slow_times, cob_time, Activation::Cob)
+                    .unwrap(),
+            )
+        };
+        assert_eq!(walk_with_slow(&[], &[0], 1000), (1001, 1001));
+        assert_eq!(walk_with_slow(&[], &[0, 500], 1000), (1000, 1000));
+        assert_eq!(walk_with_slow(&[500], &[], 1000), (1000, 1101));
+        assert_eq!(walk_with_slow(&[500], &[0], 1000), (601, 701));
+        // 冰冻期间的减速不延长冻结，减速取较晚结束者
+        assert_eq!(
+            walk_with_slow(&[0], &[100], 2000),
+            min_max_garg_walk_in_half_ticks(&[0], 2000)
+        );
     }
 
-    // #[test]
-    // fn get_imp_x_table() {
-    //     let x = get_imp_x(
-    //         &Vec2 {
-    //             x: 766.999,
-    //             y: 135.,
-    //         },
-    //         2,
-    //         0,
-    //         false,
-    //         false,
-    //     );
-    //     println!("{}", x);
-    //     assert_eq!(x as i32, 162);
-    //     let mut res: Vec<Vec<f32>> = vec![vec![0.; 2]; 300];
-    //     for garg_x_times_thousand in 400_000..=854_000 {
-    //         let garg_x = garg_x_times_thousand as f32 / 1000.;
-    //         let mut min_imp_x = 999;
-    //         let mut max_imp_x = 0;
-    //         for rnd in [0, 100] {
-    //             let x = get_imp_x(&Vec2 { x: garg_x, y: 135. }, 2, rnd, false, false);
-    //             if x < 1. {
-    //                 continue;
-    //             }
-    //             let int_x = x.trunc() as usize;
-    //             if int_x < min_imp_x {
-    //                 min_imp_x = int_x;
-    //             }
-    //             if int_x > max_imp_x {
-    //                 max_imp_x = int_x;
-    //             }
-    //         }
-    //         for possible_imp_x in min_imp_x..=max_imp_x {
-    //             if res[possible_imp_x][0] < 1. || garg_x < res[possible_imp_x][0] {
-    //                 res[possible_imp_x][0] = garg_x;
-    //             }
-    //             if garg_x > res[possible_imp_x][1] {
-    //                 res[possible_imp_x][1] = garg_x;
-    //             }
-    //         }
-    //     }
-    //     for i in 0..300 {
-    //         println!("{}: ({:.3},{:.3}),", i, res[i][0], res[i][1]);
-    //     }
-    //     assert!(false);
-    // }
+    #[test]
+    fn test_judge_timed() {
+        let scene = Scene::PE;
+        let garg_x_range = GargXRange::of_min_max_garg_pos((700., 800.));
+        let explode = Explode::of_cob(&Cob::ground(1, 8.8), &scene);
+        let (row1, row2) = (vec![1], vec![2]);
+
+        let trajectories = judge_timed(
+            &garg_x_range,
+            &[(explode.clone(), 110, &row1)],
+            false,
+            &scene,
+        );
+        assert_eq!(trajectories.len(), 4);
+        assert!(trajectories.iter().all(|t| t.killer == Some((0, 110))));
+        assert!(trajectories.iter().all(Trajectory::is_safe));
+
+        // 先生效的炮视为拦截者；不在可拦区间内的炮不算
+        let trajectories = judge_timed(
+            &garg_x_range,
+            &[
+                (explode.clone(), 115, &row1),
+                (explode.clone(), 110, &row1),
+                (explode.clone(), 130, &row2),
+            ],
+            false,
+            &scene,
+        );
+        assert_eq!(trajectories.len(), 8);
+        for trajectory in &trajectories {
+            match (trajectory.garg_row, trajectory.garg_x as i32) {
+                (1, _) => assert_eq!(trajectory.killer, Some((1, 110))),
+                (2, 700) => assert_eq!(trajectory.killer, None),
+                (2, _) => assert_eq!(trajectory.killer, Some((2, 130))),
+                _ => unreachable!(),
+            }
+        }
+
+        // 啃食后才生效视为未能无伤拦截
+        let trajectory = judge_timed(
+            &GargXRange::of_min_max_garg_pos((800., 800.)),
+            &[(Explode::of_cob(&Cob::ground(1, 4.), &scene), 400, &row1)],
+            false,
+            &scene,
+        )
+        .remove(0);
+        assert_eq!(trajectory.killer, Some((0, 400)));
+        assert!(!trajectory.is_safe());
+
+        // 可拦区间不设上限时，晚于 MAX_INTERCEPTION_DELAY 生效依然可以拦截
+        let garg_x_range = GargXRange::of_min_max_garg_pos((700., 700.));
+        let explode = Explode::of_cob(&Cob::ground(1, 2.), &scene);
+        for time in [MAX_INTERCEPTION_DELAY, MAX_INTERCEPTION_DELAY + 1] {
+            let trajectory = judge_timed(
+                &garg_x_range,
+                &[(explode.clone(), time, &row1)],
+                true,
+                &scene,
+            )
+            .remove(0);
+            assert_eq!(trajectory.rnd, 0);
+            assert_eq!(trajectory.killer, Some((0, time)));
+        }
+    }
+
+    #[test]
+    fn test_interval_contains() {
+        assert!(interval_contains((200, 300), 300));
+        assert!(!interval_contains((200, 300), 301));
+        assert!(!interval_contains((200, 300), 199));
+        assert!(interval_contains(
+            (200, MAX_INTERCEPTION_DELAY),
+            MAX_INTERCEPTION_DELAY + 1
+        ));
+        assert!(!interval_contains((200, MAX_INTERCEPTION_DELAY), 199));
+    }
+
+    #[test]
+    fn test_trace() {
+        let scene = Scene::PE;
+        let explode = Explode::of_cob(&Cob::ground(1, 8.8), &scene);
+        let garg_pos = Vec2 { x: 750., y: 50. };
+        let ticks = trace(garg_pos.x, 1, 100, false, &scene, Some(&explode));
+        assert_eq!(ticks[0].state, ImpState::S71);
+        assert_eq!(ticks.last().unwrap().state, ImpState::S0);
+
+        // 除首个时刻外，能炸到的时刻即为可拦区间
+        let (eat, intercept) = judge_internal(&garg_pos, 1, 100, false, &scene, &explode);
+        let hit_ticks = ticks[1..]
+            .iter()
+            .filter(|tick| tick.hit == Some(true))
+            .map(|tick| tick.tick)
+            .collect::<Vec<i32>>();
+        assert_eq!(
+            intercept,
+            Intercept::Success {
+                min: hit_ticks[0],
+                max: *hit_ticks.last().unwrap()
+            }
+        );
+        assert!(hit_ticks.windows(2).all(|w| w[1] == w[0] + 1));
+        let Eat::Some { eat, iceable } = eat else {
+            unreachable!()
+        };
+        assert_eq!(ticks.last().unwrap().tick, eat.max(iceable));
+
+        assert!(trace(garg_pos.x, 1, 100, false, &scene, None)
+            .iter()
+            .all(|tick| tick.hit.is_none()));
+        assert!(trace(300., 1, 0, false, &scene, None).is_empty());
+    }
+
+    #[test]
+    fn test_trace_extremes() {
+        let scene = Scene::PE;
+        let garg_x_range = GargXRange::of_min_max_garg_pos((700., 800.));
+        let explode = Explode::of_cob(&Cob::ground(1, 8.8), &scene);
+        let traces = trace_extremes(
+            &garg_x_range,
+            &[(explode.clone(), vec![1, 2])],
+            false,
+            &scene,
+        );
+        // 巨人x极值、所在行及 rnd 极值的所有组合
+        assert_eq!(traces.len(), 8);
+        let (_, intercept) = judge(&garg_x_range, &[(explode, &vec![1, 2])], false, &scene);
+        assert!(intercept.interval().is_some());
+        // 最早拦截时机为各轨迹最早能炸到时刻的最大值
+        let first_hit_ticks = traces.iter().map(|trace| {
+            trace.ticks[1..]
+                .iter()
+                .find(|tick| tick.hit == Some(true))
+                .map(|tick| tick.tick)
+        });
+        assert_eq!(
+            intercept.interval().map(|(min, _)| min),
+            first_hit_ticks
+                .collect::<Option<Vec<i32>>>()
+                .and_then(|ticks| ticks.into_iter().max())
+        );
+        assert!(trace_extremes(
+            &GargXRange::of_min_max_garg_pos((300., 350.)),
+            &[(Explode::of_cob(&Cob::ground(1, 5.), &scene), vec![1])],
+            false,
+            &scene,
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_imp_landing() {
+        for scene in [Scene::PE, Scene::RE] {
+            for rnd in [0, 100] {
+                let landing = imp_landing(750., rnd, false, &scene).unwrap();
+                let landed = trace(750., 1, rnd, false, &scene, None)
+                    .into_iter()
+                    .find(|tick| tick.state != ImpState::S71)
+                    .unwrap();
+                assert_eq!((landing.x, landing.tick), (landed.x, landed.tick));
+            }
+        }
+        assert_eq!(imp_landing(300., 0, false, &Scene::PE), None);
+        assert_eq!(imp_landing(450., 50, false, &Scene::PE), None);
+
+        // 落地x坐标的最小值不在巨人x及 rnd 的端点处取得
+        let range = imp_landing_range(
+            &GargXRange::of_min_max_garg_pos((700., 800.)),
+            false,
+            &Scene::PE,
+        )
+        .unwrap();
+        assert_eq!(range.min_max_tick, (216, 272));
+        assert_eq!(range.min_max_x.1, 253.);
+        for rnd in [0, 100] {
+            for garg_x in [700., 800.] {
+                let x = imp_landing(garg_x, rnd, false, &Scene::PE).unwrap().x;
+                assert!(range.min_max_x.0 < x && x <= range.min_max_x.1);
+            }
+        }
+        let iced_range = imp_landing_range(
+            &GargXRange::of_min_max_garg_pos((700., 800.)),
+            true,
+            &Scene::PE,
+        )
+        .unwrap();
+        assert_eq!(iced_range.min_max_x, range.min_max_x);
+        assert_eq!(iced_range.min_max_tick, (216 + 105, 272 + 105));
+        assert_eq!(
+            imp_landing_range(&GargXRange::Cancelled, false, &Scene::PE),
+            None
+        );
+    }
+
+    #[test]
+    fn test_killer() {
+        let scene = Scene::PE;
+        let garg_pos = Vec2 {
+            x: 800.,
+            y: scene.zombie_base_y() as f32,
+        };
+        let judge = |plant: KillerPlant, rnd: i32| {
+            judge_killer_internal(&garg_pos, 1, rnd, false, &scene, &Killer::of_plant(&plant))
+        };
+        let chomper = |col: i32| KillerPlant::Chomper { row: 1, col };
+        let squash = |col: i32| KillerPlant::Squash { row: 1, col };
+        // 小鬼落地于 x=166 (rnd=0) 或 x=253 (rnd=100)
+        let (eat, kill) = judge(chomper(2), 0);
+        assert_eq!(eat.eat(), Some(297));
+        assert_eq!(kill, Kill::Success { min: 330, max: 330 });
+        assert_eq!(judge(chomper(2), 100).1, Kill::Fail);
+        assert_eq!(
+            judge(chomper(3), 100).1,
+            Kill::Success { min: 303, max: 303 }
+        );
+        assert_eq!(judge(chomper(3), 0).1, Kill::Fail);
+        // 窝瓜范围更大，在小鬼飞行时即可发现
+        assert_eq!(judge(squash(5), 0).1, Kill::Success { min: 360, max: 360 });
+        assert_eq!(judge(squash(1), 100).1, Kill::Fail);
+
+        let (eat, kill) = judge_killer(
+            &GargXRange::of_min_max_garg_pos((800., 800.)),
+            &[1],
+            false,
+            &scene,
+            &Killer::of_plant(&squash(4)),
+        );
+        assert_eq!(kill, Kill::Success { min: 386, max: 386 });
+        assert!(!kill.before_eat(&eat));
+        assert!(Kill::Success { min: 100, max: 268 }.before_eat(&eat));
+    }
+
+    #[test]
+    fn test_eat_target() {
+        let scene = Scene::PE;
+        // x=800 的巨人以 rnd=0 投掷的小鬼落地于 x=166
+        let target = |plant_cols: &[i32]| eat_target(800., 1, 0, false, &scene, plant_cols);
+        let eat = judge(
+            &GargXRange::of_min_max_garg_pos((800., 800.)),
+            &[(Explode::of_cob(&Cob::ground(1, 9.), &scene), &vec![1])],
+            false,
+            &scene,
+        )
+        .0;
+        // 落地处有植物时与最早啃食相同
+        assert_eq!(eat.eat(), Some(269));
+        assert_eq!(target(&[3]), Some(EatTarget::Plant { col: 3, eat: 297 }));
+        assert_eq!(
+            eat_target(800., 1, 100, false, &scene, &[4]),
+            Some(EatTarget::Plant { col: 4, eat: 269 })
+        );
+        // 向左行走至2列植物，身后的4列植物不会被啃食
+        assert_eq!(target(&[2]), Some(EatTarget::Plant { col: 2, eat: 349 }));
+        assert_eq!(target(&[1, 2, 4]), target(&[2]));
+        assert_eq!(target(&[4]), Some(EatTarget::Pass));
+        assert_eq!(eat_target(300., 1, 0, false, &scene, &[4]), None);
+
+        let lawn = Lawn::new([(1, 1), (1, 3), (1, 4)]);
+        assert_eq!(
+            eat_targets(
+                &GargXRange::of_min_max_garg_pos((700., 800.)),
+                &[1, 2],
+                false,
+                &scene,
+                &lawn
+            ),
+            vec![
+                RowEatTargets {
+                    garg_row: 1,
+                    targets: vec![(4, 269), (3, 253), (1, 397)],
+                    passes: false
+                },
+                RowEatTargets {
+                    garg_row: 2,
+                    targets: vec![],
+                    passes: true
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_roof_cob_row() {
+        let scene = Scene::RE;
+        let center_y = |col: f32, cob_col: i32, cob_row: i32| {
+            Explode::of_cob(&Cob::roof(1, col, cob_col, cob_row), &scene)
+                .center()
+                .unwrap()
+                .y
+        };
+        // 落点恰为炮尾左侧边界 (80 * 炮尾列 - 13) 时，爆心y与炮尾所在行有关
+        let left_edge_col = |cob_col: i32| (80 * cob_col - 13) as f32 / 80.;
+        let y = center_y(left_edge_col(4), 4, 1);
+        assert_eq!(center_y(left_edge_col(4), 4, 2), y);
+        for cob_row in 3..=5 {
+            assert_eq!(center_y(left_edge_col(4), 4, cob_row), y + 5);
+        }
+        let y = center_y(left_edge_col(6), 6, 1);
+        assert_eq!(center_y(left_edge_col(6), 6, 3), y);
+        assert_eq!(center_y(left_edge_col(6), 6, 4), y + 5);
+        // 1列炮及其他落点不受影响
+        assert_eq!(center_y(87. / 80., 1, 1), center_y(87. / 80., 1, 5));
+        assert_eq!(center_y(5., 4, 1), center_y(5., 4, 5));
+    }
+
+    #[test]
+    fn test_night_scenes() {
+        for (day, night) in [
+            (Scene::DE, Scene::NE),
+            (Scene::PE, Scene::FE),
+            (Scene::RE, Scene::ME),
+        ] {
+            assert_eq!(Scene::from_code(night.code()), Some(night));
+            assert_eq!(day.is_roof(), night.is_roof());
+            assert_eq!(day.all_rows(), night.all_rows());
+            assert_eq!(day.garg_rows_for_cob(2), night.garg_rows_for_cob(2));
+            assert_eq!(day.row_height(), night.row_height());
+            assert_eq!(day.zombie_base_y(), night.zombie_base_y());
+            assert_eq!(
+                day.cob_dist(Some(4)).hit_same,
+                night.cob_dist(Some(4)).hit_same
+            );
+            assert_eq!(
+                day.default_delay_mode(7., Some(6)),
+                night.default_delay_mode(7., Some(6))
+            );
+        }
+    }
 }
@@ -0,0 +1,686 @@
+use crate::command::{HitMode, OutputFormat};
+use crate::game;
+use crate::report::{
+    AshReport, DelayReport, EatReport, HitReport, IceSolveReport, ImpReport, InputError,
+    KillerReport, LandReport, LoadReport, MaxDelayReport, MultiReport, RelativeRow, Report,
+    ScanOutcome, ScanReport, SolveReport, SvgReport, TraceReport, Warning, WaveReport,
+};
+use game::MAX_INTERCEPTION_DELAY;
+use serde_json::{json, Map, Value};
+
+use crate::lang::*;
+
+/// 以一行 JSON 打印结果；`min_max_garg_x` 为当前设置的巨人x范围
+pub fn print_report(report: &Report, min_max_garg_x: (f32, f32)) {
+    println!("{}", report_to_json(report, min_max_garg_x));
+}
+
+pub fn print_input_error(error: &InputError) {
+    println!("{}", input_error_to_json(error));
+}
+
+/// 将结果转为 JSON，格式见 doc/json.md
+pub fn report_to_json(report: &Report, min_max_garg_x: (f32, f32)) -> Value {
+    let mut object = match report {
+        Report::Help => json!({ "command": "help", "text": &*HELP }),
+        Report::About => json!({ "command": "about", "text": &*ABOUT }),
+        Report::Scene(scene) => json!({ "command": "scene", "scene": scene.code() }),
+        Report::Format(format) => json!({
+            "command": "format",
+            "format": match format {
+                OutputFormat::Text => "text",
+                OutputFormat::Json => "json",
+            },
+        }),
+        Report::Lang(lang) => json!({ "command": "lang", "lang": lang.code() }),
+        Report::Save(name) => json!({ "command": "save", "name": name }),
+        Report::Load(report) => load_report_to_json(report),
+        Report::Wave(report) => wave_report_to_json(report),
+        Report::Delay(report) => delay_report_to_json(report, min_max_garg_x),
+        Report::Ash(report) => ash_report_to_json(report, min_max_garg_x),
+        Report::Killer(report) => killer_report_to_json(report, min_max_garg_x),
+        Report::Hit(report) => hit_report_to_json(report),
+        Report::MaxDelay(report) => max_delay_report_to_json(report, min_max_garg_x),
+        Report::Scan(report) => scan_report_to_json(report, min_max_garg_x),
+        Report::Solve(report) => solve_report_to_json(report),
+        Report::IceSolve(report) => ice_solve_report_to_json(report),
+        Report::Multi(report) => multi_report_to_json(report, min_max_garg_x),
+        Report::Trace(report) => trace_report_to_json(report),
+        Report::Imp(report) => imp_report_to_json(report),
+        Report::Land(report) => land_report_to_json(report, min_max_garg_x),
+        Report::Lawn(lawn) => json!({
+            "command": "lawn",
+            "cells": lawn.cells().map(|(row, col)| json!([row, col])).collect::<Vec<Value>>(),
+        }),
+        Report::Eat(report) => eat_report_to_json(report, min_max_garg_x),
+        Report::Svg(report) => svg_report_to_json(report, min_max_garg_x),
+    };
+    let fields = object.as_object_mut().unwrap();
+    fields.insert(
+        "warnings".to_string(),
+        report.warnings().iter().map(warning_to_json).collect(),
+    );
+    fields.insert("cancelled".to_string(), report.is_cancelled().into());
+    object
+}
+
+pub fn input_error_to_json(error: &InputError) -> Value {
+    let (kind, message, input) = match error {
+        InputError::Message(message) => ("input_error", message.as_str(), None),
+        InputError::WithInput { message, input } => {
+            ("input_error", message.as_str(), Some(input.as_str()))
+        }
+        InputError::TooManyArguments => {
+            ("too_many_arguments", &*INPUT_ERROR_TOO_MANY_ARGUMENTS, None)
+        }
+        InputError::BadFormat => ("bad_format", &*INPUT_ERROR_BAD_FORMAT, None),
+        InputError::UnknownCommand => ("unknown_command", &*UNKNOWN_COMMAND, None),
+    };
+    json!({ "error": { "kind": kind, "message": message, "input": input } })
+}
+
+// f32 直接转为 f64 会带上多余的小数位，改用最短的十进制表示
+fn float(value: f32) -> Value {
+    value
+        .to_string()
+        .parse::<f64>()
+        .map(Value::from)
+        .unwrap_or(Value::Null)
+}
+
+fn range(min_max: (f32, f32)) -> Value {
+    json!([float(min_max.0), float(min_max.1)])
+}
+
+fn warning_to_json(warning: &Warning) -> Value {
+    match warning {
+        Warning::GargXRangeCancelled => json!({ "kind": "garg_x_range_cancelled" }),
+        Warning::GargXRangeModified { min, max } => json!({
+            "kind": "garg_x_range_modified",
+            "garg_x_range": range((*min, *max)),
+        }),
+        Warning::HitColCorrected {
+            hit_col,
+            corrected_hit_col,
+        } => json!({
+            "kind": "hit_col_corrected",
+            "hit_col": float(*hit_col),
+            "corrected_hit_col": float(*corrected_hit_col),
+        }),
+        Warning::CannotHitAllGarg => json!({ "kind": "cannot_hit_all_garg" }),
+        Warning::GargWalkExtrapolated { max_walk } => json!({
+            "kind": "garg_walk_extrapolated",
+            "max_walk": float(*max_walk),
+        }),
+    }
+}
+
+fn ice_and_cob_times_to_json(
+    game::IceAndCobTimes {
+        ice_times,
+        slow_times,
+        cob_time,
+        activation,
+    }: &game::IceAndCobTimes,
+    min_max_garg_x: (f32, f32),
+) -> Value {
+    json!({
+        "ice_times": ice_times,
+        "slow_times": slow_times,
+        "cob_time": cob_time,
+        "activation": activation.code(),
+        "garg_x_range": range(min_max_garg_x),
+    })
+}
+
+fn cob_to_json(cob: &game::Cob) -> Value {
+    json!({
+        "row": cob.row(),
+        "col": float(cob.col()),
+        "cob_col": cob.cob_col(),
+        "cob_row": cob.cob_row(),
+    })
+}
+
+fn explode_center_to_json(explode_center: &Option<game::IntVec2>) -> Value {
+    match explode_center {
+        None => Value::Null,
+        Some(game::IntVec2 { x, y }) => json!({ "x": x, "y": y }),
+    }
+}
+
+fn interval_to_json((min, max): (i32, i32)) -> Value {
+    json!({
+        "min": min,
+        "max": if max == MAX_INTERCEPTION_DELAY { None } else { Some(max) },
+    })
+}
+
+fn eat_and_intercept_to_json(eat: &game::Eat, intercept: &game::Intercept) -> Map<String, Value> {
+    let mut object = Map::new();
+    object.insert(
+        "interceptable".to_string(),
+        intercept
+            .interval()
+            .map(interval_to_json)
+            .unwrap_or(Value::Null),
+    );
+    object.insert(
+        "harmful".to_string(),
+        game::unsafe_intercept_interval(eat, intercept)
+            .map(interval_to_json)
+            .unwrap_or(Value::Null),
+    );
+    object.insert("earliest_eat".to_string(), eat.eat().into());
+    object.insert("earliest_iceable".to_string(), eat.iceable().into());
+    object
+}
+
+fn with_eat_and_intercept(mut value: Value, eat: &game::Eat, intercept: &game::Intercept) -> Value {
+    value
+        .as_object_mut()
+        .unwrap()
+        .extend(eat_and_intercept_to_json(eat, intercept));
+    value
+}
+
+fn wave_report_to_json(
+    WaveReport {
+        warnings: _,
+        ice_and_cob_times,
+        min_max_garg_x,
+    }: &WaveReport,
+) -> Value {
+    let mut value = ice_and_cob_times_to_json(ice_and_cob_times, *min_max_garg_x);
+    value
+        .as_object_mut()
+        .unwrap()
+        .insert("command".to_string(), "wave".into());
+    value
+}
+
+fn load_report_to_json(LoadReport { name, scene, wave }: &LoadReport) -> Value {
+    let mut value = wave_report_to_json(wave);
+    let fields = value.as_object_mut().unwrap();
+    fields.insert("command".to_string(), "load".into());
+    fields.insert("name".to_string(), name.as_str().into());
+    fields.insert("scene".to_string(), scene.code().into());
+    value
+}
+
+fn delay_report_to_json(
+    DelayReport {
+        warnings: _,
+        cob_and_garg_rows,
+        explode_center,
+        garg_x_range,
+        eat,
+        intercept,
+    }: &DelayReport,
+    min_max_garg_x: (f32, f32),
+) -> Value {
+    let cobs = cob_and_garg_rows
+        .iter()
+        .map(|(cob, garg_rows)| {
+            let mut value = cob_to_json(cob);
+            value
+                .as_object_mut()
+                .unwrap()
+                .insert("garg_rows".to_string(), json!(garg_rows));
+            value
+        })
+        .collect::<Vec<Value>>();
+    with_eat_and_intercept(
+        json!({
+            "command": "delay",
+            "cobs": cobs,
+            "explosion_center": explode_center_to_json(explode_center),
+            "garg_x_range": range(garg_x_range.unwrap_or(min_max_garg_x)),
+        }),
+        eat,
+        intercept,
+    )
+}
+
+fn ash_report_to_json(
+    AshReport {
+        warnings: _,
+        ash,
+        garg_rows,
+        explode_center,
+        garg_x_range,
+        eat,
+        intercept,
+    }: &AshReport,
+    min_max_garg_x: (f32, f32),
+) -> Value {
+    let mut value = json!({
+        "command": ash.code(),
+        "garg_rows": garg_rows,
+        "explosion_center": explode_center_to_json(explode_center),
+        "garg_x_range": range(garg_x_range.unwrap_or(min_max_garg_x)),
+    });
+    // 字段名与指令名相同，如 "doom": {"row", "col"}
+    value.as_object_mut().unwrap().insert(
+        ash.code().to_string(),
+        json!({ "row": ash.row(), "col": ash.col() }),
+    );
+    with_eat_and_intercept(value, eat, intercept)
+}
+
+fn killer_report_to_json(
+    KillerReport {
+        warnings: _,
+        plant,
+        garg_rows,
+        garg_x_range,
+        eat,
+        kill,
+    }: &KillerReport,
+    min_max_garg_x: (f32, f32),
+) -> Value {
+    let mut value = json!({
+        "command": plant.code(),
+        "garg_rows": garg_rows,
+        "garg_x_range": range(garg_x_range.unwrap_or(min_max_garg_x)),
+        "kill": kill.interval().map(interval_to_json).unwrap_or(Value::Null),
+        "before_eat": kill.before_eat(eat),
+        "earliest_eat": eat.eat(),
+        "earliest_iceable": eat.iceable(),
+    });
+    // 字段名与指令名相同，如 "squash": {"row", "col"}
+    value.as_object_mut().unwrap().insert(
+        plant.code().to_string(),
+        json!({ "row": plant.row(), "col": plant.col() }),
+    );
+    value
+}
+
+fn hit_report_to_json(
+    HitReport {
+        warnings: _,
+        mode,
+        scene,
+        cob_tail,
+        delayed,
+        hit_pixels,
+    }: &HitReport,
+) -> Value {
+    json!({
+        "command": match mode {
+            HitMode::Hit => "hit",
+            HitMode::Nohit => "nohit",
+        },
+        "scene": scene.code(),
+        "cob_tail": cob_tail.map(|(cob_row, cob_col)| json!({ "row": cob_row, "col": cob_col })),
+        "delayed": delayed
+            .as_ref()
+            .map(|(ice_and_cob_times, min_max_garg_x)| {
+                ice_and_cob_times_to_json(ice_and_cob_times, *min_max_garg_x)
+            }),
+        "hit_cols": hit_pixels
+            .iter()
+            .map(|(relative_row, pixel)| json!({
+                "garg_row": match relative_row {
+                    RelativeRow::Above => "above",
+                    RelativeRow::Same => "same",
+                    RelativeRow::Below => "below",
+                },
+                "pixel": pixel,
+                "col": float(*pixel as f32 / 80.),
+            }))
+            .collect::<Vec<Value>>(),
+    })
+}
+
+fn max_delay_report_to_json(
+    MaxDelayReport {
+        warnings: _,
+        cob,
+        garg_rows,
+        min_max_hit_col,
+        garg_x_range,
+        max_delay,
+    }: &MaxDelayReport,
+    min_max_garg_x: (f32, f32),
+) -> Value {
+    json!({
+        "command": "max",
+        "cob": cob_to_json(cob),
+        "garg_rows": garg_rows,
+        "hit_col_range": range(*min_max_hit_col),
+        "garg_x_range": range(garg_x_range.unwrap_or(min_max_garg_x)),
+        "max_delay": max_delay.as_ref().map(|max_delay| {
+            with_eat_and_intercept(
+                json!({
+                    "hit_cols": max_delay
+                        .hit_cols
+                        .iter()
+                        .map(|hit_col| float(*hit_col))
+                        .collect::<Vec<Value>>(),
+                }),
+                &max_delay.eat,
+                &max_delay.intercept,
+            )
+        }),
+    })
+}
+
+fn scan_report_to_json(
+    ScanReport {
+        warnings: _,
+        cob,
+        garg_rows,
+        min_max_hit_col,
+        garg_x_range,
+        min_max_delay,
+        csv: _,
+        rows,
+    }: &ScanReport,
+    min_max_garg_x: (f32, f32),
+) -> Value {
+    json!({
+        "command": "scan",
+        "cob": cob_to_json(cob),
+        "garg_rows": garg_rows,
+        "hit_col_range": range(*min_max_hit_col),
+        "garg_x_range": range(garg_x_range.unwrap_or(min_max_garg_x)),
+        "delay_range": min_max_delay.map(|(min, max)| [min, max]),
+        "rows": rows
+            .iter()
+            .map(|row| {
+                let mut value = with_eat_and_intercept(
+                    json!({
+                        "hit_col": float(row.hit_col),
+                        "harmless": game::safe_intercept_interval(&row.eat, &row.intercept)
+                            .map(interval_to_json)
+                            .unwrap_or(Value::Null),
+                    }),
+                    &row.eat,
+                    &row.intercept,
+                );
+                if let Some((min_delay, max_delay)) = min_max_delay {
+                    value.as_object_mut().unwrap().insert(
+                        "outcomes".to_string(),
+                        (*min_delay..=*max_delay)
+                            .map(|delay| match row.outcome(delay) {
+                                ScanOutcome::Harmless => "harmless",
+                                ScanOutcome::Harmful => "harmful",
+                                ScanOutcome::Miss => "miss",
+                            })
+                            .collect(),
+                    );
+                }
+                value
+            })
+            .collect::<Vec<Value>>(),
+    })
+}
+
+fn solve_report_to_json(
+    SolveReport {
+        warnings: _,
+        cob,
+        garg_rows,
+        min_max_cob_time: (min_cob_time, max_cob_time),
+        ranges,
+    }: &SolveReport,
+) -> Value {
+    json!({
+        "command": "solve",
+        "cob": cob_to_json(cob),
+        "garg_rows": garg_rows,
+        "cob_time_range": [min_cob_time, max_cob_time],
+        "ranges": ranges
+            .iter()
+            .map(|range| json!({
+                "cob_time_range": [range.min_max_cob_time.0, range.min_max_cob_time.1],
+                "max_delay": range.max_delay,
+                "max_delay_cob_time": range.max_delay_cob_time,
+            }))
+            .collect::<Vec<Value>>(),
+    })
+}
+
+fn ice_solve_report_to_json(report: &IceSolveReport) -> Value {
+    let ice_time_ranges = |min_max_ice_times: &[(i32, i32)]| {
+        min_max_ice_times
+            .iter()
+            .map(|(min, max)| json!([min, max]))
+            .collect::<Vec<Value>>()
+    };
+    json!({
+        "command": "icesolve",
+        "cob": cob_to_json(&report.cob),
+        "garg_rows": report.garg_rows,
+        "cob_time": report.cob_time,
+        "ice_time_ranges": ice_time_ranges(&report.min_max_ice_times),
+        "ranges": report
+            .ranges
+            .iter()
+            .map(|range| json!({
+                "ice_time_ranges": ice_time_ranges(&range.min_max_ice_times),
+                "max_delay": range.max_delay,
+                "max_delay_ice_times": range.max_delay_ice_times,
+            }))
+            .collect::<Vec<Value>>(),
+        "best": report.best().map(|best| json!({
+            "max_delay": best.max_delay,
+            "ice_times": best.max_delay_ice_times,
+        })),
+    })
+}
+
+fn multi_report_to_json(report: &MultiReport, min_max_garg_x: (f32, f32)) -> Value {
+    let cobs = report
+        .cobs
+        .iter()
+        .map(|timed_cob| {
+            let mut value = cob_to_json(&timed_cob.cob);
+            let fields = value.as_object_mut().unwrap();
+            fields.insert("time".to_string(), timed_cob.time.into());
+            fields.insert("garg_rows".to_string(), json!(timed_cob.garg_rows));
+            value
+        })
+        .collect::<Vec<Value>>();
+    let trajectories = report
+        .trajectories
+        .iter()
+        .map(|trajectory| {
+            json!({
+                "garg_x": float(trajectory.garg_x),
+                "garg_row": trajectory.garg_row,
+                "rnd": trajectory.rnd,
+                "eat": trajectory.eat,
+                "killer": trajectory
+                    .killer
+                    .map(|(index, time)| json!({ "index": index, "time": time })),
+                "safe": trajectory.is_safe(),
+            })
+        })
+        .collect::<Vec<Value>>();
+    json!({
+        "command": "multi",
+        "cobs": cobs,
+        "garg_x_range": range(report.garg_x_range.unwrap_or(min_max_garg_x)),
+        "trajectories": trajectories,
+        "all_intercepted": report.all_intercepted(),
+    })
+}
+
+fn trace_report_to_json(report: &TraceReport) -> Value {
+    let ticks = report
+        .ticks
+        .iter()
+        .map(|tick| {
+            let (state, countdown) = match tick.state {
+                game::ImpState::S71 => ("s71", None),
+                game::ImpState::S72 { countdown } => ("s72", Some(countdown)),
+                game::ImpState::S0 => ("s0", None),
+            };
+            json!({
+                "tick": tick.tick,
+                "state": state,
+                "countdown": countdown,
+                "x": float(tick.x),
+                "y": float(tick.y),
+                "h": float(tick.h),
+                "y_shift": float(tick.y_shift),
+                "defense": {
+                    "x": tick.defense.upper_left.x,
+                    "y": tick.defense.upper_left.y,
+                    "width": tick.defense.width,
+                    "height": tick.defense.height,
+                },
+                "hit": tick.hit,
+            })
+        })
+        .collect::<Vec<Value>>();
+    json!({
+        "command": "trace",
+        "cob": report.cob.as_ref().map(cob_to_json),
+        "explosion_center": explode_center_to_json(&report.explode_center),
+        "garg_x": float(report.garg_x),
+        "garg_row": report.garg_row,
+        "rnd": report.rnd,
+        "iced": report.iced,
+        "ticks": ticks,
+    })
+}
+
+fn imp_report_to_json(
+    ImpReport {
+        min_max_imp_x: (min_imp_x, max_imp_x),
+        scene,
+        min_max_garg_x,
+    }: &ImpReport,
+) -> Value {
+    json!({
+        "command": "imp",
+        "imp_x": (min_imp_x == max_imp_x).then_some(min_imp_x),
+        "imp_x_range": [min_imp_x, max_imp_x],
+        "scene": scene.code(),
+        "garg_x_range": range(*min_max_garg_x),
+    })
+}
+
+fn land_report_to_json(report: &LandReport, min_max_garg_x: (f32, f32)) -> Value {
+    json!({
+        "command": "land",
+        "garg_x_range": range(report.garg_x_range.unwrap_or(min_max_garg_x)),
+        "iced": report.iced,
+        "imp_x_range": report.landing.map(|landing| range(landing.min_max_x)),
+        "tick_range": report
+            .landing
+            .map(|landing| json!([landing.min_max_tick.0, landing.min_max_tick.1])),
+    })
+}
+
+fn eat_report_to_json(report: &EatReport, min_max_garg_x: (f32, f32)) -> Value {
+    let rows = report
+        .rows
+        .iter()
+        .map(|row| {
+            json!({
+                "garg_row": row.garg_row,
+                "targets": row
+                    .targets
+                    .iter()
+                    .map(|(col, eat)| json!({ "col": col, "eat": eat }))
+                    .collect::<Vec<Value>>(),
+                "passes": row.passes,
+            })
+        })
+        .collect::<Vec<Value>>();
+    json!({
+        "command": "eat",
+        "garg_x_range": range(report.garg_x_range.unwrap_or(min_max_garg_x)),
+        "iced": report.iced,
+        "rows": rows,
+    })
+}
+
+fn svg_report_to_json(report: &SvgReport, min_max_garg_x: (f32, f32)) -> Value {
+    let traces = report
+        .traces
+        .iter()
+        .map(|trace| {
+            json!({
+                "garg_x": float(trace.garg_x),
+                "garg_row": trace.garg_row,
+                "rnd": trace.rnd,
+                // 首个时刻不计入可拦区间
+                "hit_ticks": trace.ticks[1..]
+                    .iter()
+                    .filter(|tick| tick.hit == Some(true))
+                    .map(|tick| tick.tick)
+                    .collect::<Vec<i32>>(),
+            })
+        })
+        .collect::<Vec<Value>>();
+    json!({
+        "command": "svg",
+        "path": report.path,
+        "explosion_centers": report
+            .explodes
+            .iter()
+            .map(|explode| explode_center_to_json(&explode.center()))
+            .collect::<Vec<Value>>(),
+        "garg_x_range": range(report.garg_x_range.unwrap_or(min_max_garg_x)),
+        "iced": report.iced,
+        "traces": traces,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculator::Calculator;
+    use crate::command::AshCommand;
+
+    #[test]
+    fn test_eat_and_intercept_to_json() {
+        let object = eat_and_intercept_to_json(
+            &game::Eat::Some {
+                eat: 269,
+                iceable: 297,
+            },
+            &game::Intercept::Success {
+                min: 107,
+                max: MAX_INTERCEPTION_DELAY,
+            },
+        );
+        assert_eq!(object["interceptable"], json!({ "min": 107, "max": null }));
+        assert_eq!(object["harmful"], json!({ "min": 269, "max": null }));
+        assert_eq!(object["earliest_eat"], json!(269));
+        assert_eq!(object["earliest_iceable"], json!(297));
+
+        let object = eat_and_intercept_to_json(&game::Eat::Empty, &game::Intercept::Empty);
+        assert_eq!(object["interceptable"], Value::Null);
+        assert_eq!(object["earliest_eat"], Value::Null);
+    }
+
+    #[test]
+    fn test_jalapeno_interval_is_open_ended() {
+        let calculator = Calculator::default();
+        let ash = game::Ash::Jalapeno { row: 1 };
+        let judgement = calculator.judge_ash(&ash, &vec![1], None, None);
+        assert!(matches!(
+            judgement.intercept.interval(),
+            Some((_, MAX_INTERCEPTION_DELAY))
+        ));
+        let report = Report::Ash(calculator.run_ash(&AshCommand {
+            ash,
+            garg_pos: None,
+        }));
+        let value = report_to_json(&report, calculator.min_max_garg_x());
+        assert_eq!(value["interceptable"]["max"], Value::Null);
+        assert_eq!(value["harmful"]["max"], Value::Null);
+    }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(float(8.8), json!(8.8));
+        assert_eq!(float(8.8125), json!(8.8125));
+    }
+}
@@ -1,2 +1,349 @@
+//! 界面文字的多语言支持。
+//!
+//! [`en`] 与 [`zh`] 中的同名常量构成一条 [`Message`]，打印时按 [`current`] 语言取用；
+//! 任一语言缺少某条文字时无法通过编译。
+use std::fmt;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicU8, Ordering};
+
 pub mod en;
-pub mod zh;
\ No newline at end of file
+pub mod zh;
+
+/// 界面语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    En,
+    Zh,
+}
+
+impl Lang {
+    /// 按 `LANG` 环境变量选择语言；未设置时使用中文
+    pub fn from_env() -> Lang {
+        match std::env::var("LANG") {
+            Ok(lang) if !lang.is_empty() && !lang.starts_with("zh") => Lang::En,
+            _ => Lang::Zh,
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Lang> {
+        match code {
+            "en" => Some(Lang::En),
+            "zh" => Some(Lang::Zh),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::Zh => "zh",
+        }
+    }
+}
+
+static CURRENT: AtomicU8 = AtomicU8::new(Lang::Zh as u8);
+
+pub fn current() -> Lang {
+    if CURRENT.load(Ordering::Relaxed) == Lang::En as u8 {
+        Lang::En
+    } else {
+        Lang::Zh
+    }
+}
+
+pub fn set_current(lang: Lang) {
+    CURRENT.store(lang as u8, Ordering::Relaxed);
+}
+
+/// 一条界面文字，按当前语言解引用为 `str`
+#[derive(Debug)]
+pub struct Message {
+    en: &'static str,
+    zh: &'static str,
+}
+
+impl Message {
+    pub fn get(&self, lang: Lang) -> &'static str {
+        match lang {
+            Lang::En => self.en,
+            Lang::Zh => self.zh,
+        }
+    }
+}
+
+impl Deref for Message {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.get(current())
+    }
+}
+
+impl AsRef<str> for Message {
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+macro_rules! messages {
+    ($($name:ident),* $(,)?) => {
+        $(pub static $name: Message = Message {
+            en: en::$name,
+            zh: zh::$name,
+        };)*
+
+        #[cfg(test)]
+        static ALL_MESSAGES: &[(&str, &Message)] = &[$((stringify!($name), &$name)),*];
+    };
+}
+
+messages!(
+    GARG_X_RANGE_CANCELLED,
+    GARG_X_RANGE_MODIFIED,
+    SET_FRONTYARD,
+    SET_BACKYARD,
+    SET_ROOF,
+    SET_NIGHT,
+    SET_FOG,
+    SET_MOON,
+    SET_TEXT_FORMAT,
+    SET_JSON_FORMAT,
+    NEED_FORMAT,
+    FORMAT_SHOULD_BE_TEXT_OR_JSON,
+    SET_LANG,
+    NEED_LANG,
+    LANG_SHOULD_BE_EN_OR_ZH,
+    NEED_SETUP_NAME,
+    SETUP_SAVED,
+    SETUP_LOADED,
+    NO_CONFIG_DIR,
+    NEED_SVG_FILE,
+    NEED_EXPLOSION_BEFORE_SVG,
+    SVG_SAVED,
+    CANNOT_WRITE_SVG,
+    NEED_HIT_ROW_HIT_COL,
+    NEED_HIT_COL,
+    NEED_HIT_ROW_HIT_COL_COB_COL,
+    NEED_HIT_COL_COB_COL,
+    NEED_COB_COL,
+    NEED_DOOM_ROW_DOOM_COL,
+    NEED_DOOM_ROW,
+    NEED_PLANT_ROW_PLANT_COL,
+    NEED_PLANT_COL,
+    NEED_PLANT_ROW,
+    NEED_HIT_ROW_HIT_COL_RANGE,
+    NEED_HIT_COL_RANGE,
+    NEED_HIT_ROW_HIT_COL_RANGE_COB_COL,
+    NEED_HIT_COL_RANGE_COB_COL,
+    CANNOT_INTERCEPT_WITHOUT_HARM,
+    HIT_COL_WITH_MAX_DELAY,
+    SCAN_HEADER,
+    SCAN_LEGEND,
+    MIN_DELAY_SHOULD_BE_SMALLER_THAN_MAX_DELAY,
+    NEED_COB_TIME_RANGE_HIT_ROW_HIT_COL,
+    NEED_COB_TIME_RANGE_HIT_ROW_HIT_COL_COB_COL,
+    NEED_GARG_ROWS,
+    SOLVE_RANGE,
+    NEED_ICE_TIME_RANGE_HIT_ROW_HIT_COL,
+    NEED_ICE_TIME_RANGE_HIT_ROW_HIT_COL_COB_COL,
+    ICE_SOLVE_RANGE,
+    ICE_SOLVE_BEST,
+    NEED_IMP_X_RANGE,
+    IMP_X_SHOULD_BE_INTEGER,
+    IMP_X_SHOULD_BE_IN_RANGE,
+    NO_GARG_THROWS_IMP_X,
+    MIN_IMP_X_SHOULD_BE_SMALLER_THAN_MAX_IMP_X,
+    NEED_IMP_BEFORE_GARG_X_RANGE,
+    ICE_TIMES_SHOULD_BE_INTEGER,
+    COB_TIME_SHOULD_BE_INTEGER,
+    COB_TIME_SHOULD_BE_NON_NEGATIVE,
+    GARG_REACHED_HOUSE,
+    NEED_MIN_MAX_COB_TIME,
+    NEED_MAX_COB_TIME,
+    COB_TIME_RANGE_SHOULD_BE_INTEGER,
+    MIN_COB_TIME_SHOULD_BE_SMALLER_THAN_MAX_COB_TIME,
+    NEED_MIN_MAX_ICE_TIME,
+    NEED_MAX_ICE_TIME,
+    ICE_TIME_RANGE_SHOULD_BE_INTEGER,
+    ICE_TIME_SHOULD_BE_IN_RANGE,
+    MIN_ICE_TIME_SHOULD_BE_SMALLER_THAN_MAX_ICE_TIME,
+    DELAY_TIME_SHOULD_BE_INTEGER,
+    HIT_ROW_SHOULD_BE_INTEGER,
+    HIT_ROW_OUT_OF_RANGE,
+    HIT_COL_SHOULD_BE_NUMBER,
+    HIT_COL_SHOULD_BE_IN_RANGE,
+    HIT_COL_TIMES_EIGHTY_NOT_INTEGER,
+    NEED_MIN_MAX_HIT_COL,
+    NEED_MAX_HIT_COL,
+    MIN_COL_SHOULD_BE_SMALLER_THAN_MAX_COL,
+    COB_COL_SHOULD_BE_INTEGER,
+    COB_COL_SHOULD_BE_IN_RANGE,
+    COB_ROW_SHOULD_BE_INTEGER,
+    COB_ROW_SHOULD_BE_IN_RANGE,
+    DOOM_ROW_SHOULD_BE_INTEGER,
+    DOOM_ROW_OUT_OF_RANGE,
+    DOOM_COL_SHOULD_BE_INTEGER,
+    DOOM_COL_SHOULD_BE_IN_RANGE,
+    PLANT_ROW_SHOULD_BE_INTEGER,
+    PLANT_ROW_OUT_OF_RANGE,
+    PLANT_COL_SHOULD_BE_INTEGER,
+    PLANT_COL_SHOULD_BE_IN_RANGE,
+    LAWN_CELLS_SHOULD_BE_ROW_COLS,
+    NEED_GARG_ROWS_X_RANGE_ICE_FLAG,
+    GARG_ROWS_SHOULD_BE_INTEGER,
+    GARG_ROWS_ALL_OUT_OF_RANGE,
+    NEED_MIN_MAX_GARG_X,
+    NEED_MAX_GARG_X,
+    MIN_GARG_X_SHOULD_BE_NUMBER,
+    MAX_GARG_X_SHOULD_BE_NUMBER,
+    MIN_GARG_X_SHOULD_BE_SMALLER_THAN_MAX_GARG_X,
+    MIN_GARG_X_SHOULD_BE_LARGER_THAN_LOWER_BOUND,
+    MAX_GARG_X_SHOULD_BE_SMALLER_THAN_UPPER_BOUND,
+    ICE_FLAG_SHOULD_BE_U_OR_I,
+    DELAY_SETTING,
+    SETTING,
+    CANNOT_HIT_ALL_GARG,
+    GARG_WALK_EXTRAPOLATED,
+    NO_ICE,
+    ICE,
+    SLOW,
+    COB_EFFECTIVE,
+    COB_ACTIVATE,
+    PLANT_ACTIVATE,
+    PLANT_ACTIVATION,
+    GARG_X_RANGE,
+    NON_ROOF_SCENES,
+    ROOF_SCENES,
+    CALCULATION_SETTING,
+    COB_GARG_ROWS,
+    COB_COL_RANGE,
+    COB_TAIL,
+    EXPLOSION_CENTER,
+    GARG,
+    DOOM_GARG_ROWS,
+    CHERRY_GARG_ROWS,
+    JALAPENO_GARG_ROWS,
+    INTERCEPTABLE_INTERVAL,
+    CANNOT_INTERCEPT,
+    WILL_CAUSE_HARM,
+    EARLIEST_EAT,
+    DOES_NOT_EAT,
+    EARLIEST_ICEABLE,
+    NOT_ICEABLE,
+    SQUASH_GARG_ROWS,
+    CHOMPER_GARG_ROWS,
+    KILL_TIME,
+    CANNOT_KILL_ALL,
+    KILL_AFTER_EAT,
+    LAWN_EMPTY,
+    LAWN_ROW,
+    EAT_ROW,
+    EAT_TARGET,
+    PASSES_ALL_PLANTS,
+    COL,
+    HIT_SAME_AND_LOWER,
+    HIT_ALL_THREE_ROWS,
+    HIT_UPPER_ROW,
+    HIT_SAME_ROW,
+    HIT_LOWER_ROW,
+    NOT_HIT_SAME_AND_LOWER,
+    NOT_HIT_UPPER_ROW,
+    NOT_HIT_SAME_ROW,
+    NOT_HIT_LOWER_ROW,
+    WARNING,
+    INPUT_ERROR,
+    INPUT_ERROR_BAD_FORMAT,
+    INPUT_ERROR_GOT,
+    INPUT_ERROR_TOO_MANY_ARGUMENTS,
+    ABOUT,
+    HELLO,
+    HELP,
+    UNKNOWN_COMMAND,
+    ERROR,
+    NEED_SCRIPT_FILE,
+    CANNOT_OPEN_SCRIPT,
+    UNKNOWN_ARGUMENT,
+    SETUP_NOT_FOUND,
+    CANNOT_READ_STATE,
+    CANNOT_SAVE_STATE,
+    NEED_TIMED_COB,
+    EXPLOSION_TIME_SHOULD_BE_INTEGER,
+    GARG_X_RANGE_ONLY_AFTER_LAST_COB,
+    TIMED_COB_GARG_ROWS,
+    TRAJECTORY,
+    KILLED_BY,
+    NOT_INTERCEPTED,
+    ALL_INTERCEPTED,
+    NOT_ALL_INTERCEPTED,
+    NEED_GARG_ROW_X_RND,
+    GARG_ROW_SHOULD_BE_INTEGER,
+    GARG_ROW_OUT_OF_RANGE,
+    GARG_X_SHOULD_BE_NUMBER,
+    GARG_X_SHOULD_BE_IN_RANGE,
+    RND_SHOULD_BE_INTEGER,
+    RND_SHOULD_BE_IN_RANGE,
+    UNICED,
+    ICED,
+    TRACE_COB,
+    NO_IMP_THROWN,
+    TRACE_HEADER,
+    TRACE_HIT,
+    IMP_LANDING_X,
+    IMP_LANDING_TICK,
+    ARG_REQUIRED,
+    ARG_OPTIONAL,
+    EXPECTED,
+    ARG_ICE_TIMES,
+    ARG_COB_TIME,
+    ARG_HIT_ROW,
+    ARG_HIT_COL,
+    ARG_COB_COL,
+    ARG_GARG_ROWS,
+    ARG_GARG_X_RANGE,
+    ARG_DOOM_ROW,
+    ARG_DOOM_COL,
+    ARG_PLANT_ROW,
+    ARG_PLANT_COL,
+    ARG_DELAY,
+    ARG_HIT_COL_RANGE,
+    ARG_COB_TIME_RANGE,
+    ARG_DELAY_RANGE,
+    ARG_ICE_TIME_RANGE,
+    ARG_IMP_X,
+    ARG_SETUP_NAME,
+    ARG_SVG_FILE,
+    ARG_GARG_ROW,
+    ARG_GARG_X,
+    ARG_RND,
+    ARG_LAWN_CELLS,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translations_match() {
+        for (name, message) in ALL_MESSAGES {
+            assert!(!message.en.is_empty(), "{name}");
+            assert!(!message.zh.is_empty(), "{name}");
+            assert_eq!(
+                message.en.matches("{}").count(),
+                message.zh.matches("{}").count(),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(UNKNOWN_COMMAND.get(Lang::En), en::UNKNOWN_COMMAND);
+        assert_eq!(UNKNOWN_COMMAND.get(Lang::Zh), zh::UNKNOWN_COMMAND);
+    }
+}
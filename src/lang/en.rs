@@ -8,6 +8,33 @@ pub const GARG_X_RANGE_MODIFIED: &str =
 pub const SET_FRONTYARD: &str = "Scene has been set to Frontyard.";
 pub const SET_BACKYARD: &str = "Scene has been set to Backyard.";
 pub const SET_ROOF: &str = "Scene has been set to Roof.";
+pub const SET_NIGHT: &str = "Scene has been set to Night.";
+pub const SET_FOG: &str = "Scene has been set to Fog.";
+pub const SET_MOON: &str = "Scene has been set to Moon.";
+
+// parse_format
+pub const SET_TEXT_FORMAT: &str = "Output format has been set to text.";
+pub const SET_JSON_FORMAT: &str = "Output format has been set to JSON.";
+pub const NEED_FORMAT: &str = "Please provide output format (text/json).";
+pub const FORMAT_SHOULD_BE_TEXT_OR_JSON: &str = "output format should be text or json";
+
+// parse_lang
+pub const SET_LANG: &str = "Language has been set to English.";
+pub const NEED_LANG: &str = "Please provide language (en/zh).";
+pub const LANG_SHOULD_BE_EN_OR_ZH: &str = "language should be en or zh";
+
+// parse_save, parse_load
+pub const NEED_SETUP_NAME: &str = "Please provide setup name.";
+pub const SETUP_SAVED: &str = "Setup \"{}\" has been saved.";
+pub const SETUP_LOADED: &str = "Setup \"{}\" has been loaded.";
+pub const NO_CONFIG_DIR: &str = "Cannot find config directory to save setups.";
+
+// parse_svg
+pub const NEED_SVG_FILE: &str = "Please provide SVG file name.";
+pub const NEED_EXPLOSION_BEFORE_SVG: &str =
+    "Please run delay, doom, cherry or jalapeno before exporting SVG.";
+pub const SVG_SAVED: &str = "Picture has been saved to \"{}\".";
+pub const CANNOT_WRITE_SVG: &str = "Cannot write SVG file";
 
 // parse_delay
 pub const NEED_HIT_ROW_HIT_COL: &str = "Please provide cob hit row and cob hit col.";
@@ -17,9 +44,12 @@ pub const NEED_HIT_ROW_HIT_COL_COB_COL: &str =
 pub const NEED_HIT_COL_COB_COL: &str = "Please provide cob hit col and cob tail col.";
 pub const NEED_COB_COL: &str = "Please provide cob tail col.";
 
-// parse_doom
+// ash_command
 pub const NEED_DOOM_ROW_DOOM_COL: &str = "Please provide doom row and doom col.";
 pub const NEED_DOOM_ROW: &str = "Please provide doom row.";
+pub const NEED_PLANT_ROW_PLANT_COL: &str = "Please provide plant row and plant col.";
+pub const NEED_PLANT_COL: &str = "Please provide plant col.";
+pub const NEED_PLANT_ROW: &str = "Please provide plant row.";
 
 // parse_find_max_delay
 pub const NEED_HIT_ROW_HIT_COL_RANGE: &str =
@@ -32,18 +62,61 @@ pub const NEED_HIT_COL_RANGE_COB_COL: &str =
 pub const CANNOT_INTERCEPT_WITHOUT_HARM: &str = "Cannot intercept without causing harm.";
 pub const HIT_COL_WITH_MAX_DELAY: &str = "Cob hit col with max delay";
 
+// print_scan_report
+pub const SCAN_HEADER: &str = "hit col harmless    harmful          eat";
+pub const SCAN_LEGEND: &str = "Delay {}~{}: + harmless, ! harmful, . not intercepted";
+pub const MIN_DELAY_SHOULD_BE_SMALLER_THAN_MAX_DELAY: &str = "should satisfy min delay ≤ max delay";
+
+// parse_solve
+pub const NEED_COB_TIME_RANGE_HIT_ROW_HIT_COL: &str =
+    "Please provide comma-separated cob time range, cob hit row and cob hit col.";
+pub const NEED_COB_TIME_RANGE_HIT_ROW_HIT_COL_COB_COL: &str =
+    "Please provide comma-separated cob time range, cob hit row, cob hit col and cob tail col.";
+pub const NEED_GARG_ROWS: &str = "Please provide garg rows.";
+pub const SOLVE_RANGE: &str = "Cob time {}~{}: max delay {} (at cob time {})";
+
+// parse_ice_solve
+pub const NEED_ICE_TIME_RANGE_HIT_ROW_HIT_COL: &str =
+    "Please provide comma-separated ice time range(s), cob hit row and cob hit col.";
+pub const NEED_ICE_TIME_RANGE_HIT_ROW_HIT_COL_COB_COL: &str =
+    "Please provide comma-separated ice time range(s), cob hit row, cob hit col and cob tail col.";
+pub const ICE_SOLVE_RANGE: &str = "Ice at {}: max delay {} (ice at {})";
+pub const ICE_SOLVE_BEST: &str = "Max delay overall: {} (ice at {})";
+
 // parse_garg_x_range_of_imp_x
 pub const NEED_IMP_X_RANGE: &str =
-    "Please provide comma-separated imp x range (imp x must be integer).";
+    "Please provide imp x or comma-separated imp x range (imp x must be integer).";
 pub const IMP_X_SHOULD_BE_INTEGER: &str = "imp x should be integer";
 pub const IMP_X_SHOULD_BE_IN_RANGE: &str = "should satisfy {} ≤ imp x ≤ {}";
+pub const NO_GARG_THROWS_IMP_X: &str = "no garg throws imp of this x in this scene";
+pub const MIN_IMP_X_SHOULD_BE_SMALLER_THAN_MAX_IMP_X: &str = "should satisfy min imp x ≤ max imp x";
+pub const NEED_IMP_BEFORE_GARG_X_RANGE: &str =
+    "Please calc garg x range with imp before using \"imp\" as garg x range.";
 
 // parse_ice_times
-pub const ICE_TIMES_SHOULD_BE_INTEGER: &str = "ice times should be integer";
+pub const ICE_TIMES_SHOULD_BE_INTEGER: &str =
+    "ice times should be integer (may end with i for ice or m for slow)";
 
 // parse_cob_time
-pub const COB_TIME_SHOULD_BE_INTEGER: &str = "cob time should be integer";
+pub const COB_TIME_SHOULD_BE_INTEGER: &str =
+    "cob time should be integer (may end with a for plant activation)";
 pub const COB_TIME_SHOULD_BE_NON_NEGATIVE: &str = "cob time should ≥ 0";
+pub const GARG_REACHED_HOUSE: &str = "cob time is too late: gargantuars have reached the house";
+
+// parse_min_max_cob_time
+pub const NEED_MIN_MAX_COB_TIME: &str = "Please provide min and max cob time.";
+pub const NEED_MAX_COB_TIME: &str = "Please provide max cob time.";
+pub const COB_TIME_RANGE_SHOULD_BE_INTEGER: &str = "cob time range should be integer";
+pub const MIN_COB_TIME_SHOULD_BE_SMALLER_THAN_MAX_COB_TIME: &str =
+    "should satisfy min cob time ≤ max cob time";
+
+// parse_min_max_ice_time
+pub const NEED_MIN_MAX_ICE_TIME: &str = "Please provide min and max ice time.";
+pub const NEED_MAX_ICE_TIME: &str = "Please provide max ice time.";
+pub const ICE_TIME_RANGE_SHOULD_BE_INTEGER: &str = "ice time range should be integer";
+pub const ICE_TIME_SHOULD_BE_IN_RANGE: &str = "should satisfy 0 ≤ ice time ≤ cob time {}";
+pub const MIN_ICE_TIME_SHOULD_BE_SMALLER_THAN_MAX_ICE_TIME: &str =
+    "should satisfy min ice time ≤ max ice time";
 
 // parse_delay_time
 pub const DELAY_TIME_SHOULD_BE_INTEGER: &str = "delay time should be integer";
@@ -66,14 +139,22 @@ pub const MIN_COL_SHOULD_BE_SMALLER_THAN_MAX_COL: &str = "should satisfy min hit
 // parse_cob_col
 pub const COB_COL_SHOULD_BE_INTEGER: &str = "cob tail col should be integer";
 pub const COB_COL_SHOULD_BE_IN_RANGE: &str = "should satisfy 1 ≤ cob tail col ≤ 8";
+pub const COB_ROW_SHOULD_BE_INTEGER: &str = "cob tail row should be integer";
+pub const COB_ROW_SHOULD_BE_IN_RANGE: &str = "should satisfy 1 ≤ cob tail row ≤ 5";
 
-// parse_doom_row
+// parse_ash_row
 pub const DOOM_ROW_SHOULD_BE_INTEGER: &str = "doom row should be integer";
 pub const DOOM_ROW_OUT_OF_RANGE: &str = "doom row is out of range {}";
 
-// parse_doom_col
+// parse_ash_col
 pub const DOOM_COL_SHOULD_BE_INTEGER: &str = "doom col should be integer";
 pub const DOOM_COL_SHOULD_BE_IN_RANGE: &str = "should satisfy 1 ≤ doom col ≤ 9";
+pub const PLANT_ROW_SHOULD_BE_INTEGER: &str = "plant row should be integer";
+pub const PLANT_ROW_OUT_OF_RANGE: &str = "plant row is out of range {}";
+pub const PLANT_COL_SHOULD_BE_INTEGER: &str = "plant col should be integer";
+pub const PLANT_COL_SHOULD_BE_IN_RANGE: &str = "should satisfy 1 ≤ plant col ≤ 9";
+pub const LAWN_CELLS_SHOULD_BE_ROW_COLS: &str =
+    "plant cells should be row:cols, e.g. 1:1-6 or 2:1,3,5";
 
 // parse_garg_pos
 pub const NEED_GARG_ROWS_X_RANGE_ICE_FLAG: &str =
@@ -98,21 +179,31 @@ pub const ICE_FLAG_SHOULD_BE_U_OR_I: &str = "ice mode should be u or i";
 pub const DELAY_SETTING: &str = "Delay setting";
 pub const SETTING: &str = "Setting";
 pub const CANNOT_HIT_ALL_GARG: &str = "Cannot hit all gargantuars at this tick.";
+pub const GARG_WALK_EXTRAPOLATED: &str =
+    "garg max walk time [{}] is out of available data range (0~{}); garg x range is periodically extrapolated.";
 pub const NO_ICE: &str = "no ice,";
 pub const ICE: &str = " ice,";
+pub const SLOW: &str = " slow,";
 pub const COB_EFFECTIVE: &str = " cob";
 pub const COB_ACTIVATE: &str = " cob";
+pub const PLANT_ACTIVATE: &str = " plant";
+pub const PLANT_ACTIVATION: &str = "plant activation";
 pub const GARG_X_RANGE: &str = "Garg x range";
+pub const NON_ROOF_SCENES: &str = "Frontyard/Backyard";
+pub const ROOF_SCENES: &str = "Roof";
 
 // print_cob_calc_setting
 pub const CALCULATION_SETTING: &str = "Calc setting";
 pub const COB_GARG_ROWS: &str = "hit row {} for row {} gargs";
 pub const COB_COL_RANGE: &str = "hit col {}~{}";
+pub const COB_TAIL: &str = "cob tail {}-{}";
 pub const EXPLOSION_CENTER: &str = "explosion center ";
 pub const GARG: &str = "garg ";
 
-// print_doom_calc_setting
+// print_ash_calc_setting
 pub const DOOM_GARG_ROWS: &str = "row {} doom for row {} gargs";
+pub const CHERRY_GARG_ROWS: &str = "row {} cherry for row {} gargs";
+pub const JALAPENO_GARG_ROWS: &str = "row {} jalapeno for row {} gargs";
 
 // print_eat_and_intercept
 pub const INTERCEPTABLE_INTERVAL: &str = "Interceptable";
@@ -123,6 +214,22 @@ pub const DOES_NOT_EAT: &str = "does not eat";
 pub const EARLIEST_ICEABLE: &str = "Earliest iceable";
 pub const NOT_ICEABLE: &str = "not iceable";
 
+// print_killer_report
+pub const SQUASH_GARG_ROWS: &str = "{}-{} squash for row {} gargs";
+pub const CHOMPER_GARG_ROWS: &str = "{}-{} chomper for row {} gargs";
+pub const KILL_TIME: &str = "Kill time";
+pub const CANNOT_KILL_ALL: &str = "cannot kill all imps";
+pub const KILL_AFTER_EAT: &str = " (after eating)";
+
+// print_lawn
+pub const LAWN_EMPTY: &str = "No plants on lawn.";
+pub const LAWN_ROW: &str = "row {}: col {}";
+
+// print_eat_report
+pub const EAT_ROW: &str = "row {}";
+pub const EAT_TARGET: &str = "col {} ({})";
+pub const PASSES_ALL_PLANTS: &str = "passes all plants";
+
 // print_hit_cob_dist
 pub const COL: &str = "col {}";
 pub const HIT_SAME_AND_LOWER: &str = "Hit same & lower rows";
@@ -185,22 +292,32 @@ Current scene: Backyard.
 Type '?' for help; press ↑ to show previous commands.
 
 Results are based on cob activation by default.
-For ash activation, subtract 1 from the results."#;
+For ash activation, append a to cob time (eg. wave 1 400 800a)."#;
 
 pub const HELP: &str = r#"
-de/pe/re                Set scene
+de/ne/pe/fe/re/me       Set scene
 
 wave                    View current ice times and cob time
 
 wave [ice times..] [cob time] 
-                        Set ice times and cob time (ice times can be none)
+                        Set ice times and cob time (ice times can be none;
+                        suffix i or none for ice-shroom, m for winter melon
+                        or snow pea that slows without freezing)
+                        (append a to cob time for ash/plant activation)
                     eg. $ wave 1 400 800 -> use ice at 1, 400; use cob at 800
+                        $ wave 1i 400m 800 -> ice at 1, melon slow at 400
+                        $ wave 1 400 800a -> same as the first, but
+                            activated by ash at 800
 
 delay [hit col] (cob tail col)
                         Calc interceptable interval, earliest eat & iceable
                         (need to provide cob tail col for roof scene)
+                        (cob tail col may be followed by @row, row 3 if
+                        omitted)
                     eg. $ delay 8.8 -> Calc hit col 8.8
                         $ delay 3.5 4 -> Calc hit col 3.5 for cob tail col 4
+                        $ delay 3.5 4@2 -> Same as above, for cob tail
+                            at 2-4
 
 delay2 ...
 delay3 ...              Same as above, but specify intercepting 2/3 rows
@@ -222,6 +339,28 @@ doom [doom row] [doom col]
                         $ doom 3 8 > 2,5 700,800 ->
                             Calc 3-8 doom for row [2,5] gargs with x 700~800
 
+cherry [plant row] [plant col]
+  (> [garg rows] (garg x range) (u/i))
+                        Calc cherry for specific gargs (usage same as doom)
+                    eg. $ cherry 2 8 > 1,3 -> Calc 2-8 cherry for row [1,3] gargs
+
+jalapeno [plant row]
+  (> [garg rows] (garg x range) (u/i))
+                        Calc jalapeno for specific gargs (hits the whole row)
+                    eg. $ jalapeno 2 -> Calc row 2 jalapeno
+
+squash [plant row] [plant col]
+  (> [garg rows] (garg x range) (u/i))
+                        Calc whether squash kills all imps before eating
+                        (garg rows may only be the plant row)
+                    eg. $ squash 1 4 -> Calc 1-4 squash for row 1 gargs
+
+chomper [plant row] [plant col]
+  (> [garg rows] (garg x range) (u/i))
+                        Calc whether chomper kills all imps before eating
+                    eg. $ chomper 1 3 > 1 800,800 ->
+                            Calc 1-3 chomper for row 1 gargs with x 800
+
 hit (cob tail col) (delay)
                         Calc hit col that hits all gargs (may specify delay)
                     eg. $ hit -> Calc hit col that hits all gargs
@@ -234,7 +373,7 @@ nohit (cob tail col) (delay)
                         Calc hit col that doesn't hit any garg
                         (may specify delay)
 
-max [hit row] [hit col range]
+max [hit row] [hit col range] (cob tail col)
   > [garg rows] (garg x range) (u/i)
                         Find hit col that harmlessly intercepts with max delay
                         (may specify ice mode)
@@ -242,7 +381,96 @@ max [hit row] [hit col range]
                             For hit row 1 and hit col 7~7.5, find hit col that
                             harmlessly intercepts gargs with max delay
 
+scan (csv) (delay range) [hit row] [hit col range] (cob tail col)
+  > [garg rows] (garg x range) (u/i)
+                        List harmless/harmful interception ranges and earliest
+                        eat for every pixel hit col in the range (args same as
+                        max); with a delay range, mark whether each delay
+                        intercepts; with csv, print CSV instead
+                    eg. $ scan 100,130 1 7,7.5 > 1 ->
+                            For each hit col of row 1 in 7~7.5, list results
+                            against row 1 gargs and mark delays 100~130
+
+solve [cob time range] [hit row] [hit col] (cob tail col)
+  (> [garg rows])
+                        With the current ice times, calc each cob time in the
+                        range and list contiguous cob times that intercept
+                        harmlessly, with the max delay of each range (garg
+                        rows default to all gargs the cob can hit)
+                    eg. $ wave 1 800 $ solve 300,1500 1 8.8 > 1 ->
+                            With ice at 1, find cob times at which (1,8.8)
+                            cob harmlessly intercepts row 1 gargs
+
+icesolve [ice time range] (second ice time range) [hit row] [hit col]
+  (cob tail col) (> [garg rows])
+                        Keeping the current cob time and melon slows, replace
+                        the ice times with one or two ices (the second later
+                        than the first), calc each ice timing and list the
+                        ones that intercept harmlessly with their max delay
+                    eg. $ wave 1200 $ icesolve 1,1200 1 7 > 1 ->
+                            With cob at 1200, find ice times at which (1,7)
+                            cob harmlessly intercepts row 1 gargs
+
+multi [time] [hit row] [hit col] (cob tail col) > [garg rows]
+  ; ... (garg x range) (u/i)
+                        Calc cobs taking effect at different times; show which
+                        cob intercepts each imp and whether all imps are
+                        intercepted before eating (time is counted the same
+                        way as interceptable interval; garg x range and u/i
+                        go after the last cob)
+                    eg. $ multi 110 1 8.8 > 1 ; 130 1 8.8 > 2 700,800 ->
+                            Calc (1,8.8) cob at 110 for row 1 gargs and
+                            (1,8.8) cob at 130 for row 2 gargs with x 700~800
+
+trace [garg row] [garg x] [rnd] (u/i)
+                        Show the imp thrown by this garg tick by tick: state,
+                        position and defense rect (rnd is 0~100)
+trace [hit row] [hit col] (cob tail col) > [garg row] [garg x] [rnd] (u/i)
+                        Same as above, and mark ticks the cob can hit with *
+                    eg. $ trace 1 8.8 > 1 750 100 ->
+                            Trace the imp thrown by row 1 garg at x 750 with
+                            rnd 100, marking ticks (1,8.8) cob can hit
+
 imp [imp x]             Calc x range of garg who can throw imp of this x
+                        (roof differs from other scenes)
+imp [imp x range]       Calc x range of garg who can throw any imp in this
+                        range; later commands may use "imp" as garg x range
+                    eg. $ imp 150,180 $ delay 1 8.8 > 1 imp ->
+                            Calc (1,8.8) cob for row 1 gargs who can throw
+                            imps with x 150~180
+land (garg x range) (u/i)
+                        Calc min/max landing x and landing tick of imps thrown
+                        by gargs in this x range with rnd 0~100
+
+lawn (row:cols) ...     Set plant cells used by eat; show them if omitted
+                    eg. $ lawn 1:1-6 2:1,3,5 -> plants at cols 1~6 of row 1
+                            and cols 1, 3, 5 of row 2
+                        $ lawn clear -> remove all plants
+
+eat (garg x range) (u/i)
+                        Calc per garg row which plant col the imps eat and
+                        when they start eating, or that they pass all plants
+                    eg. $ lawn 1:1-4 $ eat -> Calc eat targets of row 1 imps
+
+svg [file name]         Draw the explosion of the last delay, doom, cherry or
+                        jalapeno and imp defense rects along the trajectories
+                        of extreme garg x and rnd as SVG; ticks it hits are
+                        filled red
+                    eg. $ delay 1 8.8 > 1 $ svg 1-8.8.svg ->
+                            Draw (1,8.8) cob intercepting row 1 gargs
+
+save [name]             Save current scene, ice times and cob time as [name]
+load [name]             Load setup saved as [name]
+                    eg. $ re $ wave 1 400 800 $ save roof1 -> save as roof1
+                        $ load roof1 -> restore roof scene, ice at 1, 400
+                            and cob at 800
+
+format [text/json]      Set output format; json prints one JSON object per
+                        result (see doc/json_en.md; start with --json to use
+                        json from the beginning)
+
+lang [en/zh]            Set language (start with --lang to choose language;
+                        defaults to LANG environment variable)
 
 ?/help                  Show this help
 about                   About Interception Calculator"#;
@@ -250,9 +478,72 @@ about                   About Interception Calculator"#;
 // main.rs
 pub const UNKNOWN_COMMAND: &str = "Unknown command. Type '?' for help.";
 pub const ERROR: &str = "Error";
-
-// game.rs
-pub const GARG_MIN_WALK_OUT_OF_RANGE: &str =
-    "garg min walk time [{}] is out of available data range ({}~{})";
-pub const GARG_MAX_WALK_OUT_OF_RANGE: &str =
-    "garg max walk time [{}] is out of available data range ({}~{})";
+pub const NEED_SCRIPT_FILE: &str = "Please provide script file: run [script file]";
+pub const CANNOT_OPEN_SCRIPT: &str = "Cannot open script file";
+pub const UNKNOWN_ARGUMENT: &str = "Unknown argument";
+
+// session.rs
+pub const SETUP_NOT_FOUND: &str = "setup not found";
+pub const CANNOT_READ_STATE: &str = "Cannot read state file";
+pub const CANNOT_SAVE_STATE: &str = "Cannot save state file";
+
+// parse_multi, print_multi_report
+pub const NEED_TIMED_COB: &str =
+    "Please provide explosion time, cob hit row, cob hit col and target gargs for each cob.";
+pub const EXPLOSION_TIME_SHOULD_BE_INTEGER: &str = "explosion time should be integer";
+pub const GARG_X_RANGE_ONLY_AFTER_LAST_COB: &str =
+    "garg x range and u/i can only follow the last cob.";
+pub const TIMED_COB_GARG_ROWS: &str = "at {} hit row {} for row {} gargs";
+pub const TRAJECTORY: &str = "Garg x={} row {} rnd={}";
+pub const KILLED_BY: &str = "intercepted by cob #{} at {}";
+pub const NOT_INTERCEPTED: &str = "not intercepted";
+pub const ALL_INTERCEPTED: &str = "All imps are intercepted before eating.";
+pub const NOT_ALL_INTERCEPTED: &str = "Some imps are not intercepted before eating.";
+
+// parse_trace, print_trace_report
+pub const NEED_GARG_ROW_X_RND: &str = "Please provide garg row, garg x and rnd (0~100).";
+pub const GARG_ROW_SHOULD_BE_INTEGER: &str = "garg row should be integer";
+pub const GARG_ROW_OUT_OF_RANGE: &str = "garg row is out of range {}";
+pub const GARG_X_SHOULD_BE_NUMBER: &str = "garg x should be number";
+pub const GARG_X_SHOULD_BE_IN_RANGE: &str = "should satisfy {} < garg x ≤ {}";
+pub const RND_SHOULD_BE_INTEGER: &str = "rnd should be integer";
+pub const RND_SHOULD_BE_IN_RANGE: &str = "should satisfy 0 ≤ rnd ≤ 100";
+pub const UNICED: &str = "uniced";
+pub const ICED: &str = "iced";
+pub const TRACE_COB: &str = "cob at {}-{}";
+pub const NO_IMP_THROWN: &str = "This garg does not throw an imp.";
+pub const TRACE_HEADER: &str =
+    "tick  state           x        y        h  y_shift  defense (x, y, w, h)";
+pub const TRACE_HIT: &str = "hit";
+
+// print_land_report
+pub const IMP_LANDING_X: &str = "Imp landing x";
+pub const IMP_LANDING_TICK: &str = "Imp landing tick";
+
+// editor.rs
+pub const ARG_REQUIRED: &str = "[{}]";
+pub const ARG_OPTIONAL: &str = "({})";
+pub const EXPECTED: &str = "expected {}";
+pub const ARG_ICE_TIMES: &str = "ice times(i/m)..";
+pub const ARG_COB_TIME: &str = "cob time(a)";
+pub const ARG_HIT_ROW: &str = "hit row";
+pub const ARG_HIT_COL: &str = "hit col";
+pub const ARG_COB_COL: &str = "cob tail col";
+pub const ARG_GARG_ROWS: &str = "garg rows";
+pub const ARG_GARG_X_RANGE: &str = "garg x range";
+pub const ARG_DOOM_ROW: &str = "doom row";
+pub const ARG_DOOM_COL: &str = "doom col";
+pub const ARG_PLANT_ROW: &str = "plant row";
+pub const ARG_PLANT_COL: &str = "plant col";
+pub const ARG_DELAY: &str = "delay";
+pub const ARG_HIT_COL_RANGE: &str = "hit col range";
+pub const ARG_COB_TIME_RANGE: &str = "cob time range";
+pub const ARG_DELAY_RANGE: &str = "delay range";
+pub const ARG_ICE_TIME_RANGE: &str = "ice time range";
+pub const ARG_IMP_X: &str = "imp x (range)";
+pub const ARG_SETUP_NAME: &str = "name";
+pub const ARG_SVG_FILE: &str = "file name";
+pub const ARG_GARG_ROW: &str = "garg row";
+pub const ARG_GARG_X: &str = "garg x";
+pub const ARG_RND: &str = "rnd";
+pub const ARG_LAWN_CELLS: &str = "row:cols";
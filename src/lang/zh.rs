@@ -6,6 +6,32 @@ pub const GARG_X_RANGE_MODIFIED: &str = "x坐标<401的巨人不会投掷小鬼,
 pub const SET_FRONTYARD: &str = "已设置为前院场合.";
 pub const SET_BACKYARD: &str = "已设置为后院场合.";
 pub const SET_ROOF: &str = "已设置为屋顶场合.";
+pub const SET_NIGHT: &str = "已设置为前院夜间场合.";
+pub const SET_FOG: &str = "已设置为后院雾夜场合.";
+pub const SET_MOON: &str = "已设置为屋顶月夜场合.";
+
+// parse_format
+pub const SET_TEXT_FORMAT: &str = "已设置为文本输出.";
+pub const SET_JSON_FORMAT: &str = "已设置为JSON输出.";
+pub const NEED_FORMAT: &str = "请提供输出格式(text/json)";
+pub const FORMAT_SHOULD_BE_TEXT_OR_JSON: &str = "输出格式应为text或json";
+
+// parse_lang
+pub const SET_LANG: &str = "已设置为中文.";
+pub const NEED_LANG: &str = "请提供语言(en/zh)";
+pub const LANG_SHOULD_BE_EN_OR_ZH: &str = "语言应为en或zh";
+
+// parse_save, parse_load
+pub const NEED_SETUP_NAME: &str = "请提供设置名称";
+pub const SETUP_SAVED: &str = "已保存设置\"{}\".";
+pub const SETUP_LOADED: &str = "已读取设置\"{}\".";
+pub const NO_CONFIG_DIR: &str = "找不到配置目录, 无法保存设置.";
+
+// parse_svg
+pub const NEED_SVG_FILE: &str = "请提供SVG文件名";
+pub const NEED_EXPLOSION_BEFORE_SVG: &str = "请先使用delay、doom、cherry或jalapeno指令";
+pub const SVG_SAVED: &str = "已保存图片至\"{}\".";
+pub const CANNOT_WRITE_SVG: &str = "无法写入SVG文件";
 
 // parse_delay
 pub const NEED_HIT_ROW_HIT_COL: &str = "请提供炮落点行、炮落点列";
@@ -14,9 +40,12 @@ pub const NEED_HIT_ROW_HIT_COL_COB_COL: &str = "请提供炮落点行、炮落
 pub const NEED_HIT_COL_COB_COL: &str = "请提供炮落点列、炮尾所在列";
 pub const NEED_COB_COL: &str = "请提供炮尾所在列";
 
-// parse_doom
+// ash_command
 pub const NEED_DOOM_ROW_DOOM_COL: &str = "请提供核所在行、核所在列";
 pub const NEED_DOOM_ROW: &str = "请提供核所在列";
+pub const NEED_PLANT_ROW_PLANT_COL: &str = "请提供植物所在行、植物所在列";
+pub const NEED_PLANT_COL: &str = "请提供植物所在列";
+pub const NEED_PLANT_ROW: &str = "请提供植物所在行";
 
 // parse_find_max_delay
 pub const NEED_HIT_ROW_HIT_COL_RANGE: &str = "请提供炮落点行、炮落点列范围(逗号分隔)";
@@ -27,17 +56,56 @@ pub const NEED_HIT_COL_RANGE_COB_COL: &str = "请提供炮落点列范围(逗号
 pub const CANNOT_INTERCEPT_WITHOUT_HARM: &str = "无法无伤拦截.";
 pub const HIT_COL_WITH_MAX_DELAY: &str = "延迟最大的炮落点";
 
+// print_scan_report
+pub const SCAN_HEADER: &str = "落点列  无伤区间    有伤区间    最早啃食";
+pub const SCAN_LEGEND: &str = "延迟{}~{}: +无伤拦截, !有伤拦截, .无法拦截";
+pub const MIN_DELAY_SHOULD_BE_SMALLER_THAN_MAX_DELAY: &str = "应满足延迟最小值≤最大值";
+
+// parse_solve
+pub const NEED_COB_TIME_RANGE_HIT_ROW_HIT_COL: &str =
+    "请提供激活时机范围(逗号分隔)、炮落点行、炮落点列";
+pub const NEED_COB_TIME_RANGE_HIT_ROW_HIT_COL_COB_COL: &str =
+    "请提供激活时机范围(逗号分隔)、炮落点行、炮落点列、炮尾所在列";
+pub const NEED_GARG_ROWS: &str = "请提供巨人所在行";
+pub const SOLVE_RANGE: &str = "激活时机{}~{}: 最大延迟{} (激活时机{})";
+
+// parse_ice_solve
+pub const NEED_ICE_TIME_RANGE_HIT_ROW_HIT_COL: &str =
+    "请提供用冰时机范围(逗号分隔, 可为一或两个)、炮落点行、炮落点列";
+pub const NEED_ICE_TIME_RANGE_HIT_ROW_HIT_COL_COB_COL: &str =
+    "请提供用冰时机范围(逗号分隔, 可为一或两个)、炮落点行、炮落点列、炮尾所在列";
+pub const ICE_SOLVE_RANGE: &str = "用冰时机{}: 最大延迟{} (用冰时机{})";
+pub const ICE_SOLVE_BEST: &str = "总体最大延迟: {} (用冰时机{})";
+
 // parse_garg_x_range_of_imp_x
-pub const NEED_IMP_X_RANGE: &str = "请提供小鬼x坐标(整数)";
+pub const NEED_IMP_X_RANGE: &str = "请提供小鬼x坐标或以逗号分隔的范围(整数)";
 pub const IMP_X_SHOULD_BE_INTEGER: &str = "小鬼x坐标应为整数";
 pub const IMP_X_SHOULD_BE_IN_RANGE: &str = "应满足{}≤小鬼x坐标≤{}";
+pub const NO_GARG_THROWS_IMP_X: &str = "此场合下没有巨人会投掷该坐标的小鬼";
+pub const MIN_IMP_X_SHOULD_BE_SMALLER_THAN_MAX_IMP_X: &str = "应满足小鬼x坐标最小值≤最大值";
+pub const NEED_IMP_BEFORE_GARG_X_RANGE: &str =
+    "请先用imp指令计算巨人x范围，再以\"imp\"代替巨人x范围";
 
 // parse_ice_times
-pub const ICE_TIMES_SHOULD_BE_INTEGER: &str = "用冰时机应为整数";
+pub const ICE_TIMES_SHOULD_BE_INTEGER: &str = "用冰时机应为整数(可加后缀i表示冰冻, m表示减速)";
 
 // parse_cob_time
-pub const COB_TIME_SHOULD_BE_INTEGER: &str = "激活时机应为整数";
+pub const COB_TIME_SHOULD_BE_INTEGER: &str = "激活时机应为整数(可加后缀a表示植物激活)";
 pub const COB_TIME_SHOULD_BE_NON_NEGATIVE: &str = "激活时机应≥0";
+pub const GARG_REACHED_HOUSE: &str = "激活时机过晚, 巨人已进家";
+
+// parse_min_max_cob_time
+pub const NEED_MIN_MAX_COB_TIME: &str = "请提供激活时机最小值、最大值";
+pub const NEED_MAX_COB_TIME: &str = "请提供激活时机最大值";
+pub const COB_TIME_RANGE_SHOULD_BE_INTEGER: &str = "激活时机范围应为整数";
+pub const MIN_COB_TIME_SHOULD_BE_SMALLER_THAN_MAX_COB_TIME: &str = "应满足激活时机最小值≤最大值";
+
+// parse_min_max_ice_time
+pub const NEED_MIN_MAX_ICE_TIME: &str = "请提供用冰时机最小值、最大值";
+pub const NEED_MAX_ICE_TIME: &str = "请提供用冰时机最大值";
+pub const ICE_TIME_RANGE_SHOULD_BE_INTEGER: &str = "用冰时机范围应为整数";
+pub const ICE_TIME_SHOULD_BE_IN_RANGE: &str = "应满足0≤用冰时机≤激活时机{}";
+pub const MIN_ICE_TIME_SHOULD_BE_SMALLER_THAN_MAX_ICE_TIME: &str = "应满足用冰时机最小值≤最大值";
 
 // parse_delay_time
 pub const DELAY_TIME_SHOULD_BE_INTEGER: &str = "炮生效延时应为整数";
@@ -59,14 +127,21 @@ pub const MIN_COL_SHOULD_BE_SMALLER_THAN_MAX_COL: &str = "应满足炮落点列
 // parse_cob_col
 pub const COB_COL_SHOULD_BE_INTEGER: &str = "炮尾所在列应为整数";
 pub const COB_COL_SHOULD_BE_IN_RANGE: &str = "应满足1≤炮尾所在列≤8";
+pub const COB_ROW_SHOULD_BE_INTEGER: &str = "炮尾所在行应为整数";
+pub const COB_ROW_SHOULD_BE_IN_RANGE: &str = "应满足1≤炮尾所在行≤5";
 
-// parse_doom_row
+// parse_ash_row
 pub const DOOM_ROW_SHOULD_BE_INTEGER: &str = "核所在行应为整数";
 pub const DOOM_ROW_OUT_OF_RANGE: &str = "核所在行超出范围{}";
 
-// parse_doom_col
+// parse_ash_col
 pub const DOOM_COL_SHOULD_BE_INTEGER: &str = "核所在列应为整数";
 pub const DOOM_COL_SHOULD_BE_IN_RANGE: &str = "应满足1≤核所在列≤9";
+pub const PLANT_ROW_SHOULD_BE_INTEGER: &str = "植物所在行应为整数";
+pub const PLANT_ROW_OUT_OF_RANGE: &str = "植物所在行超出范围{}";
+pub const PLANT_COL_SHOULD_BE_INTEGER: &str = "植物所在列应为整数";
+pub const PLANT_COL_SHOULD_BE_IN_RANGE: &str = "应满足1≤植物所在列≤9";
+pub const LAWN_CELLS_SHOULD_BE_ROW_COLS: &str = "植物格子应为 行:列，如1:1-6或2:1,3,5";
 
 // parse_garg_pos
 pub const NEED_GARG_ROWS_X_RANGE_ICE_FLAG: &str =
@@ -90,21 +165,31 @@ pub const ICE_FLAG_SHOULD_BE_U_OR_I: &str = "计算模式应为u/i(原速/减速
 pub const DELAY_SETTING: &str = "延时设定";
 pub const SETTING: &str = "当前设定";
 pub const CANNOT_HIT_ALL_GARG: &str = "此时机无法全伤巨人.";
+pub const GARG_WALK_EXTRAPOLATED: &str =
+    "巨人最长行走时间[{}]超出数据范围(0~{}), 巨人x范围按行走周期外推.";
 pub const NO_ICE: &str = "不用冰";
 pub const ICE: &str = "冰";
+pub const SLOW: &str = "减速";
 pub const COB_EFFECTIVE: &str = "炮生效";
 pub const COB_ACTIVATE: &str = "激活";
+pub const PLANT_ACTIVATE: &str = "植物激活";
+pub const PLANT_ACTIVATION: &str = "植物激活";
 pub const GARG_X_RANGE: &str = "巨人坐标范围";
+pub const NON_ROOF_SCENES: &str = "前院/后院场合";
+pub const ROOF_SCENES: &str = "屋顶场合";
 
 // print_cob_calc_setting
 pub const CALCULATION_SETTING: &str = "计算设定";
 pub const COB_GARG_ROWS: &str = "{}炮炸{}路";
 pub const COB_COL_RANGE: &str = "落点{}~{}列";
+pub const COB_TAIL: &str = "炮尾{}-{}";
 pub const EXPLOSION_CENTER: &str = "爆心";
 pub const GARG: &str = "巨人";
 
-// print_doom_calc_setting
+// print_ash_calc_setting
 pub const DOOM_GARG_ROWS: &str = "{}核炸{}路";
+pub const CHERRY_GARG_ROWS: &str = "{}樱桃炸{}路";
+pub const JALAPENO_GARG_ROWS: &str = "{}辣椒炸{}路";
 
 // print_eat_and_intercept
 pub const INTERCEPTABLE_INTERVAL: &str = "可拦区间";
@@ -115,6 +200,22 @@ pub const DOES_NOT_EAT: &str = "不啃食";
 pub const EARLIEST_ICEABLE: &str = "最早可冰";
 pub const NOT_ICEABLE: &str = "不可冰";
 
+// print_killer_report
+pub const SQUASH_GARG_ROWS: &str = "{}-{}窝瓜杀{}路";
+pub const CHOMPER_GARG_ROWS: &str = "{}-{}大嘴花杀{}路";
+pub const KILL_TIME: &str = "秒杀时机";
+pub const CANNOT_KILL_ALL: &str = "无法秒杀所有小鬼";
+pub const KILL_AFTER_EAT: &str = " (啃食后)";
+
+// print_lawn
+pub const LAWN_EMPTY: &str = "场地上没有植物";
+pub const LAWN_ROW: &str = "{}路: {}列";
+
+// print_eat_report
+pub const EAT_ROW: &str = "{}路";
+pub const EAT_TARGET: &str = "{}列({})";
+pub const PASSES_ALL_PLANTS: &str = "越过所有植物";
+
 // print_hit_cob_dist
 pub const COL: &str = "{}列";
 pub const HIT_SAME_AND_LOWER: &str = "全伤本行&下行";
@@ -167,19 +268,27 @@ https://github.com/Rottenham/pvz-interception-calculator-rust
 当前场合: 后院.
 输入问号查看帮助; 按↑键显示上次输入的指令.
 
-计算结果默认为炮激活的情况. 若为植物激活, 需额外-1."#;
+计算结果默认为炮激活的情况. 若为植物激活, 请在激活时机后加a(如wave 1 400 800a)."#;
 
 pub const HELP: &str = r#"
-de/pe/re                            设置场合
+de/ne/pe/fe/re/me                   设置场合
 
 wave                                查看当前用冰、激活时机
-wave 冰时机.. 激活时机              设置用冰、激活时机(用冰时机可为0个或多个)
+wave 冰时机.. 激活时机              设置用冰、激活时机(用冰时机可为0个或多个,
+                                    不带后缀或带i为寒冰菇, 带m为冰瓜/寒冰射手
+                                    减速, 只减速不冻结; 激活时机后加a表示
+                                    灰烬等植物激活)
                                 例：$ wave 1 400 800 -> 1、400用冰, 800激活
+                                    $ wave 1i 400m 800 -> 1用冰, 400冰瓜减速
+                                    $ wave 1 400 800a -> 同第一例, 但800由
+                                        植物激活
 
 delay 炮列数 (炮尾列)               计算可拦区间、最早啃食、最早可冰
-                                    (屋顶场合需指定炮尾所在列)
+                                    (屋顶场合需指定炮尾所在列,
+                                    可用@指定炮尾所在行, 默认为3)
                                 例：$ delay 8.8 -> 非屋顶计算落8.8列的拦截炮
                                     $ delay 3.5 4 -> 屋顶计算落3.5列的45列炮
+                                    $ delay 3.5 4@2 -> 同上, 炮尾位于2-4
 
 delay2 ...
 delay3 ...                          同上，但指定计算拦截两行或三行
@@ -199,6 +308,24 @@ doom 核行数 核列数
                                     $ doom 3 8 > 2,5 700,800 ->
                                         计算3-8核武拦截2、5路x为700~800的巨人
 
+cherry 植物行数 植物列数
+  (> 巨人所在行 (巨人x范围) (u/i))  计算樱桃拦截特定巨人(用法同doom)
+                                例：$ cherry 2 8 > 1,3 -> 计算2-8樱桃拦截1、3路巨人
+
+jalapeno 植物行数
+  (> 巨人所在行 (巨人x范围) (u/i))  计算辣椒拦截特定巨人(炸到整行)
+                                例：$ jalapeno 2 -> 计算2路辣椒
+
+squash 植物行数 植物列数
+  (> 巨人所在行 (巨人x范围) (u/i))  计算窝瓜能否在啃食前秒杀所有小鬼
+                                    (巨人只能位于植物所在行)
+                                例：$ squash 1 4 -> 计算1-4窝瓜杀1路巨人的小鬼
+
+chomper 植物行数 植物列数
+  (> 巨人所在行 (巨人x范围) (u/i))  计算大嘴花能否在啃食前秒杀所有小鬼
+                                例：$ chomper 1 3 > 1 800,800 ->
+                                        计算1-3大嘴花杀1路x为800的巨人的小鬼
+
 hit (炮尾列) (延迟)                 计算刚好全伤巨人的炮落点(可指定炮延时生效)
                                 例：$ hit -> 计算全伤巨人的炮落点
                                     $ wave 300 $ hit 50 ->
@@ -208,13 +335,88 @@ hit (炮尾列) (延迟)                 计算刚好全伤巨人的炮落点(
 
 nohit (炮尾列) (延迟)               计算刚好不伤巨人的炮落点(可指定炮延时生效)
 
-max 炮行数 炮列数范围
+max 炮行数 炮列数范围 (炮尾列)
   > 巨人所在行 (巨人x范围) (u/i)    寻找无伤拦截可延迟最多的炮落点列
                                     (可指定按原速/减速计算)
                                 例：$ max 1 7,7.5 > 1,2 ->
                                         找1路7~7.5列炮拦1、2路巨人延迟最多的落点
 
-imp 小鬼x坐标                       计算投掷该坐标小鬼的巨人x范围
+scan (csv) (延迟范围) 炮行数 炮列数范围 (炮尾列)
+  > 巨人所在行 (巨人x范围) (u/i)    列出范围内每个像素落点列的无伤、有伤拦截区间
+                                    及最早啃食(参数同max); 给出延迟范围时逐个
+                                    延迟标出能否拦截, 指定csv时输出CSV
+                                例：$ scan 100,130 1 7,7.5 > 1 ->
+                                        列出1路7~7.5列各落点拦1路巨人的结果
+
+solve 激活时机范围 炮行数 炮列数 (炮尾列)
+  (> 巨人所在行)                    沿用当前用冰, 逐个激活时机计算, 列出可无伤拦截
+                                    的连续激活时机段及各段最大延迟
+                                    (巨人所在行默认为炮能炸到的所有行)
+                                例：$ wave 1 800 $ solve 300,1500 1 8.8 > 1 ->
+                                        1用冰时, 找(1,8.8)炮无伤拦截1路巨人的激活时机
+
+icesolve 用冰时机范围 (第二次用冰时机范围)
+  炮行数 炮列数 (炮尾列) (> 巨人所在行)
+                                    沿用当前激活时机及冰瓜减速, 以一或两次用冰(第二
+                                    次晚于第一次)代替原有用冰逐个计算, 列出可无伤
+                                    拦截的用冰时机及最大延迟
+                                例：$ wave 1200 $ icesolve 1,1200 1 7 > 1 ->
+                                        1200激活时, 找(1,7)炮无伤拦截1路巨人的用冰时机
+
+multi 生效时机 炮行数 炮列数 (炮尾列)
+  > 巨人所在行 ; ... (巨人x范围) (u/i)
+                                    计算多门炮在不同时机生效的拦截, 给出每个小鬼
+                                    被哪门炮拦截及是否均在啃食前被拦截
+                                    (生效时机与可拦区间含义相同,
+                                    巨人x范围及u/i在最后一门炮之后指定)
+                                例：$ multi 110 1 8.8 > 1 ; 130 1 8.8 > 2 700,800
+                                        -> 110时(1,8.8)炮拦1路、130时(1,8.8)炮
+                                        拦2路x为700~800的巨人
+
+trace 巨人所在行 巨人x坐标 rnd (u/i)  逐刻给出该巨人投掷的小鬼的状态、坐标及判定框
+                                    (rnd为0~100)
+trace 炮行数 炮列数 (炮尾列)
+  > 巨人所在行 巨人x坐标 rnd (u/i)  同上，并用*标出该炮能炸到小鬼的时刻
+                                例：$ trace 1 8.8 > 1 750 100 -> 逐刻查看1路x为750、
+                                        rnd为100的巨人投掷的小鬼，标出(1,8.8)炮
+                                        能炸到的时刻
+
+imp 小鬼x坐标                       计算投掷该坐标小鬼的巨人x范围(屋顶与其他场合不同)
+imp 小鬼x范围                       计算能投掷该范围内任一小鬼的巨人x范围,
+                                    之后的指令可用"imp"代替巨人x范围
+                                例：$ imp 150,180 $ delay 1 8.8 > 1 imp ->
+                                        计算(1,8.8)炮拦截1路能投掷x为150~180
+                                        的小鬼的巨人
+land (巨人x范围) (u/i)              计算该范围内的巨人以rnd为0~100投掷的小鬼的
+                                    落地x坐标及落地时刻范围
+
+lawn (行:列) ...                    设置 eat 所用的植物格子，省略时查看当前设置
+                                例：$ lawn 1:1-6 2:1,3,5 ->
+                                        1路1~6列、2路1、3、5列有植物
+                                    $ lawn clear -> 清除所有植物
+
+eat (巨人x范围) (u/i)               按植物格子计算各路小鬼啃食的植物列及开始啃食时机
+                                    (或越过所有植物)
+                                例：$ lawn 1:1-4 $ eat -> 计算1路小鬼的啃食目标
+
+svg 文件名                          将上次delay、doom、cherry或jalapeno的爆炸范围及
+                                    巨人x、rnd极值的小鬼轨迹绘制为SVG图片,
+                                    能炸到的时刻以红色填充
+                                例：$ delay 1 8.8 > 1 $ svg 1-8.8.svg ->
+                                        绘制(1,8.8)炮拦截1路巨人的图片
+
+save 名称                           将当前场合、用冰及激活时机保存为该名称
+load 名称                           读取以该名称保存的设置
+                                例：$ re $ wave 1 400 800 $ save roof1 ->
+                                        保存为roof1
+                                    $ load roof1 ->
+                                        恢复屋顶场合, 1、400用冰, 800激活
+
+format text/json                    设置输出格式; json格式下每个结果输出一行JSON
+                                    (格式见doc/json.md; 启动时加--json参数亦可)
+
+lang en/zh                          设置语言(启动时可加--lang参数指定;
+                                    默认按LANG环境变量选择)
 
 ?/help                              显示此帮助
 about                               关于拦截计算器"#;
@@ -222,7 +424,70 @@ about                               关于拦截计算器"#;
 // main.rs
 pub const UNKNOWN_COMMAND: &str = "未知指令. 输入问号查看帮助.";
 pub const ERROR: &str = "出现错误";
-
-// game.rs
-pub const GARG_MIN_WALK_OUT_OF_RANGE: &str = "巨人最短行走时间[{}]超出数据范围({}~{})";
-pub const GARG_MAX_WALK_OUT_OF_RANGE: &str = "巨人最长行走时间[{}]超出数据范围({}~{})";
+pub const NEED_SCRIPT_FILE: &str = "请提供脚本文件: run 脚本文件";
+pub const CANNOT_OPEN_SCRIPT: &str = "无法打开脚本文件";
+pub const UNKNOWN_ARGUMENT: &str = "未知参数";
+
+// session.rs
+pub const SETUP_NOT_FOUND: &str = "未找到该设置";
+pub const CANNOT_READ_STATE: &str = "无法读取状态文件";
+pub const CANNOT_SAVE_STATE: &str = "无法保存状态文件";
+
+// parse_multi, print_multi_report
+pub const NEED_TIMED_COB: &str = "请为每门炮提供生效时机、炮落点行、炮落点列及目标巨人";
+pub const EXPLOSION_TIME_SHOULD_BE_INTEGER: &str = "炮生效时机应为整数";
+pub const GARG_X_RANGE_ONLY_AFTER_LAST_COB: &str = "巨人x范围及u/i只能在最后一门炮之后指定";
+pub const TIMED_COB_GARG_ROWS: &str = "{}时{}炮炸{}路";
+pub const TRAJECTORY: &str = "巨人x={} {}路 rnd={}";
+pub const KILLED_BY: &str = "被第{}门炮于{}拦截";
+pub const NOT_INTERCEPTED: &str = "未被拦截";
+pub const ALL_INTERCEPTED: &str = "所有小鬼均在啃食前被拦截.";
+pub const NOT_ALL_INTERCEPTED: &str = "有小鬼未在啃食前被拦截.";
+
+// parse_trace, print_trace_report
+pub const NEED_GARG_ROW_X_RND: &str = "请提供巨人所在行、巨人x坐标及rnd(0~100)";
+pub const GARG_ROW_SHOULD_BE_INTEGER: &str = "巨人所在行应为整数";
+pub const GARG_ROW_OUT_OF_RANGE: &str = "巨人所在行超出范围{}";
+pub const GARG_X_SHOULD_BE_NUMBER: &str = "巨人x坐标应为数字";
+pub const GARG_X_SHOULD_BE_IN_RANGE: &str = "应满足{}<巨人x坐标≤{}";
+pub const RND_SHOULD_BE_INTEGER: &str = "rnd应为整数";
+pub const RND_SHOULD_BE_IN_RANGE: &str = "应满足0≤rnd≤100";
+pub const UNICED: &str = "原速";
+pub const ICED: &str = "减速";
+pub const TRACE_COB: &str = "{}-{}炮";
+pub const NO_IMP_THROWN: &str = "该巨人不会投掷小鬼.";
+pub const TRACE_HEADER: &str =
+    "时刻  状态            x        y        h    y偏移  判定框(x, y, 宽, 高)";
+pub const TRACE_HIT: &str = "炸到";
+
+// print_land_report
+pub const IMP_LANDING_X: &str = "小鬼落地x坐标";
+pub const IMP_LANDING_TICK: &str = "小鬼落地时刻";
+
+// editor.rs
+pub const ARG_REQUIRED: &str = "{}";
+pub const ARG_OPTIONAL: &str = "({})";
+pub const EXPECTED: &str = "应为{}";
+pub const ARG_ICE_TIMES: &str = "冰时机(i/m)..";
+pub const ARG_COB_TIME: &str = "激活时机(a)";
+pub const ARG_HIT_ROW: &str = "炮行数";
+pub const ARG_HIT_COL: &str = "炮列数";
+pub const ARG_COB_COL: &str = "炮尾列";
+pub const ARG_GARG_ROWS: &str = "巨人所在行";
+pub const ARG_GARG_X_RANGE: &str = "巨人x范围";
+pub const ARG_DOOM_ROW: &str = "核行数";
+pub const ARG_DOOM_COL: &str = "核列数";
+pub const ARG_PLANT_ROW: &str = "植物行数";
+pub const ARG_PLANT_COL: &str = "植物列数";
+pub const ARG_DELAY: &str = "延迟";
+pub const ARG_HIT_COL_RANGE: &str = "炮列数范围";
+pub const ARG_COB_TIME_RANGE: &str = "激活时机范围";
+pub const ARG_DELAY_RANGE: &str = "延迟范围";
+pub const ARG_ICE_TIME_RANGE: &str = "用冰时机范围";
+pub const ARG_IMP_X: &str = "小鬼x坐标(范围)";
+pub const ARG_SETUP_NAME: &str = "名称";
+pub const ARG_SVG_FILE: &str = "文件名";
+pub const ARG_GARG_ROW: &str = "巨人行";
+pub const ARG_GARG_X: &str = "巨人x坐标";
+pub const ARG_RND: &str = "rnd";
+pub const ARG_LAWN_CELLS: &str = "行:列";
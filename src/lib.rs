@@ -1,5 +1,17 @@
+//! PVZ 拦截计算器。
+//!
+//! [`calculator::Calculator`] 提供不做任何打印的计算接口，[`game`] 包含底层的小鬼运动模拟；
+//! [`parser::Parser`] 则是命令行界面所使用的文本指令解析器。
+//! 每条指令先被解析为 [`command`] 中的指令，再由计算器执行得到 [`report`] 中的结果，最后以文本或 [`json`] 格式打印。
+pub mod calculator;
+pub mod command;
 mod constants;
-mod game;
+pub mod editor;
+pub mod game;
+pub mod json;
+pub mod lang;
 pub mod parser;
 mod printer;
-pub mod lang;
+pub mod report;
+pub mod session;
+mod svg;
@@ -1,46 +1,88 @@
-#[cfg(feature = "en")]
-use pvz_interception_calculator::lang::en::*;
+use pvz_interception_calculator::command::OutputFormat;
+use pvz_interception_calculator::editor::EditorHelper;
+use pvz_interception_calculator::parser::{ParseResult, Parser};
+use pvz_interception_calculator::session::Store;
+use std::io::{BufRead, IsTerminal};
+use std::process::ExitCode;
 
-#[cfg(feature = "zh")]
-use pvz_interception_calculator::lang::zh::*;
+use pvz_interception_calculator::lang::*;
 
-fn main() -> rustyline::Result<()> {
-    let mut rustyline = rustyline::DefaultEditor::new()?;
-    let mut parser = pvz_interception_calculator::parser::Parser::default();
+fn main() -> ExitCode {
+    set_current(Lang::from_env());
+    let mut format = OutputFormat::Text;
+    let mut script = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--json" => format = OutputFormat::Json,
+            "--lang" => match args.next().as_deref().and_then(Lang::from_code) {
+                Some(lang) => set_current(lang),
+                None => {
+                    println!("{NEED_LANG}");
+                    return ExitCode::FAILURE;
+                }
+            },
+            "run" => match args.next() {
+                Some(path) => script = Some(path),
+                None => {
+                    println!("{NEED_SCRIPT_FILE}");
+                    return ExitCode::FAILURE;
+                }
+            },
+            _ => {
+                println!("{UNKNOWN_ARGUMENT}: {arg}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    let store = Store::in_config_dir();
+    let mut parser = Parser::new(format, store.clone());
+    match script {
+        Some(path) => match std::fs::File::open(&path) {
+            Ok(file) => run_script(&mut parser, std::io::BufReader::new(file)),
+            Err(err) => {
+                println!("{CANNOT_OPEN_SCRIPT}: {path} ({err})");
+                ExitCode::FAILURE
+            }
+        },
+        None if !std::io::stdin().is_terminal() => run_script(&mut parser, std::io::stdin().lock()),
+        None => run_interactive(&mut parser, store.as_ref()),
+    }
+}
+
+// 交互模式下恢复上次退出时的设置及指令历史，退出时再保存
+fn run_interactive(parser: &mut Parser, store: Option<&Store>) -> ExitCode {
+    let mut rustyline = match rustyline::Editor::<EditorHelper, _>::new() {
+        Ok(rustyline) => rustyline,
+        Err(err) => {
+            println!("{ERROR}: {:?}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    rustyline.set_helper(Some(EditorHelper::new(*parser.calculator().scene())));
+    // JSON 输出时不打印欢迎信息，保证每行均为 JSON
+    if parser.format() == OutputFormat::Text {
+        println!("{HELLO}");
+    }
+    if let Some(store) = store {
+        match store.last() {
+            Ok(None) => {}
+            Ok(Some(setup)) => {
+                parser.restore(&setup);
+                parser.parse(setup.scene.code());
+                parser.parse("wave");
+            }
+            Err(err) => println!("{ERROR}: {err}"),
+        }
+        let _ = rustyline.load_history(&store.history_path()); // 首次运行时没有历史文件
+    }
+    update_helper(&mut rustyline, parser, store);
     loop {
         match rustyline.readline("\n$ ") {
             Ok(line) => {
                 rustyline.add_history_entry(line.as_str()).unwrap();
-                let input = line.trim().to_lowercase();
-                use pvz_interception_calculator::parser::ParseResult;
-                if let ParseResult::Matched = parser.parse_scene(input.as_str()) {
-                    continue;
-                }
-                if let ParseResult::Matched = parser.parse_wave(input.as_str()) {
-                    continue;
-                }
-                if let ParseResult::Matched = parser.parse_delay(input.as_str()) {
-                    continue;
-                }
-                if let ParseResult::Matched = parser.parse_doom(input.as_str()) {
-                    continue;
-                }
-                if let ParseResult::Matched = parser.parse_hit_or_nohit(input.as_str()) {
-                    continue;
-                }
-                if let ParseResult::Matched = parser.parse_find_max_delay(input.as_str()) {
-                    continue;
-                }
-                if let ParseResult::Matched = parser.parse_garg_x_range_of_imp_x(input.as_str()) {
-                    continue;
-                }
-                if let ParseResult::Matched = parser.parse_about(input.as_str()) {
-                    continue;
-                }
-                if let ParseResult::Matched = parser.parse_help(input.as_str()) {
-                    continue;
-                }
-                println!("{UNKNOWN_COMMAND}");
+                parser.parse(line.as_str());
+                update_helper(&mut rustyline, parser, store);
             }
             Err(rustyline::error::ReadlineError::Interrupted)
             | Err(rustyline::error::ReadlineError::Eof) => {
@@ -48,9 +90,65 @@ fn main() -> rustyline::Result<()> {
             }
             Err(err) => {
                 println!("{ERROR}: {:?}", err);
-                break;
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    if let Some(store) = store {
+        // save_last 写入状态文件时已创建配置目录
+        let saved = store.save_last(&parser.setup()).and_then(|_| {
+            rustyline
+                .save_history(&store.history_path())
+                .map_err(|err| format!("{CANNOT_SAVE_STATE}: {err}"))
+        });
+        if let Err(err) = saved {
+            println!("{ERROR}: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+// 补全及提示随场合和已保存的设置变化
+fn update_helper(
+    rustyline: &mut rustyline::Editor<EditorHelper, rustyline::history::DefaultHistory>,
+    parser: &Parser,
+    store: Option<&Store>,
+) {
+    if let Some(helper) = rustyline.helper_mut() {
+        helper.set_scene(*parser.calculator().scene());
+        if let Some(names) = store.and_then(|store| store.names().ok()) {
+            helper.set_setup_names(names);
+        }
+    }
+}
+
+// 逐行执行指令，忽略空行及 "#" 之后的注释；任一行输入有误时返回失败
+fn run_script(parser: &mut Parser, reader: impl BufRead) -> ExitCode {
+    let mut failed = false;
+    for line in reader.lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                println!("{ERROR}: {:?}", err);
+                return ExitCode::FAILURE;
             }
+        };
+        let command = line.split('#').next().unwrap_or_default().trim();
+        if command.is_empty() {
+            continue;
+        }
+        // JSON 输出时不回显指令，保证每行均为 JSON
+        if parser.format() == OutputFormat::Text {
+            println!("\n$ {command}");
         }
+        if let ParseResult::Failed = parser.parse(command) {
+            failed = true;
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
     }
-    Ok(())
 }
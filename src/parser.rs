@@ -1,70 +1,139 @@
-use crate::constants;
+use crate::calculator;
+use crate::command::{
+    AshCommand, DelayCommand, EatCommand, ExplodeCommand, GargPos, HitCommand, HitMode,
+    IceSolveCommand, ImpCommand, KillerCommand, LandCommand, LawnCommand, MaxDelayCommand,
+    MultiCommand, OutputFormat, ScanCommand, SolveCommand, SvgCommand, TimedCob, TraceCommand,
+    WaveCommand,
+};
 use crate::game;
+use crate::json;
+use crate::lang;
 use crate::printer;
+use crate::report::{InputError, LoadReport, Report, Warning};
+use crate::session;
+use crate::svg;
 use dyn_fmt::AsStrFormatExt;
 
-#[cfg(feature = "en")]
-use crate::lang::en::*;
-
-#[cfg(feature = "zh")]
-use crate::lang::zh::*;
-
-const DEFAULT_SCENE: game::Scene = game::Scene::PE;
-const DEFAULT_COB_TIME: i32 = 318;
-const DEFAULT_ROOF_COB_ROW: i32 = 3;
-
-fn validate_garg_x_range(min_max_garg_x: &mut (f32, f32)) -> Result<game::GargXRange, ()> {
-    match game::GargXRange::of_min_max_garg_pos(*min_max_garg_x) {
-        game::GargXRange::Cancelled => {
-            printer::print_warning(GARG_X_RANGE_CANCELLED);
-            Err(())
-        }
-        game::GargXRange::Modified { min, max } => {
-            printer::print_warning(GARG_X_RANGE_MODIFIED.format(&[min, max]).as_str());
-            *min_max_garg_x = (min, max);
-            Ok(game::GargXRange::Modified { min, max })
-        }
-        game::GargXRange::Ok { min, max } => Ok(game::GargXRange::Modified { min, max }),
-    }
-}
-
-struct ParsedGargPos {
-    garg_rows: Vec<i32>,
-    min_max_garg_x: Option<(f32, f32)>,
-    ice_flag: Option<bool>,
-}
+use crate::lang::*;
 
 pub struct Parser {
-    scene: game::Scene,
-    ice_and_cob_times: game::IceAndCobTimes,
-    min_max_garg_x: (f32, f32),
+    calculator: calculator::Calculator,
+    format: OutputFormat,
+    /// 为 `None` 时无法保存、读取设置
+    store: Option<session::Store>,
+    /// 上次 imp 指令算得的巨人x范围，之后可用 "imp" 代替巨人x范围
+    imp_garg_x_range: Option<(f32, f32)>,
+    /// 上次执行的 delay、doom 等指令，供 svg 指令绘图；切换场合时清空
+    last_explode: Option<ExplodeCommand>,
 }
 
 pub enum ParseResult {
     Unmatched,
     Matched,
+    /// 匹配到指令，但输入有误
+    Failed,
+}
+
+type ParseFn = fn(&mut Parser, &str) -> ParseResult;
+
+/// 参数为文件路径或设置名、需保留大小写的指令（`command` 为小写）
+pub fn keeps_arg_case(command: &str) -> bool {
+    matches!(command, "svg" | "save" | "load")
+}
+
+/// 指令名统一转为小写；参数除文件路径及设置名外也转为小写
+pub fn lowercase_command(input: &str) -> String {
+    let input = input.trim_start();
+    let (command, args) = input.split_at(input.find(char::is_whitespace).unwrap_or(input.len()));
+    let command = command.to_lowercase();
+    if keeps_arg_case(&command) {
+        command + args
+    } else {
+        command + &args.to_lowercase()
+    }
 }
 
+// 按顺序尝试各指令，直到匹配为止
+const PARSE_FNS: [ParseFn; 23] = [
+    Parser::parse_scene,
+    Parser::parse_wave,
+    |parser, input| parser.parse_delay(input),
+    |parser, input| parser.parse_ash(input),
+    |parser, input| parser.parse_killer(input),
+    |parser, input| parser.parse_hit_or_nohit(input),
+    |parser, input| parser.parse_find_max_delay(input),
+    |parser, input| parser.parse_scan(input),
+    |parser, input| parser.parse_solve(input),
+    |parser, input| parser.parse_ice_solve(input),
+    |parser, input| parser.parse_multi(input),
+    |parser, input| parser.parse_trace(input),
+    Parser::parse_garg_x_range_of_imp_x,
+    |parser, input| parser.parse_imp_landing(input),
+    Parser::parse_lawn,
+    |parser, input| parser.parse_eat(input),
+    |parser, input| parser.parse_svg(input),
+    Parser::parse_format,
+    |parser, input| parser.parse_lang(input),
+    |parser, input| parser.parse_save(input),
+    Parser::parse_load,
+    |parser, input| parser.parse_about(input),
+    |parser, input| parser.parse_help(input),
+];
+
 impl Default for Parser {
     fn default() -> Self {
-        println!("{}", HELLO);
-        let scene = DEFAULT_SCENE;
-        let ice_and_cob_times =
-            game::IceAndCobTimes::of_ice_times_and_cob_time(&[], DEFAULT_COB_TIME).unwrap();
-        let min_max_garg_x = game::min_max_garg_x(&ice_and_cob_times).unwrap();
-        Parser {
-            scene,
-            ice_and_cob_times,
-            min_max_garg_x,
-        }
+        Parser::new(OutputFormat::Text, None)
     }
 }
 
 impl Parser {
+    pub fn new(format: OutputFormat, store: Option<session::Store>) -> Self {
+        Parser {
+            calculator: calculator::Calculator::default(),
+            format,
+            store,
+            imp_garg_x_range: None,
+            last_explode: None,
+        }
+    }
+
+    pub fn calculator(&self) -> &calculator::Calculator {
+        &self.calculator
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// 当前的场合、用冰及激活时机
+    pub fn setup(&self) -> session::Setup {
+        session::Setup {
+            scene: *self.calculator.scene(),
+            ice_and_cob_times: self.calculator.ice_and_cob_times().clone(),
+        }
+    }
+
+    /// 恢复设置
+    pub fn restore(&mut self, setup: &session::Setup) {
+        self.calculator = calculator::Calculator::new(setup.scene, setup.ice_and_cob_times.clone());
+        self.last_explode = None;
+    }
+
+    /// 解析并执行一行指令，未知指令视为输入有误
+    pub fn parse(&mut self, input: &str) -> ParseResult {
+        let input = lowercase_command(input.trim());
+        for parse_fn in PARSE_FNS {
+            match parse_fn(self, input.as_str()) {
+                ParseResult::Unmatched => continue,
+                result => return result,
+            }
+        }
+        self.output(Err(InputError::UnknownCommand))
+    }
+
     pub fn parse_help(&self, input: &str) -> ParseResult {
         if input == "help" || input == "?" || input == "？" {
-            println!("{}", HELP);
-            ParseResult::Matched
+            self.output(Ok(Report::Help))
         } else {
             ParseResult::Unmatched
         }
@@ -72,81 +141,37 @@ impl Parser {
 
     pub fn parse_about(&self, input: &str) -> ParseResult {
         if input == "about" {
-            println!("{}", ABOUT);
-            ParseResult::Matched
+            self.output(Ok(Report::About))
         } else {
             ParseResult::Unmatched
         }
     }
 
     pub fn parse_scene(&mut self, input: &str) -> ParseResult {
-        match input {
-            "de" | "ne" => {
-                self.scene = game::Scene::DE;
-                println!("{SET_FRONTYARD}");
-                ParseResult::Matched
-            }
-            "pe" | "fe" => {
-                self.scene = game::Scene::PE;
-                println!("{SET_BACKYARD}");
-                ParseResult::Matched
-            }
-            "re" | "me" => {
-                self.scene = game::Scene::RE;
-                println!("{SET_ROOF}");
-                ParseResult::Matched
-            }
-            _ => ParseResult::Unmatched,
-        }
+        let scene = match input {
+            "de" | "ne" | "pe" | "fe" | "re" | "me" => game::Scene::from_code(input).unwrap(),
+            _ => return ParseResult::Unmatched,
+        };
+        self.calculator.set_scene(scene);
+        self.last_explode = None;
+        self.output(Ok(Report::Scene(scene)))
     }
 
     pub fn parse_wave(&mut self, input: &str) -> ParseResult {
         match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
             ["wave", extra_args @ ..] => {
-                match extra_args {
-                    [] => {
-                        printer::print_ice_times_and_cob_time(
-                            &self.ice_and_cob_times,
-                            self.min_max_garg_x,
-                            false,
-                        );
-                    }
-                    [ice_times @ .., cob_time] => {
-                        let (Ok(ice_times), Ok(cob_time)) = (
-                            Parser::parse_ice_times(ice_times),
-                            Parser::parse_cob_time(cob_time),
-                        ) else {
-                            return ParseResult::Matched;
-                        };
-                        match game::IceAndCobTimes::of_ice_times_and_cob_time(&ice_times, cob_time)
-                        {
-                            Err(err) => {
-                                printer::print_error(err.as_str());
-                            }
-                            Ok(ice_and_cob_times) => {
-                                match game::min_max_garg_x(&ice_and_cob_times) {
-                                    Err(err) => printer::print_error(err.as_str()),
-                                    Ok((min_x, max_x)) => {
-                                        self.ice_and_cob_times = ice_and_cob_times;
-                                        self.min_max_garg_x = (min_x, max_x);
-                                        printer::print_ice_times_and_cob_time(
-                                            &self.ice_and_cob_times,
-                                            self.min_max_garg_x,
-                                            false,
-                                        );
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-                ParseResult::Matched
+                let report = Parser::wave_command(extra_args).and_then(|command| {
+                    self.calculator
+                        .run_wave(&command)
+                        .map_err(InputError::Message)
+                });
+                self.output(report.map(Report::Wave))
             }
             _ => ParseResult::Unmatched,
         }
     }
 
-    pub fn parse_delay(&self, input: &str) -> ParseResult {
+    pub fn parse_delay(&mut self, input: &str) -> ParseResult {
         match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
             [command, extra_args @ ..] => {
                 let delay_mode = match *command {
@@ -158,285 +183,40 @@ impl Parser {
                         return ParseResult::Unmatched;
                     }
                 };
-                let (cob_and_garg_rows, mut min_max_garg_x, ice_flag, explode_to_print): (
-                    Vec<(game::Cob, Vec<i32>)>,
-                    _,
-                    _,
-                    _,
-                ) =
-                    if !self.scene.is_roof() {
-                        match extra_args {
-                            [">", ..] if *command == "delay" => {
-                                printer::print_error(NEED_HIT_ROW_HIT_COL);
-                                return ParseResult::Matched;
-                            }
-                            [_, ">", ..] if *command == "delay" => {
-                                printer::print_error(NEED_HIT_COL);
-                                return ParseResult::Matched;
-                            }
-                            [hit_row, hit_col, ">", garg_pos_args @ ..] if *command == "delay" => {
-                                let (Ok(hit_row), Ok(hit_col)) = (
-                                    Parser::parse_hit_row(hit_row, &self.scene.all_rows()),
-                                    Parser::parse_hit_col(hit_col),
-                                ) else {
-                                    return ParseResult::Matched;
-                                };
-                                let Ok(ParsedGargPos {
-                                    garg_rows,
-                                    min_max_garg_x,
-                                    ice_flag,
-                                }) = Parser::parse_garg_pos(
-                                    garg_pos_args,
-                                    &self.scene.garg_rows_for_cob(hit_row),
-                                )
-                                else {
-                                    return ParseResult::Matched;
-                                };
-                                let cob = game::Cob::Ground {
-                                    row: hit_row,
-                                    col: hit_col,
-                                };
-                                (
-                                    vec![(cob.clone(), garg_rows)],
-                                    min_max_garg_x.unwrap_or(self.min_max_garg_x),
-                                    ice_flag.unwrap_or(self.ice_and_cob_times.is_iced()),
-                                    Some(game::Explode::of_cob(&cob, &self.scene)),
-                                )
-                            }
-                            [] => {
-                                printer::print_error(NEED_HIT_COL);
-                                return ParseResult::Matched;
-                            }
-                            [hit_col] => {
-                                let Ok(hit_col) = Parser::parse_hit_col(hit_col) else {
-                                    return ParseResult::Matched;
-                                };
-                                (
-                                    self.scene
-                                        .hit_row_and_garg_rows_of_delay_mode(&delay_mode.unwrap_or(
-                                            self.scene.default_delay_mode(hit_col, None),
-                                        ))
-                                        .iter()
-                                        .map(|(hit_row, garg_rows)| {
-                                            (
-                                                game::Cob::Ground {
-                                                    row: *hit_row,
-                                                    col: hit_col,
-                                                },
-                                                garg_rows.clone(),
-                                            )
-                                        })
-                                        .collect(),
-                                    self.min_max_garg_x,
-                                    self.ice_and_cob_times.is_iced(),
-                                    None,
-                                )
-                            }
-                            _ => {
-                                printer::print_too_many_arguments_error();
-                                return ParseResult::Matched;
-                            }
-                        }
-                    } else {
-                        match extra_args {
-                            [">", ..] if *command == "delay" => {
-                                printer::print_error(NEED_HIT_ROW_HIT_COL_COB_COL);
-                                return ParseResult::Matched;
-                            }
-                            [_, ">", ..] if *command == "delay" => {
-                                printer::print_error(NEED_HIT_COL_COB_COL);
-                                return ParseResult::Matched;
-                            }
-                            [_, _, ">", ..] if *command == "delay" => {
-                                printer::print_error(NEED_COB_COL);
-                                return ParseResult::Matched;
-                            }
-                            [hit_row, hit_col, cob_col, ">", garg_pos_args @ ..]
-                                if *command == "delay" =>
-                            {
-                                let (Ok(hit_row), Ok(hit_col), Ok(cob_col)) = (
-                                    Parser::parse_hit_row(hit_row, &self.scene.all_rows()),
-                                    Parser::parse_hit_col(hit_col),
-                                    Parser::parse_cob_col(cob_col),
-                                ) else {
-                                    return ParseResult::Matched;
-                                };
-                                let Ok(ParsedGargPos {
-                                    garg_rows,
-                                    min_max_garg_x,
-                                    ice_flag,
-                                }) = Parser::parse_garg_pos(
-                                    garg_pos_args,
-                                    &self.scene.garg_rows_for_cob(hit_row),
-                                )
-                                else {
-                                    return ParseResult::Matched;
-                                };
-                                let cob = game::Cob::Roof {
-                                    row: hit_row,
-                                    col: hit_col,
-                                    cob_col,
-                                    cob_row: DEFAULT_ROOF_COB_ROW,
-                                };
-                                (
-                                    vec![(cob.clone(), garg_rows)],
-                                    min_max_garg_x.unwrap_or(self.min_max_garg_x),
-                                    ice_flag.unwrap_or(self.ice_and_cob_times.is_iced()),
-                                    Some(game::Explode::of_cob(&cob, &self.scene)),
-                                )
-                            }
-                            [] => {
-                                printer::print_error(NEED_HIT_COL_COB_COL);
-                                return ParseResult::Matched;
-                            }
-                            [_] => {
-                                printer::print_error(NEED_COB_COL);
-                                return ParseResult::Matched;
-                            }
-                            [hit_col, cob_col] => {
-                                let (Ok(hit_col), Ok(cob_col)) = (
-                                    Parser::parse_hit_col(hit_col),
-                                    Parser::parse_cob_col(cob_col),
-                                ) else {
-                                    return ParseResult::Matched;
-                                };
-                                (
-                                    self.scene
-                                        .hit_row_and_garg_rows_of_delay_mode(&delay_mode.unwrap_or(
-                                            self.scene.default_delay_mode(hit_col, Some(cob_col)),
-                                        ))
-                                        .iter()
-                                        .map(|(hit_row, garg_rows)| {
-                                            (
-                                                game::Cob::Roof {
-                                                    row: *hit_row,
-                                                    col: hit_col,
-                                                    cob_col,
-                                                    cob_row: DEFAULT_ROOF_COB_ROW,
-                                                },
-                                                garg_rows.clone(),
-                                            )
-                                        })
-                                        .collect(),
-                                    self.min_max_garg_x,
-                                    self.ice_and_cob_times.is_iced(),
-                                    None,
-                                )
-                            }
-                            _ => {
-                                printer::print_too_many_arguments_error();
-                                return ParseResult::Matched;
-                            }
-                        }
-                    };
-                let Ok(garg_x_range) = validate_garg_x_range(&mut min_max_garg_x) else {
-                    return ParseResult::Matched;
-                };
-                let explode_and_garg_rows: Vec<(game::Explode, &Vec<i32>)> = cob_and_garg_rows
-                    .iter()
-                    .map(|(cob, garg_rows)| (game::Explode::of_cob(cob, &self.scene), garg_rows))
-                    .collect();
-                let (eat, intercept) =
-                    game::judge(&garg_x_range, &explode_and_garg_rows, ice_flag, &self.scene);
-                printer::print_cob_calc_setting(
-                    &cob_and_garg_rows,
-                    explode_to_print,
-                    if (min_max_garg_x) != self.min_max_garg_x {
-                        Some(min_max_garg_x)
-                    } else {
-                        None
-                    },
-                    None,
-                );
-                printer::print_eat_and_intercept(&eat, &intercept);
-                ParseResult::Matched
+                let command = self.delay_command(delay_mode, extra_args);
+                if let Ok(command) = &command {
+                    self.last_explode = Some(ExplodeCommand::Delay(command.clone()));
+                }
+                let report =
+                    command.map(|command| Report::Delay(self.calculator.run_delay(&command)));
+                self.output(report)
             }
             _ => ParseResult::Unmatched,
         }
     }
 
-    pub fn parse_doom(&self, input: &str) -> ParseResult {
+    pub fn parse_ash(&mut self, input: &str) -> ParseResult {
         match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
-            ["doom", extra_args @ ..] => match extra_args {
-                [] => {
-                    printer::print_error(NEED_DOOM_ROW_DOOM_COL);
-                    ParseResult::Matched
-                }
-                [_] => {
-                    printer::print_error(NEED_DOOM_ROW);
-                    ParseResult::Matched
+            [name @ ("doom" | "cherry" | "jalapeno"), extra_args @ ..] => {
+                let command = self.ash_command(name, extra_args);
+                if let Ok(command) = &command {
+                    self.last_explode = Some(ExplodeCommand::Ash(command.clone()));
                 }
-                [doom_row, doom_col, garg_pos_args @ ..] => {
-                    let (Ok(doom_row), Ok(doom_col)) = (
-                        Parser::parse_doom_row(doom_row, &self.scene.all_rows()),
-                        Parser::parse_doom_col(doom_col),
-                    ) else {
-                        return ParseResult::Matched;
-                    };
-                    let explode = game::Explode::of_doom(
-                        &game::Doom {
-                            row: doom_row,
-                            col: doom_col,
-                        },
-                        &self.scene,
-                    );
-                    let (garg_rows, mut min_max_garg_x, ice_flag, explode_to_print) =
-                        match garg_pos_args {
-                            [] => (
-                                self.scene.garg_rows_for_doom(doom_row),
-                                self.min_max_garg_x,
-                                self.ice_and_cob_times.is_iced(),
-                                None,
-                            ),
-                            [">", garg_pos_args @ ..] => {
-                                let Ok(ParsedGargPos {
-                                    garg_rows,
-                                    min_max_garg_x,
-                                    ice_flag,
-                                }) = Parser::parse_garg_pos(
-                                    garg_pos_args,
-                                    &self.scene.garg_rows_for_doom(doom_row),
-                                )
-                                else {
-                                    return ParseResult::Matched;
-                                };
-                                (
-                                    garg_rows,
-                                    min_max_garg_x.unwrap_or(self.min_max_garg_x),
-                                    ice_flag.unwrap_or(self.ice_and_cob_times.is_iced()),
-                                    Some(&explode),
-                                )
-                            }
-                            _ => {
-                                printer::print_too_many_arguments_error();
-                                return ParseResult::Matched;
-                            }
-                        };
-                    let Ok(garg_x_range) = validate_garg_x_range(&mut min_max_garg_x) else {
-                        return ParseResult::Matched;
-                    };
-                    let (mut eat, mut intercept) = game::judge(
-                        &garg_x_range,
-                        &[(explode.clone(), &garg_rows)],
-                        ice_flag,
-                        &self.scene,
-                    );
-                    eat.shift_to_plant_intercept();
-                    intercept.shift_to_plant_intercept();
-                    printer::print_doom_calc_setting(
-                        doom_row,
-                        &garg_rows,
-                        explode_to_print,
-                        if min_max_garg_x != self.min_max_garg_x {
-                            Some(min_max_garg_x)
-                        } else {
-                            None
-                        },
-                    );
-                    printer::print_eat_and_intercept(&eat, &intercept);
-                    ParseResult::Matched
-                }
-            },
+                let report = command.map(|command| Report::Ash(self.calculator.run_ash(&command)));
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_killer(&self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            [name @ ("squash" | "chomper"), extra_args @ ..] => {
+                let report = self
+                    .killer_command(name, extra_args)
+                    .map(|command| Report::Killer(self.calculator.run_killer(&command)));
+                self.output(report)
+            }
             _ => ParseResult::Unmatched,
         }
     }
@@ -444,104 +224,17 @@ impl Parser {
     pub fn parse_hit_or_nohit(&self, input: &str) -> ParseResult {
         match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
             ["hit", extra_args @ ..] | ["nohit", extra_args @ ..] => {
-                let (min_max_garg_x, cob_dist) = if !self.scene.is_roof() {
-                    match extra_args {
-                        [] => (self.min_max_garg_x, self.scene.cob_dist(None)),
-                        [delay_time] => {
-                            let Ok(delay_time) = Parser::parse_delay_time(delay_time) else {
-                                return ParseResult::Matched;
-                            };
-                            match game::IceAndCobTimes::of_ice_times_and_cob_time(
-                                &self.ice_and_cob_times.ice_times,
-                                self.ice_and_cob_times.cob_time + delay_time,
-                            ) {
-                                Err(err) => {
-                                    printer::print_error(err.as_str());
-                                    return ParseResult::Matched;
-                                }
-                                Ok(ice_and_cob_times) => {
-                                    match game::min_max_garg_x(&ice_and_cob_times) {
-                                        Err(err) => {
-                                            printer::print_error(err.as_str());
-                                            return ParseResult::Matched;
-                                        }
-                                        Ok((min_garg_x, max_garg_x)) => {
-                                            printer::print_ice_times_and_cob_time(
-                                                &ice_and_cob_times,
-                                                (min_garg_x, max_garg_x),
-                                                true,
-                                            );
-                                            ((min_garg_x, max_garg_x), self.scene.cob_dist(None))
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        _ => {
-                            printer::print_too_many_arguments_error();
-                            return ParseResult::Matched;
-                        }
-                    }
+                let mode = if input.starts_with("hit") {
+                    HitMode::Hit
                 } else {
-                    match extra_args {
-                        [] => {
-                            printer::print_error(NEED_COB_COL);
-                            return ParseResult::Matched;
-                        }
-                        [cob_col] => {
-                            let Ok(cob_col) = Parser::parse_cob_col(cob_col) else {
-                                return ParseResult::Matched;
-                            };
-                            (self.min_max_garg_x, self.scene.cob_dist(Some(cob_col)))
-                        }
-                        [cob_col, delay_time] => {
-                            let (Ok(delay_time), Ok(cob_col)) = (
-                                Parser::parse_delay_time(delay_time),
-                                Parser::parse_cob_col(cob_col),
-                            ) else {
-                                return ParseResult::Matched;
-                            };
-                            match game::IceAndCobTimes::of_ice_times_and_cob_time(
-                                &self.ice_and_cob_times.ice_times,
-                                self.ice_and_cob_times.cob_time + delay_time,
-                            ) {
-                                Err(err) => {
-                                    printer::print_error(err.as_str());
-                                    return ParseResult::Matched;
-                                }
-                                Ok(ice_and_cob_times) => {
-                                    match game::min_max_garg_x(&ice_and_cob_times) {
-                                        Err(err) => {
-                                            printer::print_error(err.as_str());
-                                            return ParseResult::Matched;
-                                        }
-                                        Ok((min_garg_x, max_garg_x)) => {
-                                            printer::print_ice_times_and_cob_time(
-                                                &ice_and_cob_times,
-                                                (min_garg_x, max_garg_x),
-                                                true,
-                                            );
-                                            (
-                                                (min_garg_x, max_garg_x),
-                                                self.scene.cob_dist(Some(cob_col)),
-                                            )
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                        _ => {
-                            printer::print_too_many_arguments_error();
-                            return ParseResult::Matched;
-                        }
-                    }
+                    HitMode::Nohit
                 };
-                if input.starts_with("hit") {
-                    printer::print_hit_cob_dist(&self.scene, min_max_garg_x.1 as i32, cob_dist)
-                } else if input.starts_with("nohit") {
-                    printer::print_nohit_cob_dist(&self.scene, min_max_garg_x.0 as i32, cob_dist);
-                };
-                ParseResult::Matched
+                let report = self.hit_command(mode, extra_args).and_then(|command| {
+                    self.calculator
+                        .run_hit(&command)
+                        .map_err(InputError::Message)
+                });
+                self.output(report.map(Report::Hit))
             }
             _ => ParseResult::Unmatched,
         }
@@ -550,524 +243,1593 @@ impl Parser {
     pub fn parse_find_max_delay(&self, input: &str) -> ParseResult {
         match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
             ["max", extra_args @ ..] => {
-                let (cob_list, garg_rows, mut min_max_garg_x, ice_flag) = if !self.scene.is_roof() {
-                    match extra_args {
-                        [] | [">", ..] => {
-                            printer::print_error(NEED_HIT_ROW_HIT_COL_RANGE);
-                            return ParseResult::Matched;
-                        }
-                        [_] | [_, ">", ..] => {
-                            printer::print_error(NEED_HIT_COL_RANGE);
-                            return ParseResult::Matched;
-                        }
-                        [hit_row, min_max_hit_col, ">", garg_pos_args @ ..] => {
-                            let (Ok(hit_row), Ok((min_hit_col, max_hit_col))) = (
-                                Parser::parse_hit_row(hit_row, &self.scene.all_rows()),
-                                Parser::parse_min_max_hit_col(min_max_hit_col),
-                            ) else {
-                                return ParseResult::Matched;
-                            };
-                            let Ok(ParsedGargPos {
-                                garg_rows,
-                                min_max_garg_x,
-                                ice_flag,
-                            }) = Parser::parse_garg_pos(
-                                garg_pos_args,
-                                &self.scene.garg_rows_for_cob(hit_row),
-                            )
-                            else {
-                                return ParseResult::Matched;
-                            };
-                            (
-                                ((min_hit_col * 80.).round() as i32
-                                    ..=(max_hit_col * 80.).round() as i32)
-                                    .map(|v| game::Cob::Ground {
-                                        row: hit_row,
-                                        col: v as f32 / 80.,
-                                    })
-                                    .collect::<Vec<game::Cob>>(),
-                                garg_rows,
-                                min_max_garg_x.unwrap_or(self.min_max_garg_x),
-                                ice_flag.unwrap_or(self.ice_and_cob_times.is_iced()),
-                            )
-                        }
-                        _ => {
-                            printer::print_bad_format_error();
-                            return ParseResult::Matched;
-                        }
-                    }
-                } else {
-                    match extra_args {
-                        [] | [">", ..] => {
-                            printer::print_error(NEED_HIT_ROW_HIT_COL_RANGE_COB_COL);
-                            return ParseResult::Matched;
-                        }
-                        [_] | [_, ">", ..] => {
-                            printer::print_error(NEED_HIT_COL_RANGE_COB_COL);
-                            return ParseResult::Matched;
-                        }
-                        [_, _] | [_, _, ">", ..] => {
-                            printer::print_error(NEED_COB_COL);
-                            return ParseResult::Matched;
-                        }
-                        [hit_row, min_max_hit_col, cob_col, ">", garg_pos_args @ ..] => {
-                            let (Ok(hit_row), Ok((min_hit_col, max_hit_col)), Ok(cob_col)) = (
-                                Parser::parse_hit_row(hit_row, &self.scene.all_rows()),
-                                Parser::parse_min_max_hit_col(min_max_hit_col),
-                                Parser::parse_cob_col(cob_col),
-                            ) else {
-                                return ParseResult::Matched;
-                            };
-                            let Ok(ParsedGargPos {
-                                garg_rows,
-                                min_max_garg_x,
-                                ice_flag,
-                            }) = Parser::parse_garg_pos(
-                                garg_pos_args,
-                                &self.scene.garg_rows_for_cob(hit_row),
-                            )
-                            else {
-                                return ParseResult::Matched;
-                            };
-                            (
-                                ((min_hit_col * 80.).round() as i32
-                                    ..=(max_hit_col * 80.).round() as i32)
-                                    .map(|v| game::Cob::Roof {
-                                        row: hit_row,
-                                        col: v as f32 / 80.,
-                                        cob_col,
-                                        cob_row: DEFAULT_ROOF_COB_ROW,
-                                    })
-                                    .collect::<Vec<game::Cob>>(),
-                                garg_rows,
-                                min_max_garg_x.unwrap_or(self.min_max_garg_x),
-                                ice_flag.unwrap_or(self.ice_and_cob_times.is_iced()),
-                            )
-                        }
-                        _ => {
-                            printer::print_bad_format_error();
-                            return ParseResult::Matched;
-                        }
-                    }
-                };
-                if cob_list.is_empty() {
-                    return ParseResult::Matched;
-                }
-                let Ok(garg_x_range) = validate_garg_x_range(&mut min_max_garg_x) else {
-                    return ParseResult::Matched;
-                };
-                let mut max_delay: Option<i32> = None;
-                let mut cob_cols: Vec<f32> = vec![];
-                let mut eat = game::Eat::Empty;
-                let mut intercept = game::Intercept::Empty;
-                for cob in &cob_list {
-                    let (new_eat, new_intercept) = game::judge(
-                        &garg_x_range,
-                        &[(game::Explode::of_cob(cob, &self.scene), &garg_rows)],
-                        ice_flag,
-                        &self.scene,
-                    );
-                    match (
-                        max_delay,
-                        game::safe_intercept_interval(&new_eat, &new_intercept),
-                    ) {
-                        (_, None) => {}
-                        (None, Some((_, new_max))) => {
-                            max_delay = Some(new_max);
-                            cob_cols = vec![cob.col()];
-                            eat = new_eat;
-                            intercept = new_intercept;
-                        }
-                        (Some(prev_max), Some((_, new_max))) => match new_max.cmp(&prev_max) {
-                            std::cmp::Ordering::Greater => {
-                                max_delay = Some(new_max);
-                                cob_cols = vec![cob.col()];
-                                eat = new_eat;
-                                intercept = new_intercept;
-                            }
-                            _ => {
-                                cob_cols.push(cob.col());
-                            }
-                        },
-                    };
+                let report = self
+                    .max_delay_command(extra_args)
+                    .map(|command| Report::MaxDelay(self.calculator.run_max_delay(&command)));
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_scan(&self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["scan", extra_args @ ..] => {
+                let report = self
+                    .scan_command(extra_args)
+                    .map(|command| Report::Scan(self.calculator.run_scan(&command)));
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_solve(&self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["solve", extra_args @ ..] => {
+                let report = self.solve_command(extra_args).and_then(|command| {
+                    self.calculator
+                        .run_solve(&command)
+                        .map(Report::Solve)
+                        .map_err(InputError::Message)
+                });
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_ice_solve(&self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["icesolve", extra_args @ ..] => {
+                let report = self.ice_solve_command(extra_args).and_then(|command| {
+                    self.calculator
+                        .run_ice_solve(&command)
+                        .map(Report::IceSolve)
+                        .map_err(InputError::Message)
+                });
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_multi(&self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["multi", ..] => {
+                let report = self
+                    .multi_command(&input["multi".len()..])
+                    .map(|command| Report::Multi(self.calculator.run_multi(&command)));
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_trace(&self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["trace", extra_args @ ..] => {
+                let report = self
+                    .trace_command(extra_args)
+                    .map(|command| Report::Trace(self.calculator.run_trace(&command)));
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_imp_landing(&self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["land", extra_args @ ..] => {
+                let report = self
+                    .land_command(extra_args)
+                    .map(|command| Report::Land(self.calculator.run_land(&command)));
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_lawn(&mut self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["lawn", extra_args @ ..] => {
+                let report = self
+                    .lawn_command(extra_args)
+                    .map(|command| Report::Lawn(self.calculator.run_lawn(&command)));
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_eat(&self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["eat", extra_args @ ..] => {
+                let report = self
+                    .eat_command(extra_args)
+                    .map(|command| Report::Eat(self.calculator.run_eat(&command)));
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_garg_x_range_of_imp_x(&mut self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["imp", extra_args @ ..] => {
+                let report = Parser::imp_command(extra_args).and_then(|command| {
+                    self.calculator
+                        .run_imp(&command)
+                        .map_err(InputError::Message)
+                });
+                if let Ok(report) = &report {
+                    self.imp_garg_x_range = Some(report.min_max_garg_x);
                 }
-                printer::print_cob_calc_setting(
-                    &[(cob_list[0].clone(), garg_rows)],
-                    None,
-                    if min_max_garg_x != self.min_max_garg_x {
-                        Some(min_max_garg_x)
-                    } else {
-                        None
-                    },
-                    Some((cob_list[0].col(), cob_list.last().unwrap().col())),
-                );
-                match cob_cols.as_mut_slice() {
-                    [] => {
-                        println!("{CANNOT_INTERCEPT_WITHOUT_HARM}");
-                    }
-                    cob_cols => {
-                        cob_cols.sort_by(|a, b| a.partial_cmp(b).unwrap());
-                        println!(
-                            "{HIT_COL_WITH_MAX_DELAY}: {}",
-                            COL.format(&[format!("{:?}", cob_cols)]),
-                        );
-                        printer::print_eat_and_intercept(&eat, &intercept);
+                self.output(report.map(Report::Imp))
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_svg(&self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["svg", extra_args @ ..] => {
+                let report = self.svg_command(extra_args).and_then(|command| {
+                    let report = self.calculator.run_svg(&command);
+                    // 巨人不会投掷小鬼时跳过计算，不写入图片
+                    if !report.warnings.contains(&Warning::GargXRangeCancelled) {
+                        svg::save(&report).map_err(InputError::Message)?;
                     }
-                };
-                ParseResult::Matched
+                    Ok(Report::Svg(report))
+                });
+                self.output(report)
             }
             _ => ParseResult::Unmatched,
         }
     }
 
-    pub fn parse_garg_x_range_of_imp_x(&self, input: &str) -> ParseResult {
+    pub fn parse_format(&mut self, input: &str) -> ParseResult {
         match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
-            ["imp", extra_args @ ..] => match extra_args {
-                [] => {
-                    printer::print_error(NEED_IMP_X_RANGE);
-                    ParseResult::Matched
+            ["format", extra_args @ ..] => {
+                let report = Parser::format_command(extra_args).map(|format| {
+                    self.format = format;
+                    Report::Format(format)
+                });
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_lang(&self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["lang", extra_args @ ..] => {
+                let report = Parser::lang_command(extra_args).map(|lang| {
+                    lang::set_current(lang);
+                    Report::Lang(lang)
+                });
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_save(&self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["save", extra_args @ ..] => {
+                let report = self.setup_name(extra_args).and_then(|(store, name)| {
+                    store
+                        .save(name, &self.setup())
+                        .map(|_| Report::Save(name.to_string()))
+                        .map_err(InputError::Message)
+                });
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    pub fn parse_load(&mut self, input: &str) -> ParseResult {
+        match input.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            ["load", extra_args @ ..] => {
+                let setup = self.setup_name(extra_args).and_then(|(store, name)| {
+                    store
+                        .load(name)
+                        .map(|setup| (name, setup))
+                        .map_err(InputError::Message)
+                });
+                let report = setup.and_then(|(name, setup)| {
+                    self.restore(&setup);
+                    let wave = self
+                        .calculator
+                        .run_wave(&WaveCommand::Show)
+                        .map_err(InputError::Message)?;
+                    Ok(Report::Load(LoadReport {
+                        name: name.to_string(),
+                        scene: setup.scene,
+                        wave,
+                    }))
+                });
+                self.output(report)
+            }
+            _ => ParseResult::Unmatched,
+        }
+    }
+
+    fn output(&self, report: Result<Report, InputError>) -> ParseResult {
+        match report {
+            Ok(report) => {
+                match self.format {
+                    OutputFormat::Text => printer::print_report(&report),
+                    OutputFormat::Json => {
+                        json::print_report(&report, self.calculator.min_max_garg_x())
+                    }
                 }
-                [imp_x] => {
-                    let Ok(imp_x) = imp_x.parse::<i32>() else {
-                        printer::print_error_with_input(IMP_X_SHOULD_BE_INTEGER, imp_x);
-                        return ParseResult::Matched;
-                    };
-                    let Some((min_garg_x, max_garg_x)) =
-                        constants::min_max_garg_pos_of_imp_x(imp_x)
-                    else {
-                        printer::print_error_with_input(
-                            &IMP_X_SHOULD_BE_IN_RANGE
-                                .format(&[constants::MIN_IMP_X, constants::MAX_IMP_X]),
-                            imp_x.to_string().as_str(),
-                        );
-                        return ParseResult::Matched;
-                    };
-                    println!("{GARG_X_RANGE}: {:.3}~{:.3}", min_garg_x, max_garg_x);
-                    ParseResult::Matched
+                ParseResult::Matched
+            }
+            Err(error) => {
+                match self.format {
+                    OutputFormat::Text => printer::print_input_error(&error),
+                    OutputFormat::Json => json::print_input_error(&error),
+                }
+                ParseResult::Failed
+            }
+        }
+    }
+
+    fn scene(&self) -> &game::Scene {
+        self.calculator.scene()
+    }
+
+    fn svg_command(&self, extra_args: &[&str]) -> Result<SvgCommand, InputError> {
+        match extra_args {
+            [] => Err(InputError::Message(NEED_SVG_FILE.to_string())),
+            [path] => {
+                let explode = self
+                    .last_explode
+                    .clone()
+                    .ok_or_else(|| InputError::Message(NEED_EXPLOSION_BEFORE_SVG.to_string()))?;
+                Ok(SvgCommand {
+                    path: path.to_string(),
+                    explode,
+                })
+            }
+            _ => Err(InputError::TooManyArguments),
+        }
+    }
+
+    fn format_command(extra_args: &[&str]) -> Result<OutputFormat, InputError> {
+        match extra_args {
+            [] => Err(InputError::Message(NEED_FORMAT.to_string())),
+            ["text"] => Ok(OutputFormat::Text),
+            ["json"] => Ok(OutputFormat::Json),
+            [format] => Err(InputError::with_input(
+                &FORMAT_SHOULD_BE_TEXT_OR_JSON,
+                format,
+            )),
+            _ => Err(InputError::TooManyArguments),
+        }
+    }
+
+    fn lang_command(extra_args: &[&str]) -> Result<lang::Lang, InputError> {
+        match extra_args {
+            [] => Err(InputError::Message(NEED_LANG.to_string())),
+            [code] => lang::Lang::from_code(code)
+                .ok_or_else(|| InputError::with_input(&LANG_SHOULD_BE_EN_OR_ZH, code)),
+            _ => Err(InputError::TooManyArguments),
+        }
+    }
+
+    fn setup_name<'a>(
+        &self,
+        extra_args: &[&'a str],
+    ) -> Result<(&session::Store, &'a str), InputError> {
+        let store = self
+            .store
+            .as_ref()
+            .ok_or_else(|| InputError::Message(NO_CONFIG_DIR.to_string()))?;
+        match extra_args {
+            [] => Err(InputError::Message(NEED_SETUP_NAME.to_string())),
+            [name] => Ok((store, name)),
+            _ => Err(InputError::TooManyArguments),
+        }
+    }
+
+    fn wave_command(extra_args: &[&str]) -> Result<WaveCommand, InputError> {
+        match extra_args {
+            [] => Ok(WaveCommand::Show),
+            [ice_times @ .., cob_time] => {
+                let (ice_times, slow_times) = Parser::parse_ice_times(ice_times)?;
+                let (cob_time, activation) = Parser::parse_cob_time(cob_time)?;
+                Ok(WaveCommand::Set {
+                    ice_times,
+                    slow_times,
+                    cob_time,
+                    activation,
+                })
+            }
+        }
+    }
+
+    fn delay_command(
+        &self,
+        delay_mode: Option<game::DelayMode>,
+        extra_args: &[&str],
+    ) -> Result<DelayCommand, InputError> {
+        let specific = delay_mode.is_none();
+        if !self.scene().is_roof() {
+            match extra_args {
+                [">", ..] if specific => Err(InputError::Message(NEED_HIT_ROW_HIT_COL.to_string())),
+                [_, ">", ..] if specific => Err(InputError::Message(NEED_HIT_COL.to_string())),
+                [hit_row, hit_col, ">", garg_pos_args @ ..] if specific => {
+                    let hit_row = Parser::parse_hit_row(hit_row, &self.scene().all_rows())?;
+                    let hit_col = Parser::parse_hit_col(hit_col)?;
+                    let garg_pos = self
+                        .parse_garg_pos(garg_pos_args, &self.scene().garg_rows_for_cob(hit_row))?;
+                    Ok(DelayCommand::Specific {
+                        cob: game::Cob::ground(hit_row, hit_col),
+                        garg_pos,
+                    })
                 }
-                _ => {
-                    printer::print_too_many_arguments_error();
-                    ParseResult::Matched
+                [] => Err(InputError::Message(NEED_HIT_COL.to_string())),
+                [hit_col] => Ok(DelayCommand::Default {
+                    delay_mode,
+                    hit_col: Parser::parse_hit_col(hit_col)?,
+                    cob_col: None,
+                    cob_row: None,
+                }),
+                _ => Err(InputError::TooManyArguments),
+            }
+        } else {
+            match extra_args {
+                [">", ..] if specific => Err(InputError::Message(
+                    NEED_HIT_ROW_HIT_COL_COB_COL.to_string(),
+                )),
+                [_, ">", ..] if specific => {
+                    Err(InputError::Message(NEED_HIT_COL_COB_COL.to_string()))
                 }
-            },
-            _ => ParseResult::Unmatched,
+                [_, _, ">", ..] if specific => Err(InputError::Message(NEED_COB_COL.to_string())),
+                [hit_row, hit_col, cob_col, ">", garg_pos_args @ ..] if specific => {
+                    let hit_row = Parser::parse_hit_row(hit_row, &self.scene().all_rows())?;
+                    let hit_col = Parser::parse_hit_col(hit_col)?;
+                    let (cob_col, cob_row) = Parser::parse_cob_col_and_row(cob_col)?;
+                    let garg_pos = self
+                        .parse_garg_pos(garg_pos_args, &self.scene().garg_rows_for_cob(hit_row))?;
+                    Ok(DelayCommand::Specific {
+                        cob: game::Cob::roof(
+                            hit_row,
+                            hit_col,
+                            cob_col,
+                            cob_row.unwrap_or(calculator::DEFAULT_ROOF_COB_ROW),
+                        ),
+                        garg_pos,
+                    })
+                }
+                [] => Err(InputError::Message(NEED_HIT_COL_COB_COL.to_string())),
+                [_] => Err(InputError::Message(NEED_COB_COL.to_string())),
+                [hit_col, cob_col] => {
+                    let hit_col = Parser::parse_hit_col(hit_col)?;
+                    let (cob_col, cob_row) = Parser::parse_cob_col_and_row(cob_col)?;
+                    Ok(DelayCommand::Default {
+                        delay_mode,
+                        hit_col,
+                        cob_col: Some(cob_col),
+                        cob_row,
+                    })
+                }
+                _ => Err(InputError::TooManyArguments),
+            }
         }
     }
 
-    fn parse_ice_times(ice_times: &[&str]) -> Result<Vec<i32>, ()> {
-        match ice_times
-            .iter()
-            .map(|&s| s.parse::<i32>())
-            .collect::<Result<Vec<i32>, _>>()
-        {
-            Err(_) => {
-                printer::print_error_with_input(
-                    ICE_TIMES_SHOULD_BE_INTEGER,
-                    format!("{:?}", ice_times).as_str(),
-                );
-                Err(())
+    fn ash_command(&self, name: &str, extra_args: &[&str]) -> Result<AshCommand, InputError> {
+        // 核沿用原有提示，樱桃与辣椒使用通用的植物提示
+        let is_doom = name == "doom";
+        let (need_row_col, need_col) = if is_doom {
+            (&NEED_DOOM_ROW_DOOM_COL, &NEED_DOOM_ROW)
+        } else {
+            (&NEED_PLANT_ROW_PLANT_COL, &NEED_PLANT_COL)
+        };
+        let (ash, garg_pos_args) = match (name, extra_args) {
+            ("jalapeno", []) => return Err(InputError::Message(NEED_PLANT_ROW.to_string())),
+            ("jalapeno", [row, garg_pos_args @ ..]) => {
+                let row = Parser::parse_ash_row(row, &self.scene().all_rows(), is_doom)?;
+                (game::Ash::Jalapeno { row }, garg_pos_args)
+            }
+            (_, []) => return Err(InputError::Message(need_row_col.to_string())),
+            (_, [_]) => return Err(InputError::Message(need_col.to_string())),
+            (_, [row, col, garg_pos_args @ ..]) => {
+                let row = Parser::parse_ash_row(row, &self.scene().all_rows(), is_doom)?;
+                let col = Parser::parse_ash_col(col, is_doom)?;
+                let ash = if is_doom {
+                    game::Ash::Doom { row, col }
+                } else {
+                    game::Ash::Cherry { row, col }
+                };
+                (ash, garg_pos_args)
+            }
+        };
+        let garg_pos = match garg_pos_args {
+            [] => None,
+            [">", garg_pos_args @ ..] => {
+                Some(self.parse_garg_pos(garg_pos_args, &self.scene().garg_rows_for_ash(&ash))?)
+            }
+            _ => return Err(InputError::TooManyArguments),
+        };
+        Ok(AshCommand { ash, garg_pos })
+    }
+
+    fn killer_command(&self, name: &str, extra_args: &[&str]) -> Result<KillerCommand, InputError> {
+        match extra_args {
+            [] => Err(InputError::Message(NEED_PLANT_ROW_PLANT_COL.to_string())),
+            [_] => Err(InputError::Message(NEED_PLANT_COL.to_string())),
+            [row, col, garg_pos_args @ ..] => {
+                let row = Parser::parse_ash_row(row, &self.scene().all_rows(), false)?;
+                let col = Parser::parse_ash_col(col, false)?;
+                let plant = if name == "squash" {
+                    game::KillerPlant::Squash { row, col }
+                } else {
+                    game::KillerPlant::Chomper { row, col }
+                };
+                let garg_pos = match garg_pos_args {
+                    [] => None,
+                    [">", garg_pos_args @ ..] => Some(self.parse_garg_pos(
+                        garg_pos_args,
+                        &self.scene().garg_rows_for_killer(&plant),
+                    )?),
+                    _ => return Err(InputError::TooManyArguments),
+                };
+                Ok(KillerCommand { plant, garg_pos })
             }
-            Ok(ice_times) => Ok(ice_times),
         }
     }
 
-    fn parse_cob_time(cob_time: &&str) -> Result<i32, ()> {
-        match cob_time.parse::<i32>() {
-            Err(_) => {
-                printer::print_error_with_input(COB_TIME_SHOULD_BE_INTEGER, cob_time);
-                Err(())
+    fn hit_command(&self, mode: HitMode, extra_args: &[&str]) -> Result<HitCommand, InputError> {
+        let ((cob_col, cob_row), delay_time) = if !self.scene().is_roof() {
+            match extra_args {
+                [] => ((None, None), None),
+                [delay_time] => ((None, None), Some(Parser::parse_delay_time(delay_time)?)),
+                _ => return Err(InputError::TooManyArguments),
             }
-            Ok(cob_time) if cob_time < 0 => {
-                printer::print_error_with_input(
-                    COB_TIME_SHOULD_BE_NON_NEGATIVE,
-                    cob_time.to_string().as_str(),
-                );
-                Err(())
+        } else {
+            match extra_args {
+                [] => return Err(InputError::Message(NEED_COB_COL.to_string())),
+                [cob_col] => {
+                    let (cob_col, cob_row) = Parser::parse_cob_col_and_row(cob_col)?;
+                    ((Some(cob_col), cob_row), None)
+                }
+                [cob_col, delay_time] => {
+                    let delay_time = Parser::parse_delay_time(delay_time)?;
+                    let (cob_col, cob_row) = Parser::parse_cob_col_and_row(cob_col)?;
+                    ((Some(cob_col), cob_row), Some(delay_time))
+                }
+                _ => return Err(InputError::TooManyArguments),
+            }
+        };
+        Ok(HitCommand {
+            mode,
+            cob_col,
+            cob_row,
+            delay_time,
+        })
+    }
+
+    fn max_delay_command(&self, extra_args: &[&str]) -> Result<MaxDelayCommand, InputError> {
+        if !self.scene().is_roof() {
+            match extra_args {
+                [] | [">", ..] => Err(InputError::Message(NEED_HIT_ROW_HIT_COL_RANGE.to_string())),
+                [_] | [_, ">", ..] => Err(InputError::Message(NEED_HIT_COL_RANGE.to_string())),
+                [hit_row, min_max_hit_col, ">", garg_pos_args @ ..] => {
+                    let hit_row = Parser::parse_hit_row(hit_row, &self.scene().all_rows())?;
+                    let min_max_hit_col = Parser::parse_min_max_hit_col(min_max_hit_col)?;
+                    let garg_pos = self
+                        .parse_garg_pos(garg_pos_args, &self.scene().garg_rows_for_cob(hit_row))?;
+                    Ok(MaxDelayCommand {
+                        hit_row,
+                        min_max_hit_col,
+                        cob_col: None,
+                        cob_row: None,
+                        garg_pos,
+                    })
+                }
+                _ => Err(InputError::BadFormat),
+            }
+        } else {
+            match extra_args {
+                [] | [">", ..] => Err(InputError::Message(
+                    NEED_HIT_ROW_HIT_COL_RANGE_COB_COL.to_string(),
+                )),
+                [_] | [_, ">", ..] => {
+                    Err(InputError::Message(NEED_HIT_COL_RANGE_COB_COL.to_string()))
+                }
+                [_, _] | [_, _, ">", ..] => Err(InputError::Message(NEED_COB_COL.to_string())),
+                [hit_row, min_max_hit_col, cob_col, ">", garg_pos_args @ ..] => {
+                    let hit_row = Parser::parse_hit_row(hit_row, &self.scene().all_rows())?;
+                    let min_max_hit_col = Parser::parse_min_max_hit_col(min_max_hit_col)?;
+                    let (cob_col, cob_row) = Parser::parse_cob_col_and_row(cob_col)?;
+                    let garg_pos = self
+                        .parse_garg_pos(garg_pos_args, &self.scene().garg_rows_for_cob(hit_row))?;
+                    Ok(MaxDelayCommand {
+                        hit_row,
+                        min_max_hit_col,
+                        cob_col: Some(cob_col),
+                        cob_row,
+                        garg_pos,
+                    })
+                }
+                _ => Err(InputError::BadFormat),
             }
-            Ok(cob_time) => Ok(cob_time),
         }
     }
 
-    fn parse_delay_time(delay_time: &&str) -> Result<i32, ()> {
-        match delay_time.parse::<i32>() {
-            Err(_) => {
-                printer::print_error_with_input(DELAY_TIME_SHOULD_BE_INTEGER, delay_time);
-                Err(())
+    // 炮落点行不含逗号，据此区分是否给出了延迟范围；其余参数与 max 相同
+    fn scan_command(&self, extra_args: &[&str]) -> Result<ScanCommand, InputError> {
+        let (csv, extra_args) = match extra_args {
+            ["csv", extra_args @ ..] => (true, extra_args),
+            _ => (false, extra_args),
+        };
+        let (min_max_delay, extra_args) = match extra_args {
+            [min_max_delay, extra_args @ ..] if min_max_delay.contains([',', '，']) => (
+                Some(Parser::parse_min_max_delay(min_max_delay)?),
+                extra_args,
+            ),
+            _ => (None, extra_args),
+        };
+        let MaxDelayCommand {
+            hit_row,
+            min_max_hit_col,
+            cob_col,
+            cob_row,
+            garg_pos,
+        } = self.max_delay_command(extra_args)?;
+        Ok(ScanCommand {
+            hit_row,
+            min_max_hit_col,
+            cob_col,
+            cob_row,
+            garg_pos,
+            min_max_delay,
+            csv,
+        })
+    }
+
+    fn solve_command(&self, extra_args: &[&str]) -> Result<SolveCommand, InputError> {
+        match extra_args {
+            [] | [">", ..] => Err(InputError::Message(if self.scene().is_roof() {
+                NEED_COB_TIME_RANGE_HIT_ROW_HIT_COL_COB_COL.to_string()
+            } else {
+                NEED_COB_TIME_RANGE_HIT_ROW_HIT_COL.to_string()
+            })),
+            [min_max_cob_time, cob_args @ ..] => {
+                let min_max_cob_time = Parser::parse_min_max_cob_time(min_max_cob_time)?;
+                let (cob, garg_rows) = self.solve_cob_and_garg_rows(cob_args)?;
+                Ok(SolveCommand {
+                    min_max_cob_time,
+                    cob,
+                    garg_rows,
+                })
             }
-            Ok(delay) => Ok(delay),
         }
     }
 
-    fn parse_hit_row(hit_row: &&str, valid_hit_rows: &[i32]) -> Result<i32, ()> {
-        match hit_row.parse::<i32>() {
-            Err(_) => {
-                printer::print_error_with_input(HIT_ROW_SHOULD_BE_INTEGER, hit_row);
-                Err(())
+    // 炮落点行不含逗号，据此区分是否给出了第二次用冰的范围
+    fn ice_solve_command(&self, extra_args: &[&str]) -> Result<IceSolveCommand, InputError> {
+        let is_range = |arg: &str| arg.contains([',', '，']);
+        let (min_max_ice_time, min_max_second_ice_time, cob_args) = match extra_args {
+            [] | [">", ..] => {
+                return Err(InputError::Message(if self.scene().is_roof() {
+                    NEED_ICE_TIME_RANGE_HIT_ROW_HIT_COL_COB_COL.to_string()
+                } else {
+                    NEED_ICE_TIME_RANGE_HIT_ROW_HIT_COL.to_string()
+                }))
             }
-            Ok(hit_row) if !(valid_hit_rows.contains(&hit_row)) => {
-                printer::print_error_with_input(
-                    &HIT_ROW_OUT_OF_RANGE.format(&[format!("{:?}", valid_hit_rows)]),
-                    hit_row.to_string().as_str(),
-                );
-                Err(())
+            [first, second, cob_args @ ..] if is_range(second) => (first, Some(second), cob_args),
+            [first, cob_args @ ..] => (first, None, cob_args),
+        };
+        let cob_time = self.calculator.ice_and_cob_times().cob_time;
+        let min_max_ice_time = Parser::parse_min_max_ice_time(min_max_ice_time, cob_time)?;
+        let min_max_second_ice_time = min_max_second_ice_time
+            .map(|min_max_ice_time| Parser::parse_min_max_ice_time(min_max_ice_time, cob_time))
+            .transpose()?;
+        let (cob, garg_rows) = self.solve_cob_and_garg_rows(cob_args)?;
+        Ok(IceSolveCommand {
+            min_max_ice_time,
+            min_max_second_ice_time,
+            cob,
+            garg_rows,
+        })
+    }
+
+    // 巨人x范围及是否减速由扫描的时机决定，">" 之后只能指定巨人所在行；省略时为炮能炸到的所有行
+    fn solve_cob_and_garg_rows(
+        &self,
+        cob_args: &[&str],
+    ) -> Result<(game::Cob, Vec<i32>), InputError> {
+        let roof = self.scene().is_roof();
+        let (hit_row, hit_col, cob_col, garg_rows_args) = match cob_args {
+            [] | [">", ..] if roof => {
+                return Err(InputError::Message(
+                    NEED_HIT_ROW_HIT_COL_COB_COL.to_string(),
+                ))
             }
-            Ok(hit_row) => Ok(hit_row),
+            [] | [">", ..] => return Err(InputError::Message(NEED_HIT_ROW_HIT_COL.to_string())),
+            [_] | [_, ">", ..] if roof => {
+                return Err(InputError::Message(NEED_HIT_COL_COB_COL.to_string()))
+            }
+            [_] | [_, ">", ..] => return Err(InputError::Message(NEED_HIT_COL.to_string())),
+            [_, _] | [_, _, ">", ..] if roof => {
+                return Err(InputError::Message(NEED_COB_COL.to_string()))
+            }
+            [hit_row, hit_col, cob_col, garg_rows_args @ ..] if roof => {
+                (hit_row, hit_col, Some(cob_col), garg_rows_args)
+            }
+            [hit_row, hit_col, garg_rows_args @ ..] => (hit_row, hit_col, None, garg_rows_args),
+        };
+        let hit_row = Parser::parse_hit_row(hit_row, &self.scene().all_rows())?;
+        let hit_col = Parser::parse_hit_col(hit_col)?;
+        let valid_garg_rows = self.scene().garg_rows_for_cob(hit_row);
+        let garg_rows = match garg_rows_args {
+            [] => valid_garg_rows,
+            [">"] => return Err(InputError::Message(NEED_GARG_ROWS.to_string())),
+            [">", garg_rows] => Parser::parse_garg_rows(garg_rows, &valid_garg_rows)?,
+            [">", ..] => return Err(InputError::TooManyArguments),
+            _ => return Err(InputError::BadFormat),
+        };
+        let cob = match cob_col {
+            None => game::Cob::ground(hit_row, hit_col),
+            Some(cob_col) => {
+                let (cob_col, cob_row) = Parser::parse_cob_col_and_row(cob_col)?;
+                game::Cob::roof(
+                    hit_row,
+                    hit_col,
+                    cob_col,
+                    cob_row.unwrap_or(calculator::DEFAULT_ROOF_COB_ROW),
+                )
+            }
+        };
+        Ok((cob, garg_rows))
+    }
+
+    // 各炮以 ";" 分隔；巨人x范围及u/i只能在最后一门炮之后指定，对所有炮生效
+    fn multi_command(&self, args: &str) -> Result<MultiCommand, InputError> {
+        let segments = args.split(';').map(str::trim).collect::<Vec<&str>>();
+        let mut cobs = vec![];
+        let mut garg_pos = None;
+        for (index, segment) in segments.iter().enumerate() {
+            let (timed_cob, segment_garg_pos) =
+                self.timed_cob(&segment.split_whitespace().collect::<Vec<&str>>())?;
+            if index + 1 < segments.len()
+                && (segment_garg_pos.min_max_garg_x.is_some()
+                    || segment_garg_pos.ice_flag.is_some())
+            {
+                return Err(InputError::Message(
+                    GARG_X_RANGE_ONLY_AFTER_LAST_COB.to_string(),
+                ));
+            }
+            cobs.push(timed_cob);
+            garg_pos = Some(segment_garg_pos);
         }
+        let garg_pos = garg_pos.unwrap();
+        Ok(MultiCommand {
+            cobs,
+            min_max_garg_x: garg_pos.min_max_garg_x,
+            ice_flag: garg_pos.ice_flag,
+        })
     }
 
-    fn parse_hit_col(hit_col: &&str) -> Result<f32, ()> {
-        match hit_col.parse::<f32>() {
-            Err(_) => {
-                printer::print_error_with_input(HIT_COL_SHOULD_BE_NUMBER, hit_col);
-                Err(())
+    fn timed_cob(&self, args: &[&str]) -> Result<(TimedCob, GargPos), InputError> {
+        let (time, hit_row, hit_col, cob_col, garg_pos_args) = match (self.scene().is_roof(), args)
+        {
+            (_, []) => return Err(InputError::Message(NEED_TIMED_COB.to_string())),
+            (false, [time, hit_row, hit_col, ">", garg_pos_args @ ..]) => {
+                (time, hit_row, hit_col, None, garg_pos_args)
             }
-            Ok(hit_col) if !((0. ..10.).contains(&hit_col)) => {
-                printer::print_error_with_input(
-                    HIT_COL_SHOULD_BE_IN_RANGE,
-                    hit_col.to_string().as_str(),
-                );
-                Err(())
+            (true, [time, hit_row, hit_col, cob_col, ">", garg_pos_args @ ..]) => {
+                (time, hit_row, hit_col, Some(cob_col), garg_pos_args)
             }
-            Ok(hit_col) => match game::hit_col_matching_int_pixel(hit_col) {
-                None => Ok(hit_col),
-                Some(corrected_hit_col) => {
-                    printer::print_warning(
-                        &HIT_COL_TIMES_EIGHTY_NOT_INTEGER.format(&[hit_col, corrected_hit_col]),
-                    );
-                    Ok(corrected_hit_col)
+            _ => return Err(InputError::BadFormat),
+        };
+        let time = time
+            .parse::<i32>()
+            .map_err(|_| InputError::with_input(&EXPLOSION_TIME_SHOULD_BE_INTEGER, time))?;
+        let hit_row = Parser::parse_hit_row(hit_row, &self.scene().all_rows())?;
+        let hit_col = Parser::parse_hit_col(hit_col)?;
+        let cob = match cob_col {
+            None => game::Cob::ground(hit_row, hit_col),
+            Some(cob_col) => {
+                let (cob_col, cob_row) = Parser::parse_cob_col_and_row(cob_col)?;
+                game::Cob::roof(
+                    hit_row,
+                    hit_col,
+                    cob_col,
+                    cob_row.unwrap_or(calculator::DEFAULT_ROOF_COB_ROW),
+                )
+            }
+        };
+        let garg_pos =
+            self.parse_garg_pos(garg_pos_args, &self.scene().garg_rows_for_cob(hit_row))?;
+        Ok((
+            TimedCob {
+                cob,
+                time,
+                garg_rows: garg_pos.garg_rows.clone(),
+            },
+            garg_pos,
+        ))
+    }
+
+    // ">" 之前为可选的炮，之后为小鬼所属巨人
+    fn trace_command(&self, extra_args: &[&str]) -> Result<TraceCommand, InputError> {
+        let (cob, garg_args) = match extra_args.iter().position(|&arg| arg == ">") {
+            None => (None, extra_args),
+            Some(index) => (
+                Some(self.trace_cob(&extra_args[..index])?),
+                &extra_args[index + 1..],
+            ),
+        };
+        let (garg_row, garg_x, rnd, ice_flag) = match garg_args {
+            [] | [_] | [_, _] => {
+                return Err(InputError::Message(NEED_GARG_ROW_X_RND.to_string()));
+            }
+            [garg_row, garg_x, rnd] => (garg_row, garg_x, rnd, None),
+            [garg_row, garg_x, rnd, ice_flag] => (garg_row, garg_x, rnd, Some(ice_flag)),
+            _ => return Err(InputError::TooManyArguments),
+        };
+        Ok(TraceCommand {
+            cob,
+            garg_row: Parser::parse_garg_row(garg_row, &self.scene().all_rows())?,
+            garg_x: Parser::parse_garg_x(garg_x)?,
+            rnd: Parser::parse_rnd(rnd)?,
+            ice_flag: ice_flag.map(Parser::parse_ice_flag).transpose()?,
+        })
+    }
+
+    fn trace_cob(&self, args: &[&str]) -> Result<game::Cob, InputError> {
+        if !self.scene().is_roof() {
+            match args {
+                [] => Err(InputError::Message(NEED_HIT_ROW_HIT_COL.to_string())),
+                [_] => Err(InputError::Message(NEED_HIT_COL.to_string())),
+                [hit_row, hit_col] => Ok(game::Cob::ground(
+                    Parser::parse_hit_row(hit_row, &self.scene().all_rows())?,
+                    Parser::parse_hit_col(hit_col)?,
+                )),
+                _ => Err(InputError::TooManyArguments),
+            }
+        } else {
+            match args {
+                [] => Err(InputError::Message(
+                    NEED_HIT_ROW_HIT_COL_COB_COL.to_string(),
+                )),
+                [_] => Err(InputError::Message(NEED_HIT_COL_COB_COL.to_string())),
+                [_, _] => Err(InputError::Message(NEED_COB_COL.to_string())),
+                [hit_row, hit_col, cob_col] => {
+                    let hit_row = Parser::parse_hit_row(hit_row, &self.scene().all_rows())?;
+                    let hit_col = Parser::parse_hit_col(hit_col)?;
+                    let (cob_col, cob_row) = Parser::parse_cob_col_and_row(cob_col)?;
+                    Ok(game::Cob::roof(
+                        hit_row,
+                        hit_col,
+                        cob_col,
+                        cob_row.unwrap_or(calculator::DEFAULT_ROOF_COB_ROW),
+                    ))
+                }
+                _ => Err(InputError::TooManyArguments),
+            }
+        }
+    }
+
+    fn land_command(&self, extra_args: &[&str]) -> Result<LandCommand, InputError> {
+        let (min_max_garg_x, ice_flag) = match extra_args {
+            [] => (None, None),
+            [ice_flag @ ("u" | "i")] => (None, Some(ice_flag)),
+            [min_max_garg_x] => (Some(min_max_garg_x), None),
+            [min_max_garg_x, ice_flag] => (Some(min_max_garg_x), Some(ice_flag)),
+            _ => return Err(InputError::TooManyArguments),
+        };
+        Ok(LandCommand {
+            min_max_garg_x: min_max_garg_x
+                .map(|min_max_garg_x| self.parse_garg_x_range(min_max_garg_x))
+                .transpose()?,
+            ice_flag: ice_flag.map(Parser::parse_ice_flag).transpose()?,
+        })
+    }
+
+    fn lawn_command(&self, extra_args: &[&str]) -> Result<LawnCommand, InputError> {
+        match extra_args {
+            [] => Ok(LawnCommand::Show),
+            ["clear"] => Ok(LawnCommand::Clear),
+            cells => {
+                let mut lawn = vec![];
+                for cells in cells {
+                    lawn.extend(self.parse_lawn_cells(cells)?);
+                }
+                Ok(LawnCommand::Set(game::Lawn::new(lawn)))
+            }
+        }
+    }
+
+    // "行:列"，列可为 "1-6" 或 "1,3,5" 等
+    fn parse_lawn_cells(&self, cells: &&str) -> Result<Vec<(i32, i32)>, InputError> {
+        let cells = cells.replace('：', ":").replace('，', ",");
+        let Some((row, cols)) = cells.split_once(':') else {
+            return Err(InputError::with_input(
+                &LAWN_CELLS_SHOULD_BE_ROW_COLS,
+                &cells,
+            ));
+        };
+        let row = Parser::parse_ash_row(&row, &self.scene().all_rows(), false)?;
+        let mut lawn = vec![];
+        for cols in cols.split(',') {
+            let (min_col, max_col) = match cols.split_once('-') {
+                None => (cols, cols),
+                Some((min_col, max_col)) => (min_col, max_col),
+            };
+            let min_col = Parser::parse_ash_col(&min_col, false)?;
+            let max_col = Parser::parse_ash_col(&max_col, false)?;
+            if min_col > max_col {
+                return Err(InputError::with_input(
+                    &LAWN_CELLS_SHOULD_BE_ROW_COLS,
+                    &cells,
+                ));
+            }
+            lawn.extend((min_col..=max_col).map(|col| (row, col)));
+        }
+        Ok(lawn)
+    }
+
+    fn eat_command(&self, extra_args: &[&str]) -> Result<EatCommand, InputError> {
+        let LandCommand {
+            min_max_garg_x,
+            ice_flag,
+        } = self.land_command(extra_args)?;
+        Ok(EatCommand {
+            min_max_garg_x,
+            ice_flag,
+        })
+    }
+
+    fn imp_command(extra_args: &[&str]) -> Result<ImpCommand, InputError> {
+        let parse_imp_x = |imp_x: &str| {
+            imp_x
+                .parse::<i32>()
+                .map_err(|_| InputError::with_input(&IMP_X_SHOULD_BE_INTEGER, imp_x))
+        };
+        match extra_args {
+            [] => Err(InputError::Message(NEED_IMP_X_RANGE.to_string())),
+            [imp_x] => match imp_x.replace('，', ",").split(',').collect::<Vec<&str>>()[..] {
+                [imp_x] => {
+                    let imp_x = parse_imp_x(imp_x)?;
+                    Ok(ImpCommand {
+                        min_max_imp_x: (imp_x, imp_x),
+                    })
                 }
+                [min_imp_x, max_imp_x] => {
+                    match (parse_imp_x(min_imp_x)?, parse_imp_x(max_imp_x)?) {
+                        (min_imp_x, max_imp_x) if min_imp_x > max_imp_x => {
+                            Err(InputError::with_input(
+                                &MIN_IMP_X_SHOULD_BE_SMALLER_THAN_MAX_IMP_X,
+                                format!("{}, {}", min_imp_x, max_imp_x).as_str(),
+                            ))
+                        }
+                        min_max_imp_x => Ok(ImpCommand { min_max_imp_x }),
+                    }
+                }
+                _ => Err(InputError::TooManyArguments),
             },
+            _ => Err(InputError::TooManyArguments),
         }
     }
 
-    fn parse_min_max_hit_col(min_max_hit_col: &&str) -> Result<(f32, f32), ()> {
-        match min_max_hit_col
+    // 以 "i" 结尾或不带后缀为用冰（寒冰菇），以 "m" 结尾为减速（冰瓜）
+    fn parse_ice_times(ice_times: &[&str]) -> Result<(Vec<i32>, Vec<i32>), InputError> {
+        let mut ice = vec![];
+        let mut slow = vec![];
+        for ice_time in ice_times {
+            let (time, times) = match ice_time.strip_suffix('m') {
+                Some(time) => (time, &mut slow),
+                None => (ice_time.strip_suffix('i').unwrap_or(ice_time), &mut ice),
+            };
+            times.push(time.parse::<i32>().map_err(|_| {
+                InputError::with_input(
+                    &ICE_TIMES_SHOULD_BE_INTEGER,
+                    format!("{:?}", ice_times).as_str(),
+                )
+            })?);
+        }
+        Ok((ice, slow))
+    }
+
+    // 以 "a" 结尾表示植物激活
+    fn parse_cob_time(cob_time: &&str) -> Result<(i32, game::Activation), InputError> {
+        let (time, activation) = match cob_time.strip_suffix('a') {
+            Some(time) => (time, game::Activation::Plant),
+            None => (*cob_time, game::Activation::Cob),
+        };
+        match time.parse::<i32>() {
+            Err(_) => Err(InputError::with_input(
+                &COB_TIME_SHOULD_BE_INTEGER,
+                cob_time,
+            )),
+            Ok(cob_time) if cob_time < 0 => Err(InputError::with_input(
+                &COB_TIME_SHOULD_BE_NON_NEGATIVE,
+                cob_time.to_string().as_str(),
+            )),
+            Ok(cob_time) => Ok((cob_time, activation)),
+        }
+    }
+
+    fn parse_min_max_cob_time(min_max_cob_time: &&str) -> Result<(i32, i32), InputError> {
+        match min_max_cob_time
             .replace('，', ",")
             .split(',')
             .collect::<Vec<&str>>()
             .as_slice()
         {
-            [] => {
-                printer::print_error(NEED_MIN_MAX_HIT_COL);
-                Err(())
-            }
-            [_] => {
-                printer::print_error(NEED_MAX_HIT_COL);
-                Err(())
+            [] => Err(InputError::Message(NEED_MIN_MAX_COB_TIME.to_string())),
+            [_] => Err(InputError::Message(NEED_MAX_COB_TIME.to_string())),
+            [min_cob_time, max_cob_time] => {
+                let parse = |cob_time: &str| match cob_time.parse::<i32>() {
+                    Err(_) => Err(InputError::with_input(
+                        &COB_TIME_RANGE_SHOULD_BE_INTEGER,
+                        cob_time,
+                    )),
+                    Ok(cob_time) if cob_time < 0 => Err(InputError::with_input(
+                        &COB_TIME_SHOULD_BE_NON_NEGATIVE,
+                        cob_time.to_string().as_str(),
+                    )),
+                    Ok(cob_time) => Ok(cob_time),
+                };
+                let (min_cob_time, max_cob_time) = (parse(min_cob_time)?, parse(max_cob_time)?);
+                if min_cob_time > max_cob_time {
+                    return Err(InputError::with_input(
+                        &MIN_COB_TIME_SHOULD_BE_SMALLER_THAN_MAX_COB_TIME,
+                        format!("{}, {}", min_cob_time, max_cob_time).as_str(),
+                    ));
+                }
+                Ok((min_cob_time, max_cob_time))
             }
-            [min_hit_col, max_hit_col] => {
-                match (
-                    Parser::parse_hit_col(min_hit_col),
-                    Parser::parse_hit_col(max_hit_col),
-                ) {
-                    (Err(_), _) | (_, Err(_)) => Err(()),
-                    (Ok(min_hit_col), Ok(max_hit_col)) => {
-                        let min_hit_pixel = (min_hit_col * 80.).round() as i32;
-                        let max_hit_pixel = (max_hit_col * 80.).round() as i32;
-                        if min_hit_pixel > max_hit_pixel {
-                            printer::print_error_with_input(
-                                MIN_COL_SHOULD_BE_SMALLER_THAN_MAX_COL,
-                                format!("{}, {}", min_hit_col, max_hit_col).as_str(),
-                            );
-                            return Err(());
-                        }
-                        Ok((min_hit_col, max_hit_col))
+            _ => Err(InputError::TooManyArguments),
+        }
+    }
+
+    // 激活之后的用冰不起作用，故用冰时机不能晚于激活时机
+    fn parse_min_max_ice_time(
+        min_max_ice_time: &&str,
+        cob_time: i32,
+    ) -> Result<(i32, i32), InputError> {
+        match min_max_ice_time
+            .replace('，', ",")
+            .split(',')
+            .collect::<Vec<&str>>()
+            .as_slice()
+        {
+            [] => Err(InputError::Message(NEED_MIN_MAX_ICE_TIME.to_string())),
+            [_] => Err(InputError::Message(NEED_MAX_ICE_TIME.to_string())),
+            [min_ice_time, max_ice_time] => {
+                let parse = |ice_time: &str| match ice_time.parse::<i32>() {
+                    Err(_) => Err(InputError::with_input(
+                        &ICE_TIME_RANGE_SHOULD_BE_INTEGER,
+                        ice_time,
+                    )),
+                    Ok(ice_time) if !(0..=cob_time).contains(&ice_time) => {
+                        Err(InputError::with_input(
+                            &ICE_TIME_SHOULD_BE_IN_RANGE.format(&[cob_time]),
+                            ice_time.to_string().as_str(),
+                        ))
                     }
+                    Ok(ice_time) => Ok(ice_time),
+                };
+                let (min_ice_time, max_ice_time) = (parse(min_ice_time)?, parse(max_ice_time)?);
+                if min_ice_time > max_ice_time {
+                    return Err(InputError::with_input(
+                        &MIN_ICE_TIME_SHOULD_BE_SMALLER_THAN_MAX_ICE_TIME,
+                        format!("{}, {}", min_ice_time, max_ice_time).as_str(),
+                    ));
                 }
+                Ok((min_ice_time, max_ice_time))
             }
-            _ => {
-                printer::print_too_many_arguments_error();
-                Err(())
-            }
+            _ => Err(InputError::TooManyArguments),
         }
     }
 
-    fn parse_cob_col(cob_col: &&str) -> Result<i32, ()> {
-        match cob_col.parse::<i32>() {
-            Err(_) => {
-                printer::print_error_with_input(COB_COL_SHOULD_BE_INTEGER, cob_col);
-                Err(())
-            }
-            Ok(cob_col) if !((1..=8).contains(&cob_col)) => {
-                printer::print_error_with_input(
-                    COB_COL_SHOULD_BE_IN_RANGE,
-                    cob_col.to_string().as_str(),
+    fn parse_min_max_delay(min_max_delay: &&str) -> Result<(i32, i32), InputError> {
+        match min_max_delay
+            .replace('，', ",")
+            .split(',')
+            .collect::<Vec<&str>>()
+            .as_slice()
+        {
+            [min_delay, max_delay] => {
+                let (min_delay, max_delay) = (
+                    Parser::parse_delay_time(min_delay)?,
+                    Parser::parse_delay_time(max_delay)?,
                 );
-                Err(())
+                if min_delay > max_delay {
+                    return Err(InputError::with_input(
+                        &MIN_DELAY_SHOULD_BE_SMALLER_THAN_MAX_DELAY,
+                        format!("{}, {}", min_delay, max_delay).as_str(),
+                    ));
+                }
+                Ok((min_delay, max_delay))
             }
-            Ok(cob_col) => Ok(cob_col),
+            _ => Err(InputError::TooManyArguments),
         }
     }
 
-    fn parse_doom_row(doom_row: &&str, valid_doom_rows: &[i32]) -> Result<i32, ()> {
-        match doom_row.parse::<i32>() {
-            Err(_) => {
-                printer::print_error_with_input(DOOM_ROW_SHOULD_BE_INTEGER, doom_row);
-                Err(())
-            }
-            Ok(doom_row) if !(valid_doom_rows.contains(&doom_row)) => {
-                printer::print_error_with_input(
-                    &DOOM_ROW_OUT_OF_RANGE.format(&[format!("{:?}", valid_doom_rows)]),
-                    doom_row.to_string().as_str(),
-                );
-                Err(())
-            }
-            Ok(doom_row) => Ok(doom_row),
+    fn parse_delay_time(delay_time: &&str) -> Result<i32, InputError> {
+        delay_time
+            .parse::<i32>()
+            .map_err(|_| InputError::with_input(&DELAY_TIME_SHOULD_BE_INTEGER, delay_time))
+    }
+
+    fn parse_hit_row(hit_row: &&str, valid_hit_rows: &[i32]) -> Result<i32, InputError> {
+        match hit_row.parse::<i32>() {
+            Err(_) => Err(InputError::with_input(&HIT_ROW_SHOULD_BE_INTEGER, hit_row)),
+            Ok(hit_row) if !(valid_hit_rows.contains(&hit_row)) => Err(InputError::with_input(
+                &HIT_ROW_OUT_OF_RANGE.format(&[format!("{:?}", valid_hit_rows)]),
+                hit_row.to_string().as_str(),
+            )),
+            Ok(hit_row) => Ok(hit_row),
         }
     }
 
-    fn parse_doom_col(doom_col: &&str) -> Result<i32, ()> {
-        match doom_col.parse::<i32>() {
-            Err(_) => {
-                printer::print_error_with_input(DOOM_COL_SHOULD_BE_INTEGER, doom_col);
-                Err(())
-            }
-            Ok(doom_col) if !((1..=9).contains(&doom_col)) => {
-                printer::print_error_with_input(
-                    DOOM_COL_SHOULD_BE_IN_RANGE,
-                    doom_col.to_string().as_str(),
-                );
-                Err(())
+    // 炮列数*80不是整数时，由计算器修正并给出警告
+    fn parse_hit_col(hit_col: &&str) -> Result<f32, InputError> {
+        match hit_col.parse::<f32>() {
+            Err(_) => Err(InputError::with_input(&HIT_COL_SHOULD_BE_NUMBER, hit_col)),
+            Ok(hit_col) if !((0. ..10.).contains(&hit_col)) => Err(InputError::with_input(
+                &HIT_COL_SHOULD_BE_IN_RANGE,
+                hit_col.to_string().as_str(),
+            )),
+            Ok(hit_col) => Ok(hit_col),
+        }
+    }
+
+    fn parse_min_max_hit_col(min_max_hit_col: &&str) -> Result<(f32, f32), InputError> {
+        match min_max_hit_col
+            .replace('，', ",")
+            .split(',')
+            .collect::<Vec<&str>>()
+            .as_slice()
+        {
+            [] => Err(InputError::Message(NEED_MIN_MAX_HIT_COL.to_string())),
+            [_] => Err(InputError::Message(NEED_MAX_HIT_COL.to_string())),
+            [min_hit_col, max_hit_col] => {
+                let min_hit_col = Parser::parse_hit_col(min_hit_col)?;
+                let max_hit_col = Parser::parse_hit_col(max_hit_col)?;
+                let min_hit_pixel = (min_hit_col * 80.).round() as i32;
+                let max_hit_pixel = (max_hit_col * 80.).round() as i32;
+                if min_hit_pixel > max_hit_pixel {
+                    return Err(InputError::with_input(
+                        &MIN_COL_SHOULD_BE_SMALLER_THAN_MAX_COL,
+                        format!("{}, {}", min_hit_col, max_hit_col).as_str(),
+                    ));
+                }
+                Ok((min_hit_col, max_hit_col))
             }
-            Ok(doom_col) => Ok(doom_col),
+            _ => Err(InputError::TooManyArguments),
+        }
+    }
+
+    fn parse_cob_col(cob_col: &&str) -> Result<i32, InputError> {
+        match cob_col.parse::<i32>() {
+            Err(_) => Err(InputError::with_input(&COB_COL_SHOULD_BE_INTEGER, cob_col)),
+            Ok(cob_col) if !((1..=8).contains(&cob_col)) => Err(InputError::with_input(
+                &COB_COL_SHOULD_BE_IN_RANGE,
+                cob_col.to_string().as_str(),
+            )),
+            Ok(cob_col) => Ok(cob_col),
+        }
+    }
+
+    // "炮尾列@炮尾行"，省略炮尾行时由计算器使用默认值
+    fn parse_cob_col_and_row(cob_pos: &&str) -> Result<(i32, Option<i32>), InputError> {
+        match cob_pos.split_once('@') {
+            None => Ok((Parser::parse_cob_col(cob_pos)?, None)),
+            Some((cob_col, cob_row)) => Ok((
+                Parser::parse_cob_col(&cob_col)?,
+                Some(Parser::parse_cob_row(&cob_row)?),
+            )),
+        }
+    }
+
+    fn parse_cob_row(cob_row: &&str) -> Result<i32, InputError> {
+        match cob_row.parse::<i32>() {
+            Err(_) => Err(InputError::with_input(&COB_ROW_SHOULD_BE_INTEGER, cob_row)),
+            Ok(cob_row) if !((1..=5).contains(&cob_row)) => Err(InputError::with_input(
+                &COB_ROW_SHOULD_BE_IN_RANGE,
+                cob_row.to_string().as_str(),
+            )),
+            Ok(cob_row) => Ok(cob_row),
+        }
+    }
+
+    fn parse_ash_row(row: &&str, valid_rows: &[i32], is_doom: bool) -> Result<i32, InputError> {
+        let (should_be_integer, out_of_range) = if is_doom {
+            (&DOOM_ROW_SHOULD_BE_INTEGER, &DOOM_ROW_OUT_OF_RANGE)
+        } else {
+            (&PLANT_ROW_SHOULD_BE_INTEGER, &PLANT_ROW_OUT_OF_RANGE)
+        };
+        match row.parse::<i32>() {
+            Err(_) => Err(InputError::with_input(should_be_integer, row)),
+            Ok(row) if !(valid_rows.contains(&row)) => Err(InputError::with_input(
+                &out_of_range.format(&[format!("{:?}", valid_rows)]),
+                row.to_string().as_str(),
+            )),
+            Ok(row) => Ok(row),
+        }
+    }
+
+    fn parse_ash_col(col: &&str, is_doom: bool) -> Result<i32, InputError> {
+        let (should_be_integer, should_be_in_range) = if is_doom {
+            (&DOOM_COL_SHOULD_BE_INTEGER, &DOOM_COL_SHOULD_BE_IN_RANGE)
+        } else {
+            (&PLANT_COL_SHOULD_BE_INTEGER, &PLANT_COL_SHOULD_BE_IN_RANGE)
+        };
+        match col.parse::<i32>() {
+            Err(_) => Err(InputError::with_input(should_be_integer, col)),
+            Ok(col) if !((1..=9).contains(&col)) => Err(InputError::with_input(
+                should_be_in_range,
+                col.to_string().as_str(),
+            )),
+            Ok(col) => Ok(col),
         }
     }
 
     fn parse_garg_pos(
+        &self,
         garg_pos_args: &[&str],
         valid_garg_rows: &[i32],
-    ) -> Result<ParsedGargPos, ()> {
+    ) -> Result<GargPos, InputError> {
         let (garg_rows, min_max_garg_x, ice_flag) = match garg_pos_args {
             [] => {
-                printer::print_error(NEED_GARG_ROWS_X_RANGE_ICE_FLAG);
-                (Err(()), Err(()), Err(()))
+                return Err(InputError::Message(
+                    NEED_GARG_ROWS_X_RANGE_ICE_FLAG.to_string(),
+                ))
             }
-            [garg_rows] => (
-                Parser::parse_garg_rows(garg_rows, valid_garg_rows),
-                Ok(None),
-                Ok(None),
-            ),
-            [garg_rows, min_max_garg_x] => (
-                Parser::parse_garg_rows(garg_rows, valid_garg_rows),
-                Parser::parse_min_max_garg_x(min_max_garg_x).map(Some),
-                Ok(None),
-            ),
-            [garg_rows, min_max_garg_x, ice_flag] => (
-                Parser::parse_garg_rows(garg_rows, valid_garg_rows),
-                Parser::parse_min_max_garg_x(min_max_garg_x).map(Some),
-                Parser::parse_ice_flag(ice_flag).map(Some),
-            ),
-            _ => {
-                printer::print_too_many_arguments_error();
-                (Err(()), Err(()), Err(()))
+            [garg_rows] => (garg_rows, None, None),
+            [garg_rows, min_max_garg_x] => (garg_rows, Some(min_max_garg_x), None),
+            [garg_rows, min_max_garg_x, ice_flag] => {
+                (garg_rows, Some(min_max_garg_x), Some(ice_flag))
             }
+            _ => return Err(InputError::TooManyArguments),
         };
-        match (garg_rows, min_max_garg_x, ice_flag) {
-            (Err(()), _, _) | (_, Err(()), _) | (_, _, Err(())) => Err(()),
-            (Ok(garg_rows), Ok(min_max_garg_x), Ok(ice_flag)) => Ok(ParsedGargPos {
-                garg_rows,
-                min_max_garg_x,
-                ice_flag,
-            }),
-        }
+        Ok(GargPos {
+            garg_rows: Parser::parse_garg_rows(garg_rows, valid_garg_rows)?,
+            min_max_garg_x: min_max_garg_x
+                .map(|min_max_garg_x| self.parse_garg_x_range(min_max_garg_x))
+                .transpose()?,
+            ice_flag: ice_flag.map(Parser::parse_ice_flag).transpose()?,
+        })
     }
 
-    fn parse_garg_rows(garg_rows: &&str, valid_garg_rows: &[i32]) -> Result<Vec<i32>, ()> {
-        match garg_rows
+    fn parse_garg_rows(garg_rows: &&str, valid_garg_rows: &[i32]) -> Result<Vec<i32>, InputError> {
+        let garg_rows = garg_rows
             .replace('，', ",")
             .split(',')
             .map(|s| s.parse::<i32>())
             .collect::<Result<Vec<i32>, _>>()
-        {
-            Err(_) => {
-                printer::print_error_with_input(GARG_ROWS_SHOULD_BE_INTEGER, garg_rows);
-                Err(())
-            }
-            Ok(garg_rows) => {
-                let filtered_garg_rows = garg_rows
-                    .iter()
-                    .filter(|&v| valid_garg_rows.contains(v))
-                    .cloned()
-                    .collect::<Vec<i32>>();
-                if filtered_garg_rows.is_empty() {
-                    printer::print_error_with_input(
-                        &GARG_ROWS_ALL_OUT_OF_RANGE.format(&[format!("{:?}", valid_garg_rows)]),
-                        format!("{:?}", garg_rows).as_str(),
-                    );
-                    Err(())
-                } else {
-                    Ok(filtered_garg_rows)
-                }
+            .map_err(|_| InputError::with_input(&GARG_ROWS_SHOULD_BE_INTEGER, garg_rows))?;
+        let filtered_garg_rows = garg_rows
+            .iter()
+            .filter(|&v| valid_garg_rows.contains(v))
+            .cloned()
+            .collect::<Vec<i32>>();
+        if filtered_garg_rows.is_empty() {
+            Err(InputError::with_input(
+                &GARG_ROWS_ALL_OUT_OF_RANGE.format(&[format!("{:?}", valid_garg_rows)]),
+                format!("{:?}", garg_rows).as_str(),
+            ))
+        } else {
+            Ok(filtered_garg_rows)
+        }
+    }
+
+    fn parse_garg_row(garg_row: &&str, valid_garg_rows: &[i32]) -> Result<i32, InputError> {
+        match garg_row.parse::<i32>() {
+            Err(_) => Err(InputError::with_input(
+                &GARG_ROW_SHOULD_BE_INTEGER,
+                garg_row,
+            )),
+            Ok(garg_row) if !(valid_garg_rows.contains(&garg_row)) => Err(InputError::with_input(
+                &GARG_ROW_OUT_OF_RANGE.format(&[format!("{:?}", valid_garg_rows)]),
+                garg_row.to_string().as_str(),
+            )),
+            Ok(garg_row) => Ok(garg_row),
+        }
+    }
+
+    fn parse_garg_x(garg_x: &&str) -> Result<f32, InputError> {
+        match garg_x.parse::<f32>() {
+            Err(_) => Err(InputError::with_input(&GARG_X_SHOULD_BE_NUMBER, garg_x)),
+            Ok(garg_x) if garg_x <= game::MIN_GARG_X || garg_x > game::MAX_GARG_X => {
+                Err(InputError::with_input(
+                    &GARG_X_SHOULD_BE_IN_RANGE.format(&[game::MIN_GARG_X, game::MAX_GARG_X]),
+                    garg_x.to_string().as_str(),
+                ))
             }
+            Ok(garg_x) => Ok(garg_x),
+        }
+    }
+
+    fn parse_rnd(rnd: &&str) -> Result<i32, InputError> {
+        match rnd.parse::<i32>() {
+            Err(_) => Err(InputError::with_input(&RND_SHOULD_BE_INTEGER, rnd)),
+            Ok(rnd) if !((0..=100).contains(&rnd)) => Err(InputError::with_input(
+                &RND_SHOULD_BE_IN_RANGE,
+                rnd.to_string().as_str(),
+            )),
+            Ok(rnd) => Ok(rnd),
+        }
+    }
+
+    // "imp" 表示上次 imp 指令算得的巨人x范围
+    fn parse_garg_x_range(&self, min_max_garg_x: &&str) -> Result<(f32, f32), InputError> {
+        match *min_max_garg_x {
+            "imp" => self
+                .imp_garg_x_range
+                .ok_or_else(|| InputError::Message(NEED_IMP_BEFORE_GARG_X_RANGE.to_string())),
+            _ => Parser::parse_min_max_garg_x(min_max_garg_x),
         }
     }
 
-    fn parse_min_max_garg_x(min_max_garg_x: &&str) -> Result<(f32, f32), ()> {
+    fn parse_min_max_garg_x(min_max_garg_x: &&str) -> Result<(f32, f32), InputError> {
         match min_max_garg_x
             .replace('，', ",")
             .split(',')
             .collect::<Vec<&str>>()
             .as_slice()
         {
-            [] => {
-                printer::print_error(NEED_MIN_MAX_GARG_X);
-                Err(())
-            }
-            [_] => {
-                printer::print_error(NEED_MAX_GARG_X);
-                Err(())
-            }
+            [] => Err(InputError::Message(NEED_MIN_MAX_GARG_X.to_string())),
+            [_] => Err(InputError::Message(NEED_MAX_GARG_X.to_string())),
             [min_garg_x, max_garg_x] => {
                 match (min_garg_x.parse::<f32>(), max_garg_x.parse::<f32>()) {
-                    (Err(_), _) => {
-                        printer::print_error_with_input(MIN_GARG_X_SHOULD_BE_NUMBER, min_garg_x);
-                        Err(())
-                    }
-                    (_, Err(_)) => {
-                        printer::print_error_with_input(MAX_GARG_X_SHOULD_BE_NUMBER, max_garg_x);
-                        Err(())
-                    }
+                    (Err(_), _) => Err(InputError::with_input(
+                        &MIN_GARG_X_SHOULD_BE_NUMBER,
+                        min_garg_x,
+                    )),
+                    (_, Err(_)) => Err(InputError::with_input(
+                        &MAX_GARG_X_SHOULD_BE_NUMBER,
+                        max_garg_x,
+                    )),
                     (Ok(min_garg_x), Ok(max_garg_x)) if min_garg_x > max_garg_x => {
-                        printer::print_error_with_input(
-                            MIN_GARG_X_SHOULD_BE_SMALLER_THAN_MAX_GARG_X,
+                        Err(InputError::with_input(
+                            &MIN_GARG_X_SHOULD_BE_SMALLER_THAN_MAX_GARG_X,
                             format!("{}, {}", min_garg_x, max_garg_x).as_str(),
-                        );
-                        Err(())
+                        ))
                     }
                     (Ok(min_garg_x), _) if min_garg_x <= game::MIN_GARG_X => {
-                        printer::print_error_with_input(
+                        Err(InputError::with_input(
                             &MIN_GARG_X_SHOULD_BE_LARGER_THAN_LOWER_BOUND
                                 .format(&[game::MIN_GARG_X]),
                             format!("{}", min_garg_x).as_str(),
-                        );
-                        Err(())
+                        ))
                     }
                     (_, Ok(max_garg_x)) if max_garg_x > game::MAX_GARG_X => {
-                        printer::print_error_with_input(
+                        Err(InputError::with_input(
                             &MAX_GARG_X_SHOULD_BE_SMALLER_THAN_UPPER_BOUND
                                 .format(&[game::MAX_GARG_X]),
                             format!("{}", max_garg_x).as_str(),
-                        );
-                        Err(())
+                        ))
                     }
                     (Ok(min_garg_x), Ok(max_garg_x)) => Ok((min_garg_x, max_garg_x)),
                 }
             }
-            _ => {
-                printer::print_too_many_arguments_error();
-                Err(())
-            }
+            _ => Err(InputError::TooManyArguments),
         }
     }
 
-    fn parse_ice_flag(ice_mode: &&str) -> Result<bool, ()> {
+    fn parse_ice_flag(ice_mode: &&str) -> Result<bool, InputError> {
         if *ice_mode == "u" {
             Ok(false)
         } else if *ice_mode == "i" {
             Ok(true)
         } else {
-            printer::print_error_with_input(ICE_FLAG_SHOULD_BE_U_OR_I, ice_mode);
-            Err(())
+            Err(InputError::with_input(&ICE_FLAG_SHOULD_BE_U_OR_I, ice_mode))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut parser = Parser::default();
+        assert!(matches!(parser.parse(" RE "), ParseResult::Matched));
+        assert_eq!(parser.calculator().scene(), &game::Scene::RE);
+        assert!(matches!(parser.parse("me"), ParseResult::Matched));
+        assert_eq!(parser.calculator().scene(), &game::Scene::ME);
+        assert!(matches!(parser.parse("pe"), ParseResult::Matched));
+        assert!(matches!(
+            parser.parse("multi 110 1 8.8 > 1 ; 130 1 8.8 > 2 700,800 u"),
+            ParseResult::Matched
+        ));
+        assert!(matches!(
+            parser.parse("multi 110 1 8.8 > 1 700,800 ; 130 1 8.8 > 2"),
+            ParseResult::Failed
+        ));
+        assert!(matches!(
+            parser.parse("cherry 2 8 > 1"),
+            ParseResult::Matched
+        ));
+        assert!(matches!(parser.parse("cherry 2 10"), ParseResult::Failed));
+        assert!(matches!(parser.parse("cherry 2"), ParseResult::Failed));
+        assert!(matches!(
+            parser.parse("jalapeno 5 > 5 700,800"),
+            ParseResult::Matched
+        ));
+        assert!(matches!(
+            parser.parse("jalapeno 5 > 6"),
+            ParseResult::Failed
+        ));
+        assert!(matches!(parser.parse("jalapeno 5 8"), ParseResult::Failed));
+        assert!(matches!(
+            parser.parse("squash 1 3 > 1 800,800"),
+            ParseResult::Matched
+        ));
+        assert!(matches!(
+            parser.parse("chomper 1 3 > 2"),
+            ParseResult::Failed
+        ));
+        assert!(matches!(parser.parse("chomper 1"), ParseResult::Failed));
+        assert!(matches!(
+            parser.parse("lawn 1:1-6 2:1,3"),
+            ParseResult::Matched
+        ));
+        assert_eq!(
+            parser.calculator().lawn(),
+            &game::Lawn::new((1..=6).map(|col| (1, col)).chain([(2, 1), (2, 3)]))
+        );
+        assert!(matches!(parser.parse("lawn 1:0-6"), ParseResult::Failed));
+        assert!(matches!(
+            parser.parse("eat 700,800 u"),
+            ParseResult::Matched
+        ));
+        assert!(matches!(parser.parse("lawn clear"), ParseResult::Matched));
+        assert!(parser.calculator().lawn().is_empty());
+        assert_eq!(
+            parser.trace_command(&["1", "8.8", ">", "2", "750", "100", "u"]),
+            Ok(TraceCommand {
+                cob: Some(game::Cob::ground(1, 8.8)),
+                garg_x: 750.,
+                garg_row: 2,
+                rnd: 100,
+                ice_flag: Some(false),
+            })
+        );
+        assert!(matches!(
+            parser.parse("trace 1 750 0"),
+            ParseResult::Matched
+        ));
+        assert!(matches!(
+            parser.parse("trace 1 750 101"),
+            ParseResult::Failed
+        ));
+        assert!(matches!(parser.parse("trace 7 750 0"), ParseResult::Failed));
+        assert!(matches!(
+            parser.parse("trace 1 > 1 750 0"),
+            ParseResult::Failed
+        ));
+        assert_eq!(
+            parser.land_command(&["i"]),
+            Ok(LandCommand {
+                min_max_garg_x: None,
+                ice_flag: Some(true),
+            })
+        );
+        assert!(matches!(
+            parser.parse("land 700,800 u"),
+            ParseResult::Matched
+        ));
+        assert!(matches!(parser.parse("land 700 u"), ParseResult::Failed));
+        assert!(matches!(
+            parser.parse("delay 1 8.8 > 1 imp"),
+            ParseResult::Failed
+        ));
+        assert!(matches!(parser.parse("imp 180,150"), ParseResult::Failed));
+        assert_eq!(
+            Parser::imp_command(&["150，180"]),
+            Ok(ImpCommand {
+                min_max_imp_x: (150, 180),
+            })
+        );
+        assert!(matches!(parser.parse("imp 150,180"), ParseResult::Matched));
+        let imp_garg_x_range = parser.imp_garg_x_range.unwrap();
+        assert!(matches!(
+            parser.delay_command(None, &["1", "8.8", ">", "1", "imp", "u"]),
+            Ok(DelayCommand::Specific {
+                garg_pos: GargPos {
+                    min_max_garg_x: Some(min_max_garg_x),
+                    ..
+                },
+                ..
+            }) if min_max_garg_x == imp_garg_x_range
+        ));
+        assert!(matches!(parser.parse("land imp"), ParseResult::Matched));
+        assert!(matches!(parser.parse("jalapeno 2"), ParseResult::Matched));
+        assert!(matches!(
+            parser.svg_command(&["a.svg"]),
+            Ok(SvgCommand {
+                explode: ExplodeCommand::Ash(_),
+                ..
+            })
+        ));
+        assert!(matches!(parser.parse("re"), ParseResult::Matched));
+        // 切换场合后原爆炸已不适用
+        assert_eq!(
+            parser.svg_command(&["a.svg"]),
+            Err(InputError::Message(NEED_EXPLOSION_BEFORE_SVG.to_string()))
+        );
+        assert!(matches!(
+            parser.parse("multi 110 1 8.8 > 1"),
+            ParseResult::Failed
+        ));
+        assert_eq!(
+            parser.delay_command(None, &["3.5", "4@2"]),
+            Ok(DelayCommand::Default {
+                delay_mode: None,
+                hit_col: 3.5,
+                cob_col: Some(4),
+                cob_row: Some(2),
+            })
+        );
+        assert!(matches!(
+            parser.parse("delay 1 3.5 4@2 > 1"),
+            ParseResult::Matched
+        ));
+        assert!(matches!(parser.parse("hit 4@6"), ParseResult::Failed));
+        assert_eq!(
+            parser.hit_command(HitMode::Hit, &["4@2"]),
+            Ok(HitCommand {
+                mode: HitMode::Hit,
+                cob_col: Some(4),
+                cob_row: Some(2),
+                delay_time: None,
+            })
+        );
+        assert!(matches!(parser.parse("hit 4@2"), ParseResult::Matched));
+        assert!(matches!(parser.parse("nohit 4@2"), ParseResult::Matched));
+        assert!(matches!(parser.parse("nohit 4@6"), ParseResult::Failed));
+        assert_eq!(
+            parser.solve_command(&["300,400", "1", "3.5", "4@2", ">", "1"]),
+            Ok(SolveCommand {
+                min_max_cob_time: (300, 400),
+                cob: game::Cob::roof(1, 3.5, 4, 2),
+                garg_rows: vec![1],
+            })
+        );
+        assert_eq!(
+            parser.solve_command(&["300,400", "1", "3.5", ">", "1"]),
+            Err(InputError::Message(NEED_COB_COL.to_string()))
+        );
+        assert!(matches!(
+            parser.parse("max 1 3,4 4@x > 1"),
+            ParseResult::Failed
+        ));
+        assert!(matches!(
+            parser.parse("wave 1 400 800"),
+            ParseResult::Matched
+        ));
+        assert!(matches!(parser.parse("delay 3.5"), ParseResult::Failed));
+        assert!(matches!(parser.parse("wave 1 -400"), ParseResult::Failed));
+        assert!(matches!(parser.parse("pe"), ParseResult::Matched));
+        assert_eq!(
+            parser.solve_command(&["300,400", "1", "8.8"]),
+            Ok(SolveCommand {
+                min_max_cob_time: (300, 400),
+                cob: game::Cob::ground(1, 8.8),
+                garg_rows: vec![1, 2],
+            })
+        );
+        assert!(matches!(
+            parser.parse("solve 400,300 1 8.8 > 1"),
+            ParseResult::Failed
+        ));
+        assert_eq!(
+            parser.scan_command(&["csv", "100，130", "1", "8,8.8", ">", "1"]),
+            Ok(ScanCommand {
+                hit_row: 1,
+                min_max_hit_col: (8., 8.8),
+                cob_col: None,
+                cob_row: None,
+                garg_pos: GargPos {
+                    garg_rows: vec![1],
+                    min_max_garg_x: None,
+                    ice_flag: None,
+                },
+                min_max_delay: Some((100, 130)),
+                csv: true,
+            })
+        );
+        assert_eq!(
+            parser
+                .scan_command(&["1", "8,8.8", ">", "1"])
+                .map(|command| (command.min_max_delay, command.csv)),
+            Ok((None, false))
+        );
+        assert!(matches!(
+            parser.parse("scan 130,100 1 8,8.8 > 1"),
+            ParseResult::Failed
+        ));
+        assert_eq!(
+            parser.ice_solve_command(&["1,100", "200，300", "1", "8.8", ">", "1"]),
+            Ok(IceSolveCommand {
+                min_max_ice_time: (1, 100),
+                min_max_second_ice_time: Some((200, 300)),
+                cob: game::Cob::ground(1, 8.8),
+                garg_rows: vec![1],
+            })
+        );
+        assert_eq!(
+            parser
+                .ice_solve_command(&["1,100", "1", "8.8"])
+                .map(|command| command.min_max_second_ice_time),
+            Ok(None)
+        );
+        // 用冰时机不能晚于激活时机
+        assert!(matches!(
+            parser.parse("icesolve 1,1000 1 8.8"),
+            ParseResult::Failed
+        ));
+        assert_eq!(
+            Parser::wave_command(&["1i", "400m", "600", "800"]),
+            Ok(WaveCommand::Set {
+                ice_times: vec![1, 600],
+                slow_times: vec![400],
+                cob_time: 800,
+                activation: game::Activation::Cob,
+            })
+        );
+        assert_eq!(
+            Parser::wave_command(&["800a"]),
+            Ok(WaveCommand::Set {
+                ice_times: vec![],
+                slow_times: vec![],
+                cob_time: 800,
+                activation: game::Activation::Plant,
+            })
+        );
+        assert!(matches!(parser.parse("wave 800b"), ParseResult::Failed));
+        assert!(matches!(parser.parse("wave 1x 800"), ParseResult::Failed));
+        assert!(matches!(parser.parse("bogus"), ParseResult::Failed));
+        assert!(matches!(parser.parse("lang fr"), ParseResult::Failed));
+        assert!(matches!(parser.parse("format json"), ParseResult::Matched));
+        assert_eq!(parser.format(), OutputFormat::Json);
+    }
+
+    #[test]
+    fn test_parse_keeps_arg_case() {
+        assert_eq!(
+            lowercase_command("SVG /tmp/rt/MyPlan.svg"),
+            "svg /tmp/rt/MyPlan.svg"
+        );
+        assert_eq!(lowercase_command("Load Level1"), "load Level1");
+        assert_eq!(lowercase_command("DELAY 1 8.8 > 1 U"), "delay 1 8.8 > 1 u");
+
+        let dir = std::env::temp_dir().join(format!("pvz_parser_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut parser = Parser::new(OutputFormat::Text, Some(session::Store::new(&dir)));
+        assert!(matches!(parser.parse("delay 8.8"), ParseResult::Matched));
+        let path = dir.join("MyPlan.svg");
+        assert!(matches!(
+            parser.parse(&format!("svg {}", path.display())),
+            ParseResult::Matched
+        ));
+        let file_names = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect::<Vec<_>>();
+        assert!(file_names.contains(&"MyPlan.svg".into()));
+        assert!(matches!(parser.parse("SAVE Level1"), ParseResult::Matched));
+        assert_eq!(
+            parser.store.as_ref().unwrap().names(),
+            Ok(vec!["Level1".to_string()])
+        );
+        assert!(matches!(parser.parse("load Level1"), ParseResult::Matched));
+        assert!(matches!(parser.parse("load level1"), ParseResult::Failed));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,17 +1,77 @@
+use crate::command::{HitMode, OutputFormat};
+use crate::constants;
 use crate::game;
+use crate::report::{
+    AshReport, DelayReport, EatReport, HitReport, IceSolveRange, IceSolveReport, ImpReport,
+    InputError, KillerReport, LandReport, LoadReport, MaxDelayReport, MultiReport, RelativeRow,
+    Report, ScanOutcome, ScanReport, SolveRange, SolveReport, TraceReport, Warning, WaveReport,
+};
 use dyn_fmt::AsStrFormatExt;
 use game::MAX_INTERCEPTION_DELAY;
 use std::io::Write;
 use std::str;
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
-#[cfg(feature = "en")]
-use crate::lang::en::*;
+use crate::lang::*;
 
-#[cfg(feature = "zh")]
-use crate::lang::zh::*;
+pub fn print_report(report: &Report) {
+    for warning in report.warnings() {
+        print_warning(warning);
+    }
+    if report.is_cancelled() {
+        return;
+    }
+    match report {
+        Report::Help => println!("{}", HELP),
+        Report::About => println!("{}", ABOUT),
+        Report::Scene(scene) => print_scene(scene),
+        Report::Format(format) => print_format(format),
+        Report::Lang(_) => println!("{SET_LANG}"),
+        Report::Save(name) => println!("{}", SETUP_SAVED.format(&[name])),
+        Report::Load(report) => print_load_report(report),
+        Report::Wave(report) => print_wave_report(report),
+        Report::Delay(report) => print_delay_report(report),
+        Report::Ash(report) => print_ash_report(report),
+        Report::Killer(report) => print_killer_report(report),
+        Report::Hit(report) => print_hit_report(report),
+        Report::MaxDelay(report) => print_max_delay_report(report),
+        Report::Scan(report) => print_scan_report(report),
+        Report::Solve(report) => print_solve_report(report),
+        Report::IceSolve(report) => print_ice_solve_report(report),
+        Report::Multi(report) => print_multi_report(report),
+        Report::Trace(report) => print_trace_report(report),
+        Report::Imp(report) => print_imp_report(report),
+        Report::Land(report) => print_land_report(report),
+        Report::Lawn(lawn) => print_lawn(lawn),
+        Report::Eat(report) => print_eat_report(report),
+        Report::Svg(report) => println!("{}", SVG_SAVED.format(&[&report.path])),
+    }
+}
+
+pub fn print_input_error(error: &InputError) {
+    match error {
+        InputError::Message(error) => print_error(error),
+        InputError::WithInput { message, input } => print_error_with_input(message, input),
+        InputError::TooManyArguments => print_too_many_arguments_error(),
+        InputError::BadFormat => print_bad_format_error(),
+        InputError::UnknownCommand => println!("{UNKNOWN_COMMAND}"),
+    }
+}
 
-pub fn print_warning(str: &str) {
+fn print_warning(warning: &Warning) {
+    let str = match warning {
+        Warning::GargXRangeCancelled => GARG_X_RANGE_CANCELLED.to_string(),
+        Warning::GargXRangeModified { min, max } => GARG_X_RANGE_MODIFIED.format(&[min, max]),
+        Warning::HitColCorrected {
+            hit_col,
+            corrected_hit_col,
+        } => HIT_COL_TIMES_EIGHTY_NOT_INTEGER.format(&[hit_col, corrected_hit_col]),
+        Warning::CannotHitAllGarg => CANNOT_HIT_ALL_GARG.to_string(),
+        Warning::GargWalkExtrapolated { max_walk } => GARG_WALK_EXTRAPOLATED.format(&[
+            max_walk.to_string(),
+            (constants::GARG_DATA_SIZE - 1).to_string(),
+        ]),
+    };
     print_colored(format!("{}: {}", WARNING, str).as_str(), Color::Yellow);
 }
 
@@ -28,58 +88,652 @@ fn print_colored(str: &str, color: Color) {
     println!();
 }
 
-pub fn print_error(error: &str) {
+fn print_error(error: &str) {
     println!("{INPUT_ERROR}: {error}");
 }
 
-pub fn print_error_with_input(error: &str, input: &str) {
+fn print_error_with_input(error: &str, input: &str) {
     println!("{INPUT_ERROR}: {error} ({INPUT_ERROR_GOT}: {input})")
 }
 
-pub fn print_too_many_arguments_error() {
+fn print_too_many_arguments_error() {
     println!("{INPUT_ERROR_TOO_MANY_ARGUMENTS}");
 }
 
-pub fn print_bad_format_error() {
+fn print_bad_format_error() {
     println!("{INPUT_ERROR_BAD_FORMAT}");
 }
 
-pub fn print_ice_times_and_cob_time(
+fn print_scene(scene: &game::Scene) {
+    match scene {
+        game::Scene::DE => println!("{SET_FRONTYARD}"),
+        game::Scene::NE => println!("{SET_NIGHT}"),
+        game::Scene::PE => println!("{SET_BACKYARD}"),
+        game::Scene::FE => println!("{SET_FOG}"),
+        game::Scene::RE => println!("{SET_ROOF}"),
+        game::Scene::ME => println!("{SET_MOON}"),
+    }
+}
+
+fn print_format(format: &OutputFormat) {
+    match format {
+        OutputFormat::Text => println!("{SET_TEXT_FORMAT}"),
+        OutputFormat::Json => println!("{SET_JSON_FORMAT}"),
+    }
+}
+
+fn print_load_report(LoadReport { name, scene, wave }: &LoadReport) {
+    println!("{}", SETUP_LOADED.format(&[name]));
+    print_scene(scene);
+    print_wave_report(wave);
+}
+
+fn print_wave_report(
+    WaveReport {
+        warnings: _,
+        ice_and_cob_times,
+        min_max_garg_x,
+    }: &WaveReport,
+) {
+    print_ice_times_and_cob_time(ice_and_cob_times, *min_max_garg_x, false);
+}
+
+fn print_delay_report(
+    DelayReport {
+        warnings: _,
+        cob_and_garg_rows,
+        explode_center,
+        garg_x_range,
+        eat,
+        intercept,
+    }: &DelayReport,
+) {
+    print_cob_calc_setting(
+        cob_and_garg_rows,
+        explode_center.as_ref(),
+        *garg_x_range,
+        None,
+    );
+    print_eat_and_intercept(eat, intercept);
+}
+
+fn print_ash_report(
+    AshReport {
+        warnings: _,
+        ash,
+        garg_rows,
+        explode_center,
+        garg_x_range,
+        eat,
+        intercept,
+    }: &AshReport,
+) {
+    print_ash_calc_setting(ash, garg_rows, explode_center.as_ref(), *garg_x_range);
+    print_eat_and_intercept(eat, intercept);
+}
+
+fn print_killer_report(
+    KillerReport {
+        warnings: _,
+        plant,
+        garg_rows,
+        garg_x_range,
+        eat,
+        kill,
+    }: &KillerReport,
+) {
+    println!(
+        "{CALCULATION_SETTING}: {}{}",
+        match plant {
+            game::KillerPlant::Squash { .. } => &SQUASH_GARG_ROWS,
+            game::KillerPlant::Chomper { .. } => &CHOMPER_GARG_ROWS,
+        }
+        .format(&[
+            plant.row().to_string(),
+            plant.col().to_string(),
+            format!("{:?}", garg_rows)
+        ]),
+        if let Some((min, max)) = garg_x_range {
+            format!(", {GARG}x={}~{}", min, max)
+        } else {
+            "".to_string()
+        }
+    );
+    print!("{KILL_TIME}: ");
+    match kill.interval() {
+        None => print_colored(&CANNOT_KILL_ALL, Color::Yellow),
+        Some((min, max)) => {
+            print!("{}~{}", min, max);
+            if kill.before_eat(eat) {
+                println!();
+            } else {
+                print_colored(&KILL_AFTER_EAT, Color::Yellow);
+            }
+        }
+    }
+    println!(
+        "{EARLIEST_EAT}: {}",
+        match eat.eat() {
+            None => DOES_NOT_EAT.to_string(),
+            Some(eat) => eat.to_string(),
+        }
+    );
+}
+
+fn print_hit_report(
+    HitReport {
+        warnings: _,
+        mode,
+        scene,
+        cob_tail,
+        delayed,
+        hit_pixels,
+    }: &HitReport,
+) {
+    if let Some((cob_row, cob_col)) = cob_tail {
+        println!(
+            "{CALCULATION_SETTING}: {}",
+            COB_TAIL.format(&[cob_row, cob_col])
+        );
+    }
+    if let Some((ice_and_cob_times, min_max_garg_x)) = delayed {
+        print_ice_times_and_cob_time(ice_and_cob_times, *min_max_garg_x, true);
+    }
+    for (relative_row, pixel) in hit_pixels {
+        let label = match (mode, scene.is_roof(), relative_row) {
+            (HitMode::Hit, false, RelativeRow::Same) => &HIT_SAME_AND_LOWER,
+            (HitMode::Hit, false, _) => &HIT_ALL_THREE_ROWS,
+            (HitMode::Hit, true, RelativeRow::Above) => &HIT_UPPER_ROW,
+            (HitMode::Hit, true, RelativeRow::Same) => &HIT_SAME_ROW,
+            (HitMode::Hit, true, RelativeRow::Below) => &HIT_LOWER_ROW,
+            (HitMode::Nohit, false, RelativeRow::Same) => &NOT_HIT_SAME_AND_LOWER,
+            (HitMode::Nohit, _, RelativeRow::Above) => &NOT_HIT_UPPER_ROW,
+            (HitMode::Nohit, _, RelativeRow::Same) => &NOT_HIT_SAME_ROW,
+            (HitMode::Nohit, _, RelativeRow::Below) => &NOT_HIT_LOWER_ROW,
+        };
+        println!(
+            "{label}: {} ({})",
+            pixel,
+            COL.format(&[(*pixel as f32) / 80.])
+        );
+    }
+}
+
+fn print_max_delay_report(
+    MaxDelayReport {
+        warnings: _,
+        cob,
+        garg_rows,
+        min_max_hit_col,
+        garg_x_range,
+        max_delay,
+    }: &MaxDelayReport,
+) {
+    print_cob_calc_setting(
+        &[(cob.clone(), garg_rows.clone())],
+        None,
+        *garg_x_range,
+        Some(*min_max_hit_col),
+    );
+    match max_delay {
+        None => println!("{CANNOT_INTERCEPT_WITHOUT_HARM}"),
+        Some(max_delay) => {
+            println!(
+                "{HIT_COL_WITH_MAX_DELAY}: {}",
+                COL.format(&[format!("{:?}", max_delay.hit_cols)]),
+            );
+            print_eat_and_intercept(&max_delay.eat, &max_delay.intercept);
+        }
+    }
+}
+
+fn print_scan_report(report: &ScanReport) {
+    let ScanReport {
+        warnings: _,
+        cob,
+        garg_rows,
+        min_max_hit_col,
+        garg_x_range,
+        min_max_delay,
+        csv,
+        rows,
+    } = report;
+    let delays = min_max_delay.map_or(vec![], |(min, max)| (min..=max).collect::<Vec<i32>>());
+    if *csv {
+        print_scan_csv(report, &delays);
+        return;
+    }
+    print_cob_calc_setting(
+        &[(cob.clone(), garg_rows.clone())],
+        None,
+        *garg_x_range,
+        Some(*min_max_hit_col),
+    );
+    let interval = |interval: Option<(i32, i32)>| match interval {
+        None => "-".to_string(),
+        Some((min, MAX_INTERCEPTION_DELAY)) => format!("{min}+"),
+        Some((min, max)) => format!("{min}~{max}"),
+    };
+    if let Some((min_delay, max_delay)) = min_max_delay {
+        println!("{}", SCAN_LEGEND.format(&[min_delay, max_delay]));
+    }
+    println!("{SCAN_HEADER}");
+    for row in rows {
+        let line = format!(
+            "{:<8}{:<12}{:<12}{:>8}  {}",
+            row.hit_col,
+            interval(game::safe_intercept_interval(&row.eat, &row.intercept)),
+            interval(game::unsafe_intercept_interval(&row.eat, &row.intercept)),
+            row.eat.eat().map_or("-".to_string(), |eat| eat.to_string()),
+            delays
+                .iter()
+                .map(|&delay| match row.outcome(delay) {
+                    ScanOutcome::Harmless => '+',
+                    ScanOutcome::Harmful => '!',
+                    ScanOutcome::Miss => '.',
+                })
+                .collect::<String>()
+        );
+        println!("{}", line.trim_end());
+    }
+}
+
+// 表头不随语言变化，便于导入表格软件；开放的区间上限留空
+fn print_scan_csv(ScanReport { rows, .. }: &ScanReport, delays: &[i32]) {
+    let bound = |bound: i32| {
+        if bound == MAX_INTERCEPTION_DELAY {
+            "".to_string()
+        } else {
+            bound.to_string()
+        }
+    };
+    let interval = |interval: Option<(i32, i32)>| match interval {
+        None => ",".to_string(),
+        Some((min, max)) => format!("{min},{}", bound(max)),
+    };
+    println!(
+        "hit_col,harmless_min,harmless_max,harmful_min,harmful_max,earliest_eat{}",
+        delays
+            .iter()
+            .map(|delay| format!(",{delay}"))
+            .collect::<String>()
+    );
+    for row in rows {
+        println!(
+            "{},{},{},{}{}",
+            row.hit_col,
+            interval(game::safe_intercept_interval(&row.eat, &row.intercept)),
+            interval(game::unsafe_intercept_interval(&row.eat, &row.intercept)),
+            row.eat.eat().map_or("".to_string(), |eat| eat.to_string()),
+            delays
+                .iter()
+                .map(|&delay| match row.outcome(delay) {
+                    ScanOutcome::Harmless => ",harmless",
+                    ScanOutcome::Harmful => ",harmful",
+                    ScanOutcome::Miss => ",",
+                })
+                .collect::<String>()
+        );
+    }
+}
+
+fn print_solve_report(
+    SolveReport {
+        warnings: _,
+        cob,
+        garg_rows,
+        min_max_cob_time: _,
+        ranges,
+    }: &SolveReport,
+) {
+    print_cob_calc_setting(&[(cob.clone(), garg_rows.clone())], None, None, None);
+    if ranges.is_empty() {
+        println!("{CANNOT_INTERCEPT_WITHOUT_HARM}");
+    }
+    for SolveRange {
+        min_max_cob_time: (min_cob_time, max_cob_time),
+        max_delay,
+        max_delay_cob_time,
+    } in ranges
+    {
+        println!(
+            "{}",
+            SOLVE_RANGE.format(&[min_cob_time, max_cob_time, max_delay, max_delay_cob_time])
+        );
+    }
+}
+
+fn print_ice_solve_report(report: &IceSolveReport) {
+    let IceSolveReport {
+        warnings: _,
+        cob,
+        garg_rows,
+        cob_time: _,
+        min_max_ice_times: _,
+        ranges,
+    } = report;
+    let ice_times = |ice_times: &[i32]| {
+        ice_times
+            .iter()
+            .map(i32::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+    print_cob_calc_setting(&[(cob.clone(), garg_rows.clone())], None, None, None);
+    for IceSolveRange {
+        min_max_ice_times,
+        max_delay,
+        max_delay_ice_times,
+    } in ranges
+    {
+        println!(
+            "{}",
+            ICE_SOLVE_RANGE.format(&[
+                min_max_ice_times
+                    .iter()
+                    .map(|(min, max)| format!("{min}~{max}"))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                max_delay.to_string(),
+                ice_times(max_delay_ice_times),
+            ])
+        );
+    }
+    match report.best() {
+        None => println!("{CANNOT_INTERCEPT_WITHOUT_HARM}"),
+        Some(best) => println!(
+            "{}",
+            ICE_SOLVE_BEST.format(&[
+                best.max_delay.to_string(),
+                ice_times(&best.max_delay_ice_times),
+            ])
+        ),
+    }
+}
+
+fn print_multi_report(report: &MultiReport) {
+    let MultiReport {
+        warnings: _,
+        cobs,
+        garg_x_range,
+        trajectories,
+    } = report;
+    println!(
+        "{CALCULATION_SETTING}: {}{}",
+        cobs.iter()
+            .map(|timed_cob| {
+                let cob = &timed_cob.cob;
+                let setting = TIMED_COB_GARG_ROWS.format(&[
+                    timed_cob.time.to_string(),
+                    cob.row().to_string(),
+                    format!("{:?}", timed_cob.garg_rows),
+                ]);
+                match cob.cob_row().zip(cob.cob_col()) {
+                    Some((cob_row, cob_col)) => {
+                        format!("{setting} ({})", COB_TAIL.format(&[cob_row, cob_col]))
+                    }
+                    None => setting,
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(", "),
+        if let Some((min_garg_x, max_garg_x)) = garg_x_range {
+            format!(", {GARG}x={}~{}", min_garg_x, max_garg_x)
+        } else {
+            "".to_string()
+        }
+    );
+    for trajectory in trajectories {
+        let line = format!(
+            "{}: {}, {EARLIEST_EAT}: {}",
+            TRAJECTORY.format(&[
+                format!("{:.3}", trajectory.garg_x),
+                trajectory.garg_row.to_string(),
+                trajectory.rnd.to_string(),
+            ]),
+            match trajectory.killer {
+                Some((index, time)) => KILLED_BY.format(&[index + 1, time as usize]),
+                None => NOT_INTERCEPTED.to_string(),
+            },
+            trajectory.eat
+        );
+        if trajectory.is_safe() {
+            println!("{line}");
+        } else {
+            print_colored(&line, Color::Yellow);
+        }
+    }
+    if report.all_intercepted() {
+        println!("{ALL_INTERCEPTED}");
+    } else {
+        print_colored(&NOT_ALL_INTERCEPTED, Color::Yellow);
+    }
+}
+
+fn print_trace_report(
+    TraceReport {
+        warnings: _,
+        cob,
+        explode_center,
+        garg_x,
+        garg_row,
+        rnd,
+        iced,
+        ticks,
+    }: &TraceReport,
+) {
+    println!(
+        "{CALCULATION_SETTING}: {}, {}{}{}{}",
+        TRAJECTORY.format(&[garg_x.to_string(), garg_row.to_string(), rnd.to_string()]),
+        if *iced { &*ICED } else { &*UNICED },
+        if let Some(cob) = cob {
+            ", ".to_owned() + &TRACE_COB.format(&[cob.row().to_string(), cob.col().to_string()])
+        } else {
+            "".to_string()
+        },
+        if let Some((cob_row, cob_col)) = cob
+            .as_ref()
+            .and_then(|cob| cob.cob_row().zip(cob.cob_col()))
+        {
+            ", ".to_owned() + &COB_TAIL.format(&[cob_row, cob_col])
+        } else {
+            "".to_string()
+        },
+        if let Some(explode_center) = explode_center {
+            format!(
+                ", {EXPLOSION_CENTER}x={} y={}",
+                explode_center.x, explode_center.y
+            )
+        } else {
+            "".to_string()
+        },
+    );
+    if ticks.is_empty() {
+        println!("{NO_IMP_THROWN}");
+        return;
+    }
+    if cob.is_some() {
+        println!("{TRACE_HEADER}  {TRACE_HIT}");
+    } else {
+        println!("{TRACE_HEADER}");
+    }
+    for tick in ticks {
+        let state = match tick.state {
+            game::ImpState::S71 => "S71".to_string(),
+            game::ImpState::S72 { countdown } => format!("S72 {countdown}"),
+            game::ImpState::S0 => "S0".to_string(),
+        };
+        let defense = format!(
+            "({}, {}, {}, {})",
+            tick.defense.upper_left.x,
+            tick.defense.upper_left.y,
+            tick.defense.width,
+            tick.defense.height
+        );
+        let line = format!(
+            "{:>4}  {:<8}{:>9.3}{:>9.3}{:>9.3}{:>9.3}  {:<22}",
+            tick.tick, state, tick.x, tick.y, tick.h, tick.y_shift, defense
+        );
+        // 能炸到的时刻用黄色标出
+        if tick.hit == Some(true) {
+            print_colored(&format!("{line}*"), Color::Yellow);
+        } else {
+            println!("{}", line.trim_end());
+        }
+    }
+}
+
+fn print_imp_report(
+    ImpReport {
+        min_max_imp_x: _,
+        scene,
+        min_max_garg_x: (min_garg_x, max_garg_x),
+    }: &ImpReport,
+) {
+    println!(
+        "{GARG_X_RANGE} ({}): {:.3}~{:.3}",
+        if scene.is_roof() {
+            &*ROOF_SCENES
+        } else {
+            &*NON_ROOF_SCENES
+        },
+        min_garg_x,
+        max_garg_x
+    );
+}
+
+fn print_land_report(
+    LandReport {
+        warnings: _,
+        garg_x_range,
+        iced,
+        landing,
+    }: &LandReport,
+) {
+    println!(
+        "{CALCULATION_SETTING}: {}{}",
+        if *iced { &*ICED } else { &*UNICED },
+        if let Some((min_garg_x, max_garg_x)) = garg_x_range {
+            format!(", {GARG}x={}~{}", min_garg_x, max_garg_x)
+        } else {
+            "".to_string()
+        }
+    );
+    if let Some(game::ImpLandingRange {
+        min_max_x: (min_x, max_x),
+        min_max_tick: (min_tick, max_tick),
+    }) = landing
+    {
+        println!("{IMP_LANDING_X}: {:.3}~{:.3}", min_x, max_x);
+        println!("{IMP_LANDING_TICK}: {}~{}", min_tick, max_tick);
+    }
+}
+
+fn print_lawn(lawn: &game::Lawn) {
+    if lawn.is_empty() {
+        println!("{LAWN_EMPTY}");
+        return;
+    }
+    let mut rows = lawn.cells().map(|(row, _)| row).collect::<Vec<i32>>();
+    rows.dedup();
+    for row in rows {
+        let cols = lawn
+            .cols(row)
+            .iter()
+            .map(|col| col.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+        println!("{}", LAWN_ROW.format(&[row.to_string(), cols]));
+    }
+}
+
+fn print_eat_report(
+    EatReport {
+        warnings: _,
+        garg_x_range,
+        iced,
+        rows,
+    }: &EatReport,
+) {
+    println!(
+        "{CALCULATION_SETTING}: {}{}",
+        if *iced { &*ICED } else { &*UNICED },
+        if let Some((min_garg_x, max_garg_x)) = garg_x_range {
+            format!(", {GARG}x={}~{}", min_garg_x, max_garg_x)
+        } else {
+            "".to_string()
+        }
+    );
+    for game::RowEatTargets {
+        garg_row,
+        targets,
+        passes,
+    } in rows
+    {
+        let mut descriptions = targets
+            .iter()
+            .map(|(col, eat)| EAT_TARGET.format(&[col, eat]))
+            .collect::<Vec<String>>();
+        if *passes {
+            descriptions.push(PASSES_ALL_PLANTS.to_string());
+        }
+        if descriptions.is_empty() {
+            descriptions.push(DOES_NOT_EAT.to_string());
+        }
+        println!(
+            "{}: {}",
+            EAT_ROW.format(&[garg_row]),
+            descriptions.join(", ")
+        );
+    }
+}
+
+fn print_ice_times_and_cob_time(
     game::IceAndCobTimes {
         ice_times,
+        slow_times,
         cob_time,
+        activation,
     }: &game::IceAndCobTimes,
     min_max_garg_x: (f32, f32),
     delayed: bool,
 ) {
     let (min_garg_x, max_garg_x) = min_max_garg_x;
-    if max_garg_x as i32 > 817 {
-        print_warning(CANNOT_HIT_ALL_GARG);
-    }
     println!(
-        "{}: {}{}",
-        if delayed { DELAY_SETTING } else { SETTING },
+        "{}: {}{}{}",
+        if delayed { &DELAY_SETTING } else { &SETTING },
         match ice_times.as_slice() {
             [] => NO_ICE.to_string(),
             ice_times => format!("{:?}{ICE}", ice_times),
         },
-        if delayed {
-            format!(" {}{COB_EFFECTIVE}", cob_time)
-        } else {
-            format!(" {}{COB_ACTIVATE}", cob_time)
+        match slow_times.as_slice() {
+            [] => String::new(),
+            slow_times => format!(" {:?}{SLOW}", slow_times),
+        },
+        match (delayed, activation) {
+            (true, game::Activation::Cob) => format!(" {}{COB_EFFECTIVE}", cob_time),
+            (true, game::Activation::Plant) => {
+                format!(" {}{COB_EFFECTIVE} ({PLANT_ACTIVATION})", cob_time)
+            }
+            (false, game::Activation::Cob) => format!(" {}{COB_ACTIVATE}", cob_time),
+            (false, game::Activation::Plant) => format!(" {}{PLANT_ACTIVATE}", cob_time),
         }
     );
     println!("{GARG_X_RANGE}: [{:.3}, {:.3}]", min_garg_x, max_garg_x);
 }
 
-pub fn print_cob_calc_setting(
+fn print_cob_calc_setting(
     cob_and_garg_rows: &[(game::Cob, Vec<i32>)],
-    explode: Option<game::Explode>,
+    explode_center: Option<&game::IntVec2>,
     modified_min_max_garg_x: Option<(f32, f32)>,
     cob_col_range: Option<(f32, f32)>,
 ) {
+    // 同一指令中各炮的炮尾位置相同
+    let cob_tail = cob_and_garg_rows
+        .first()
+        .and_then(|(cob, _)| cob.cob_row().zip(cob.cob_col()));
     println!(
-        "{CALCULATION_SETTING}: {}{}{}{}",
+        "{CALCULATION_SETTING}: {}{}{}{}{}",
         cob_and_garg_rows
             .iter()
             .map(|(cob, garg_rows)| {
@@ -87,15 +741,20 @@ pub fn print_cob_calc_setting(
             })
             .collect::<Vec<String>>()
             .join(", "),
+        if let Some((cob_row, cob_col)) = cob_tail {
+            ", ".to_owned() + &COB_TAIL.format(&[cob_row, cob_col])
+        } else {
+            "".to_string()
+        },
         if let Some((min_cob_col, max_cob_col)) = cob_col_range {
             ", ".to_owned() + &COB_COL_RANGE.format(&[min_cob_col, max_cob_col])
         } else {
             "".to_string()
         },
-        if let Some(explode) = explode {
+        if let Some(explode_center) = explode_center {
             format!(
                 ", {EXPLOSION_CENTER}x={} y={}",
-                explode.range.center.x, explode.range.center.y
+                explode_center.x, explode_center.y
             )
         } else {
             "".to_string()
@@ -108,19 +767,24 @@ pub fn print_cob_calc_setting(
     );
 }
 
-pub fn print_doom_calc_setting(
-    doom_row: i32,
+fn print_ash_calc_setting(
+    ash: &game::Ash,
     garg_rows: &[i32],
-    explode: Option<&game::Explode>,
+    explode_center: Option<&game::IntVec2>,
     modified_min_max_garg_x: Option<(f32, f32)>,
 ) {
     println!(
         "{CALCULATION_SETTING}: {}{}{}",
-        DOOM_GARG_ROWS.format(&[doom_row.to_string(), format!("{:?}", garg_rows)]),
-        if let Some(explode) = explode {
+        match ash {
+            game::Ash::Doom { .. } => &DOOM_GARG_ROWS,
+            game::Ash::Cherry { .. } => &CHERRY_GARG_ROWS,
+            game::Ash::Jalapeno { .. } => &JALAPENO_GARG_ROWS,
+        }
+        .format(&[ash.row().to_string(), format!("{:?}", garg_rows)]),
+        if let Some(explode_center) = explode_center {
             format!(
                 ", {EXPLOSION_CENTER}x={} y={}",
-                explode.range.center.x, explode.range.center.y
+                explode_center.x, explode_center.y
             )
         } else {
             "".to_string()
@@ -136,11 +800,11 @@ pub fn print_doom_calc_setting(
     );
 }
 
-pub fn print_eat_and_intercept(eat: &game::Eat, intercept: &game::Intercept) {
+fn print_eat_and_intercept(eat: &game::Eat, intercept: &game::Intercept) {
     print!("{INTERCEPTABLE_INTERVAL}: ");
     match intercept {
         game::Intercept::Empty | game::Intercept::OnlyHighIndexImp | game::Intercept::Fail => {
-            print_colored(CANNOT_INTERCEPT, Color::Yellow)
+            print_colored(&CANNOT_INTERCEPT, Color::Yellow)
         }
         game::Intercept::Success { min, max } => {
             if *max == MAX_INTERCEPTION_DELAY {
@@ -177,71 +841,3 @@ pub fn print_eat_and_intercept(eat: &game::Eat, intercept: &game::Intercept) {
         }
     );
 }
-
-pub fn print_hit_cob_dist(scene: &game::Scene, max_garg_x: i32, cob_dist: &game::CobDist) {
-    match scene {
-        game::Scene::DE | game::Scene::PE => {
-            println!(
-                "{HIT_SAME_AND_LOWER}: {} ({})",
-                max_garg_x - cob_dist.hit_same,
-                COL.format(&[((max_garg_x - cob_dist.hit_same) as f32) / 80.])
-            );
-            println!(
-                "{HIT_ALL_THREE_ROWS}: {} ({})",
-                max_garg_x - cob_dist.hit_above,
-                COL.format(&[((max_garg_x - cob_dist.hit_above) as f32) / 80.])
-            );
-        }
-        game::Scene::RE => {
-            println!(
-                "{HIT_UPPER_ROW}: {} ({})",
-                max_garg_x - cob_dist.hit_above,
-                COL.format(&[((max_garg_x - cob_dist.hit_above) as f32) / 80.])
-            );
-            println!(
-                "{HIT_SAME_ROW}: {} ({})",
-                max_garg_x - cob_dist.hit_same,
-                COL.format(&[((max_garg_x - cob_dist.hit_same) as f32) / 80.])
-            );
-            println!(
-                "{HIT_LOWER_ROW}: {} ({})",
-                max_garg_x - cob_dist.hit_below,
-                COL.format(&[((max_garg_x - cob_dist.hit_below) as f32) / 80.])
-            );
-        }
-    }
-}
-
-pub fn print_nohit_cob_dist(scene: &game::Scene, min_garg_x: i32, cob_dist: &game::CobDist) {
-    match scene {
-        game::Scene::DE | game::Scene::PE => {
-            println!(
-                "{NOT_HIT_SAME_AND_LOWER}: {} ({})",
-                min_garg_x - cob_dist.hit_same - 1,
-                COL.format(&[((min_garg_x - cob_dist.hit_same - 1) as f32) / 80.])
-            );
-            println!(
-                "{NOT_HIT_UPPER_ROW}: {} ({})",
-                min_garg_x - cob_dist.hit_above - 1,
-                COL.format(&[((min_garg_x - cob_dist.hit_above - 1) as f32) / 80.])
-            );
-        }
-        game::Scene::RE => {
-            println!(
-                "{NOT_HIT_UPPER_ROW}: {} ({})",
-                min_garg_x - cob_dist.hit_above - 1,
-                COL.format(&[((min_garg_x - cob_dist.hit_above - 1) as f32) / 80.])
-            );
-            println!(
-                "{NOT_HIT_SAME_ROW}: {} ({})",
-                min_garg_x - cob_dist.hit_same - 1,
-                COL.format(&[((min_garg_x - cob_dist.hit_same - 1) as f32) / 80.])
-            );
-            println!(
-                "{NOT_HIT_LOWER_ROW}: {} ({})",
-                min_garg_x - cob_dist.hit_below - 1,
-                COL.format(&[((min_garg_x - cob_dist.hit_below - 1) as f32) / 80.])
-            );
-        }
-    }
-}
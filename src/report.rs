@@ -0,0 +1,347 @@
+use crate::command::{HitMode, OutputFormat, TimedCob};
+use crate::game;
+use crate::lang::Lang;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    /// 巨人x范围均<401，不会投掷小鬼，跳过计算
+    GargXRangeCancelled,
+    /// 巨人x范围部分<401，改用 `min~max` 计算
+    GargXRangeModified { min: f32, max: f32 },
+    /// 炮列数*80不是整数，改用 `corrected_hit_col` 计算
+    HitColCorrected {
+        hit_col: f32,
+        corrected_hit_col: f32,
+    },
+    /// 此时无法全伤巨人
+    CannotHitAllGarg,
+    /// 巨人最长行走时间 `max_walk` 超出行走数据表长，巨人x范围按行走周期外推
+    GargWalkExtrapolated { max_walk: f32 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputError {
+    Message(String),
+    WithInput { message: String, input: String },
+    TooManyArguments,
+    BadFormat,
+    UnknownCommand,
+}
+
+impl InputError {
+    pub fn with_input(message: &str, input: &str) -> InputError {
+        InputError::WithInput {
+            message: message.to_string(),
+            input: input.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaveReport {
+    pub warnings: Vec<Warning>,
+    pub ice_and_cob_times: game::IceAndCobTimes,
+    pub min_max_garg_x: (f32, f32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadReport {
+    pub name: String,
+    pub scene: game::Scene,
+    pub wave: WaveReport,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DelayReport {
+    pub warnings: Vec<Warning>,
+    pub cob_and_garg_rows: Vec<(game::Cob, Vec<i32>)>,
+    /// 仅在指定巨人位置时给出
+    pub explode_center: Option<game::IntVec2>,
+    /// 与当前设置不同时给出
+    pub garg_x_range: Option<(f32, f32)>,
+    pub eat: game::Eat,
+    pub intercept: game::Intercept,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AshReport {
+    pub warnings: Vec<Warning>,
+    pub ash: game::Ash,
+    pub garg_rows: Vec<i32>,
+    /// 仅在指定巨人位置时给出（火爆辣椒没有爆炸中心）
+    pub explode_center: Option<game::IntVec2>,
+    /// 与当前设置不同时给出
+    pub garg_x_range: Option<(f32, f32)>,
+    pub eat: game::Eat,
+    pub intercept: game::Intercept,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KillerReport {
+    pub warnings: Vec<Warning>,
+    pub plant: game::KillerPlant,
+    pub garg_rows: Vec<i32>,
+    /// 与当前设置不同时给出
+    pub garg_x_range: Option<(f32, f32)>,
+    pub eat: game::Eat,
+    pub kill: game::Kill,
+}
+
+/// 巨人相对炮落点所在的行
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeRow {
+    Above,
+    Same,
+    Below,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HitReport {
+    pub warnings: Vec<Warning>,
+    pub mode: HitMode,
+    pub scene: game::Scene,
+    /// 炮尾所在行、列（仅屋顶）
+    pub cob_tail: Option<(i32, i32)>,
+    /// 指定延迟时的设置及巨人x范围
+    pub delayed: Option<(game::IceAndCobTimes, (f32, f32))>,
+    /// 刚好（不）炸到各行巨人的炮落点像素
+    pub hit_pixels: Vec<(RelativeRow, i32)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxDelay {
+    pub hit_cols: Vec<f32>,
+    pub eat: game::Eat,
+    pub intercept: game::Intercept,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxDelayReport {
+    pub warnings: Vec<Warning>,
+    pub cob: game::Cob,
+    pub garg_rows: Vec<i32>,
+    pub min_max_hit_col: (f32, f32),
+    /// 与当前设置不同时给出
+    pub garg_x_range: Option<(f32, f32)>,
+    /// 为 `None` 表示无法无伤拦截
+    pub max_delay: Option<MaxDelay>,
+}
+
+/// 某一延迟生效的炮能否拦截
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanOutcome {
+    Harmless,
+    Harmful,
+    Miss,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanRow {
+    pub hit_col: f32,
+    pub eat: game::Eat,
+    pub intercept: game::Intercept,
+}
+
+impl ScanRow {
+    pub fn outcome(&self, delay: i32) -> ScanOutcome {
+        let in_interval = |interval: Option<(i32, i32)>| {
+            interval.is_some_and(|interval| game::interval_contains(interval, delay))
+        };
+        if in_interval(game::safe_intercept_interval(&self.eat, &self.intercept)) {
+            ScanOutcome::Harmless
+        } else if in_interval(game::unsafe_intercept_interval(&self.eat, &self.intercept)) {
+            ScanOutcome::Harmful
+        } else {
+            ScanOutcome::Miss
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanReport {
+    pub warnings: Vec<Warning>,
+    pub cob: game::Cob,
+    pub garg_rows: Vec<i32>,
+    pub min_max_hit_col: (f32, f32),
+    /// 与当前设置不同时给出
+    pub garg_x_range: Option<(f32, f32)>,
+    pub min_max_delay: Option<(i32, i32)>,
+    pub csv: bool,
+    pub rows: Vec<ScanRow>,
+}
+
+/// 一段连续的可无伤拦截的激活时机
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveRange {
+    pub min_max_cob_time: (i32, i32),
+    /// 段内可无伤拦截的最大延迟
+    pub max_delay: i32,
+    /// 取得最大延迟的最早激活时机
+    pub max_delay_cob_time: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveReport {
+    pub warnings: Vec<Warning>,
+    pub cob: game::Cob,
+    pub garg_rows: Vec<i32>,
+    pub min_max_cob_time: (i32, i32),
+    pub ranges: Vec<SolveRange>,
+}
+
+/// 一组可无伤拦截的用冰时机，每次用冰的时机在各自范围内任取
+#[derive(Debug, Clone, PartialEq)]
+pub struct IceSolveRange {
+    pub min_max_ice_times: Vec<(i32, i32)>,
+    /// 范围内可无伤拦截的最大延迟
+    pub max_delay: i32,
+    /// 取得最大延迟的最早用冰时机
+    pub max_delay_ice_times: Vec<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IceSolveReport {
+    pub warnings: Vec<Warning>,
+    pub cob: game::Cob,
+    pub garg_rows: Vec<i32>,
+    pub cob_time: i32,
+    pub min_max_ice_times: Vec<(i32, i32)>,
+    pub ranges: Vec<IceSolveRange>,
+}
+
+impl IceSolveReport {
+    /// 延迟最大的一组用冰时机
+    pub fn best(&self) -> Option<&IceSolveRange> {
+        self.ranges.iter().rev().max_by_key(|range| range.max_delay)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiReport {
+    pub warnings: Vec<Warning>,
+    pub cobs: Vec<TimedCob>,
+    /// 与当前设置不同时给出
+    pub garg_x_range: Option<(f32, f32)>,
+    pub trajectories: Vec<game::Trajectory>,
+}
+
+impl MultiReport {
+    /// 所有小鬼均在啃食前被拦截
+    pub fn all_intercepted(&self) -> bool {
+        self.trajectories.iter().all(game::Trajectory::is_safe)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceReport {
+    pub warnings: Vec<Warning>,
+    pub cob: Option<game::Cob>,
+    pub explode_center: Option<game::IntVec2>,
+    pub garg_x: f32,
+    pub garg_row: i32,
+    pub rnd: i32,
+    pub iced: bool,
+    /// 为空表示该巨人不会投掷小鬼
+    pub ticks: Vec<game::ImpTick>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImpReport {
+    pub min_max_imp_x: (i32, i32),
+    /// 屋顶场合与其他场合的对照表不同
+    pub scene: game::Scene,
+    /// 能投掷该范围内任一小鬼的巨人x范围之并
+    pub min_max_garg_x: (f32, f32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LandReport {
+    pub warnings: Vec<Warning>,
+    /// 与当前设置不同时给出
+    pub garg_x_range: Option<(f32, f32)>,
+    pub iced: bool,
+    pub landing: Option<game::ImpLandingRange>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EatReport {
+    pub warnings: Vec<Warning>,
+    /// 与当前设置不同时给出
+    pub garg_x_range: Option<(f32, f32)>,
+    pub iced: bool,
+    pub rows: Vec<game::RowEatTargets>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgReport {
+    pub warnings: Vec<Warning>,
+    pub path: String,
+    pub scene: game::Scene,
+    pub explodes: Vec<game::Explode>,
+    /// 与当前设置不同时给出
+    pub garg_x_range: Option<(f32, f32)>,
+    pub iced: bool,
+    pub traces: Vec<game::ImpTrace>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Report {
+    Help,
+    About,
+    Scene(game::Scene),
+    Format(OutputFormat),
+    Lang(Lang),
+    Save(String),
+    Load(LoadReport),
+    Wave(WaveReport),
+    Delay(DelayReport),
+    Ash(AshReport),
+    Killer(KillerReport),
+    Hit(HitReport),
+    MaxDelay(MaxDelayReport),
+    Scan(ScanReport),
+    Solve(SolveReport),
+    IceSolve(IceSolveReport),
+    Multi(MultiReport),
+    Trace(TraceReport),
+    Imp(ImpReport),
+    Land(LandReport),
+    Lawn(game::Lawn),
+    Eat(EatReport),
+    Svg(SvgReport),
+}
+
+impl Report {
+    pub fn warnings(&self) -> &[Warning] {
+        match self {
+            Report::Help
+            | Report::About
+            | Report::Scene(_)
+            | Report::Format(_)
+            | Report::Lang(_)
+            | Report::Save(_)
+            | Report::Imp(_)
+            | Report::Lawn(_) => &[],
+            Report::Land(report) => &report.warnings,
+            Report::Eat(report) => &report.warnings,
+            Report::Svg(report) => &report.warnings,
+            Report::Load(report) => &report.wave.warnings,
+            Report::Wave(report) => &report.warnings,
+            Report::Delay(report) => &report.warnings,
+            Report::Ash(report) => &report.warnings,
+            Report::Killer(report) => &report.warnings,
+            Report::Hit(report) => &report.warnings,
+            Report::MaxDelay(report) => &report.warnings,
+            Report::Scan(report) => &report.warnings,
+            Report::Solve(report) => &report.warnings,
+            Report::IceSolve(report) => &report.warnings,
+            Report::Multi(report) => &report.warnings,
+            Report::Trace(report) => &report.warnings,
+        }
+    }
+
+    /// 巨人不会投掷小鬼，跳过了计算
+    pub fn is_cancelled(&self) -> bool {
+        self.warnings().contains(&Warning::GargXRangeCancelled)
+    }
+}
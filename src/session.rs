@@ -0,0 +1,186 @@
+use crate::game;
+use crate::lang::*;
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const APP_DIR: &str = "pvz_interception_calculator";
+const STATE_FILE: &str = "state.json";
+const HISTORY_FILE: &str = "history.txt";
+
+/// 一套场合、用冰及激活时机设置
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setup {
+    pub scene: game::Scene,
+    pub ice_and_cob_times: game::IceAndCobTimes,
+}
+
+impl Setup {
+    fn to_json(&self) -> Value {
+        json!({
+            "scene": self.scene.code(),
+            "ice_times": self.ice_and_cob_times.ice_times,
+            "slow_times": self.ice_and_cob_times.slow_times,
+            "cob_time": self.ice_and_cob_times.cob_time,
+            "activation": self.ice_and_cob_times.activation.code(),
+        })
+    }
+
+    fn from_json(value: &Value) -> Option<Setup> {
+        let scene = game::Scene::from_code(value.get("scene")?.as_str()?)?;
+        let times = |times: &Value| {
+            times
+                .as_array()?
+                .iter()
+                .map(|time| time.as_i64().map(|time| time as i32))
+                .collect::<Option<Vec<i32>>>()
+        };
+        let ice_times = times(value.get("ice_times")?)?;
+        // 旧版本保存的设置没有减速时机及激活方式
+        let slow_times = match value.get("slow_times") {
+            None => vec![],
+            Some(slow_times) => times(slow_times)?,
+        };
+        let cob_time = value.get("cob_time")?.as_i64()? as i32;
+        let activation = match value.get("activation") {
+            None => game::Activation::Cob,
+            Some(activation) => game::Activation::from_code(activation.as_str()?)?,
+        };
+        let ice_and_cob_times =
+            game::IceAndCobTimes::of_times(&ice_times, &slow_times, cob_time, activation).ok()?;
+        Some(Setup {
+            scene,
+            ice_and_cob_times,
+        })
+    }
+}
+
+/// 保存在配置目录下的状态：上次退出时的设置、命名设置及指令历史
+///
+/// 状态文件格式为 `{"last": 设置, "setups": {名称: 设置}}`。
+#[derive(Debug, Clone)]
+pub struct Store {
+    dir: PathBuf,
+}
+
+impl Store {
+    pub fn new(dir: &Path) -> Store {
+        Store {
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    /// 用户配置目录下的默认位置；找不到配置目录时返回 `None`
+    pub fn in_config_dir() -> Option<Store> {
+        dirs::config_dir().map(|dir| Store::new(&dir.join(APP_DIR)))
+    }
+
+    pub fn history_path(&self) -> PathBuf {
+        self.dir.join(HISTORY_FILE)
+    }
+
+    // 写入状态文件前创建目录；指令历史保存在状态文件之后，同样依赖此处
+    fn create_dir(&self) -> Result<(), String> {
+        fs::create_dir_all(&self.dir)
+            .map_err(|err| format!("{CANNOT_SAVE_STATE}: {} ({err})", self.dir.display()))
+    }
+
+    pub fn last(&self) -> Result<Option<Setup>, String> {
+        let state = self.read_state()?;
+        Ok(state.get("last").and_then(Setup::from_json))
+    }
+
+    pub fn save_last(&self, setup: &Setup) -> Result<(), String> {
+        let mut state = self.read_state()?;
+        state.insert("last".to_string(), setup.to_json());
+        self.write_state(state)
+    }
+
+    pub fn load(&self, name: &str) -> Result<Setup, String> {
+        let state = self.read_state()?;
+        state
+            .get("setups")
+            .and_then(|setups| setups.get(name))
+            .and_then(Setup::from_json)
+            .ok_or_else(|| format!("{SETUP_NOT_FOUND} ({INPUT_ERROR_GOT}: {name})"))
+    }
+
+    /// 已保存的设置名称，供交互模式补全
+    pub fn names(&self) -> Result<Vec<String>, String> {
+        let state = self.read_state()?;
+        Ok(state
+            .get("setups")
+            .and_then(Value::as_object)
+            .map(|setups| setups.keys().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    pub fn save(&self, name: &str, setup: &Setup) -> Result<(), String> {
+        let mut state = self.read_state()?;
+        let setups = state
+            .entry("setups")
+            .or_insert_with(|| Value::Object(Map::new()));
+        if !setups.is_object() {
+            *setups = Value::Object(Map::new());
+        }
+        setups
+            .as_object_mut()
+            .unwrap()
+            .insert(name.to_string(), setup.to_json());
+        self.write_state(state)
+    }
+
+    // 状态文件不存在时视为空
+    fn read_state(&self) -> Result<Map<String, Value>, String> {
+        let path = self.dir.join(STATE_FILE);
+        match fs::read_to_string(&path) {
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Map::new()),
+            Err(err) => Err(format!("{CANNOT_READ_STATE}: {} ({err})", path.display())),
+            Ok(content) => match serde_json::from_str::<Value>(&content) {
+                Ok(Value::Object(state)) => Ok(state),
+                _ => Err(format!("{CANNOT_READ_STATE}: {}", path.display())),
+            },
+        }
+    }
+
+    fn write_state(&self, state: Map<String, Value>) -> Result<(), String> {
+        let path = self.dir.join(STATE_FILE);
+        self.create_dir()?;
+        let content = serde_json::to_string_pretty(&Value::Object(state)).unwrap();
+        fs::write(&path, content)
+            .map_err(|err| format!("{CANNOT_SAVE_STATE}: {} ({err})", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("{APP_DIR}_test_{}", std::process::id()));
+        let store = Store::new(&dir);
+        let setup = Setup {
+            scene: game::Scene::RE,
+            ice_and_cob_times: game::IceAndCobTimes::of_times(
+                &[1, 400],
+                &[200],
+                800,
+                game::Activation::Plant,
+            )
+            .unwrap(),
+        };
+        assert_eq!(store.last(), Ok(None));
+        assert!(store.load("level1").is_err());
+        assert_eq!(store.names(), Ok(vec![]));
+
+        store.save("level1", &setup).unwrap();
+        store.save_last(&setup).unwrap();
+        assert_eq!(store.load("level1"), Ok(setup.clone()));
+        assert_eq!(store.last(), Ok(Some(setup)));
+        assert!(store.load("level2").is_err());
+        assert_eq!(store.names(), Ok(vec!["level1".to_string()]));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
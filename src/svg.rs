@@ -0,0 +1,233 @@
+use crate::report::SvgReport;
+use std::fmt::Write;
+use std::fs;
+
+use crate::lang::*;
+
+// 草坪左上角及格子宽度，与游戏内像素坐标一致；屋顶场合不画出坡度
+const LAWN_LEFT: i32 = 40;
+const LAWN_TOP: i32 = 80;
+const CELL_WIDTH: i32 = 80;
+const LAWN_COLS: i32 = 9;
+// 图片至少包含整个游戏窗口
+const WINDOW_WIDTH: i32 = 800;
+const WINDOW_HEIGHT: i32 = 600;
+// 各条轨迹依次循环使用的颜色
+const TRACE_COLORS: [&str; 4] = ["#1565c0", "#6a1b9a", "#00838f", "#5d4037"];
+const HIT_COLOR: &str = "#e53935";
+
+/// 绘制并写入 `report.path`
+pub fn save(report: &SvgReport) -> Result<(), String> {
+    fs::write(&report.path, render(report))
+        .map_err(|err| format!("{CANNOT_WRITE_SVG}: {} ({err})", report.path))
+}
+
+/// 以游戏内像素坐标绘制草坪、爆炸范围及各条轨迹逐刻的小鬼判定框，能炸到小鬼的时刻以红色填充
+pub fn render(report: &SvgReport) -> String {
+    let scene = &report.scene;
+    let row_height = scene.row_height();
+    let rows = scene.all_rows();
+    let lawn_width = LAWN_COLS * CELL_WIDTH;
+    let lawn_height = rows.len() as i32 * row_height;
+    let (min_x, min_y, max_x, max_y) = bounds(report);
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{min_x} {min_y} {} {}" width="{}" height="{}">"#,
+        max_x - min_x,
+        max_y - min_y,
+        max_x - min_x,
+        max_y - min_y,
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        r#"<rect x="{min_x}" y="{min_y}" width="{}" height="{}" fill="white"/>"#,
+        max_x - min_x,
+        max_y - min_y,
+    )
+    .unwrap();
+
+    let garg_rows = scene.garg_rows();
+    for &row in &rows {
+        let y = LAWN_TOP + (row - 1) * row_height;
+        // 不会出现巨人的行为水路
+        let fill = if !garg_rows.contains(&row) {
+            "#81d4fa"
+        } else if row % 2 == 0 {
+            "#9ccc65"
+        } else {
+            "#aed581"
+        };
+        writeln!(
+            svg,
+            r#"<rect x="{LAWN_LEFT}" y="{y}" width="{lawn_width}" height="{row_height}" fill="{fill}"/>"#,
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"<text x="{}" y="{}" font-size="20" text-anchor="middle" dominant-baseline="middle">{row}</text>"#,
+            LAWN_LEFT / 2,
+            y + row_height / 2,
+        )
+        .unwrap();
+    }
+    for col in 1..LAWN_COLS {
+        let x = LAWN_LEFT + col * CELL_WIDTH;
+        writeln!(
+            svg,
+            r#"<line x1="{x}" y1="{LAWN_TOP}" x2="{x}" y2="{}" stroke="white" stroke-opacity="0.5"/>"#,
+            LAWN_TOP + lawn_height,
+        )
+        .unwrap();
+    }
+
+    for explode in &report.explodes {
+        match &explode.range {
+            Some(circle) => writeln!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}" fill="{HIT_COLOR}" fill-opacity="0.1" stroke="{HIT_COLOR}" stroke-width="2"/>"#,
+                circle.center.x,
+                circle.center.y,
+                circle.radius(),
+            )
+            .unwrap(),
+            // 火爆辣椒炸到整行
+            None => {
+                for row in explode.hittable_rows() {
+                    writeln!(
+                        svg,
+                        r#"<rect x="{LAWN_LEFT}" y="{}" width="{lawn_width}" height="{row_height}" fill="{HIT_COLOR}" fill-opacity="0.2"/>"#,
+                        LAWN_TOP + (row - 1) * row_height,
+                    )
+                    .unwrap();
+                }
+            }
+        }
+    }
+
+    for (i, trace) in report.traces.iter().enumerate() {
+        let color = TRACE_COLORS[i % TRACE_COLORS.len()];
+        writeln!(svg, r#"<g stroke="{color}" fill="none">"#).unwrap();
+        writeln!(
+            svg,
+            "<title>x={} row={} rnd={}</title>",
+            trace.garg_x, trace.garg_row, trace.rnd
+        )
+        .unwrap();
+        for (j, tick) in trace.ticks.iter().enumerate() {
+            let rect = &tick.defense;
+            // 首个时刻只能炸到先生成的小鬼，不计入可拦区间
+            let style = if j > 0 && tick.hit == Some(true) {
+                format!(r#"stroke="{HIT_COLOR}" fill="{HIT_COLOR}" fill-opacity="0.3""#)
+            } else {
+                r#"stroke-opacity="0.25""#.to_string()
+            };
+            writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" {style}><title>{}</title></rect>"#,
+                rect.upper_left.x, rect.upper_left.y, rect.width, rect.height, tick.tick,
+            )
+            .unwrap();
+        }
+        let points = trace
+            .ticks
+            .iter()
+            .map(|tick| {
+                let rect = &tick.defense;
+                format!(
+                    "{},{}",
+                    rect.upper_left.x + rect.width / 2,
+                    rect.upper_left.y + rect.height / 2
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+        writeln!(svg, r#"<polyline points="{points}" stroke-width="2"/>"#).unwrap();
+        writeln!(svg, "</g>").unwrap();
+    }
+    writeln!(svg, "</svg>").unwrap();
+    svg
+}
+
+// 包含游戏窗口、草坪、爆炸范围及所有判定框的最小矩形
+fn bounds(report: &SvgReport) -> (i32, i32, i32, i32) {
+    let lawn_bottom = LAWN_TOP + report.scene.all_rows().len() as i32 * report.scene.row_height();
+    let mut bounds = (0, 0, WINDOW_WIDTH, WINDOW_HEIGHT.max(lawn_bottom));
+    let mut extend = |min_x: i32, min_y: i32, max_x: i32, max_y: i32| {
+        bounds = (
+            bounds.0.min(min_x),
+            bounds.1.min(min_y),
+            bounds.2.max(max_x),
+            bounds.3.max(max_y),
+        );
+    };
+    for circle in report
+        .explodes
+        .iter()
+        .filter_map(|explode| explode.range.as_ref())
+    {
+        let (x, y, r) = (circle.center.x, circle.center.y, circle.radius());
+        extend(x - r, y - r, x + r, y + r);
+    }
+    for tick in report.traces.iter().flat_map(|trace| &trace.ticks) {
+        let rect = &tick.defense;
+        extend(
+            rect.upper_left.x,
+            rect.upper_left.y,
+            rect.upper_left.x + rect.width,
+            rect.upper_left.y + rect.height,
+        );
+    }
+    bounds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculator::Calculator;
+    use crate::command::{DelayCommand, ExplodeCommand, GargPos, SvgCommand};
+    use crate::game;
+
+    #[test]
+    fn test_render() {
+        let report = Calculator::default().run_svg(&SvgCommand {
+            path: "a.svg".to_string(),
+            explode: ExplodeCommand::Delay(DelayCommand::Specific {
+                cob: game::Cob::ground(1, 8.8),
+                garg_pos: GargPos {
+                    garg_rows: vec![1, 2],
+                    min_max_garg_x: None,
+                    ice_flag: None,
+                },
+            }),
+        });
+        let svg = render(&report);
+        assert_eq!(svg.matches("<circle").count(), 1);
+        assert_eq!(
+            svg.matches(&format!(r#"fill="{HIT_COLOR}" fill-opacity="0.3""#))
+                .count(),
+            report
+                .traces
+                .iter()
+                .map(|trace| trace.ticks[1..]
+                    .iter()
+                    .filter(|tick| tick.hit == Some(true))
+                    .count())
+                .sum::<usize>()
+        );
+        // 各行草坪、各个判定框及背景
+        assert_eq!(
+            svg.matches("<rect").count(),
+            report.scene.all_rows().len()
+                + report
+                    .traces
+                    .iter()
+                    .map(|trace| trace.ticks.len())
+                    .sum::<usize>()
+                + 1
+        );
+        let (min_x, min_y, max_x, max_y) = bounds(&report);
+        assert!(min_x <= 0 && min_y <= 0 && max_x >= WINDOW_WIDTH && max_y >= WINDOW_HEIGHT);
+    }
+}